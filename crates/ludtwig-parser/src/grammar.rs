@@ -66,7 +66,15 @@ fn parse_ludtwig_directive(
     outer: Marker,
     closing_kind: SyntaxKind,
 ) -> CompletedMarker {
-    debug_assert!(parser.at_set(&[T!["ludtwig-ignore-file"], T!["ludtwig-ignore"]]));
+    debug_assert!(parser.at_set(&[
+        T!["ludtwig-ignore-file"],
+        T!["ludtwig-ignore"],
+        T!["ludtwig-config"]
+    ]));
+    if parser.at(T!["ludtwig-config"]) {
+        return parse_ludtwig_config_directive(parser, outer, closing_kind);
+    }
+
     let ignore_kind = if parser.at(T!["ludtwig-ignore-file"]) {
         SyntaxKind::LUDTWIG_DIRECTIVE_FILE_IGNORE
     } else {
@@ -91,6 +99,39 @@ fn parse_ludtwig_directive(
     parser.complete(outer, ignore_kind)
 }
 
+fn parse_ludtwig_config_directive(
+    parser: &mut Parser,
+    outer: Marker,
+    closing_kind: SyntaxKind,
+) -> CompletedMarker {
+    debug_assert!(parser.at(T!["ludtwig-config"]));
+    parser.bump();
+
+    parse_many(
+        parser,
+        |p| p.at(closing_kind),
+        |p| {
+            let option_m = p.start();
+            p.expect(T![word], &[T!["="], T![","], closing_kind]);
+            p.expect(T!["="], &[T![","], closing_kind]);
+            // the value is a single token (like a word, number or boolean)
+            if p.at_end() || p.at_set(&[T![","], closing_kind]) {
+                p.add_error(ParseErrorBuilder::new("ludtwig-config option value"));
+            } else {
+                p.bump();
+            }
+            p.complete(option_m, SyntaxKind::LUDTWIG_DIRECTIVE_CONFIG_OPTION);
+
+            if p.at(T![","]) {
+                p.bump();
+            }
+        },
+    );
+
+    parser.expect(closing_kind, &[]);
+    parser.complete(outer, SyntaxKind::LUDTWIG_DIRECTIVE_CONFIG)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::grammar::parse_many;
@@ -194,7 +235,7 @@ mod tests {
                       TK_WORD@47..73 "twig-block-name-snake ..."
                     TK_WHITESPACE@73..74 " "
                     TK_HASHTAG_CLOSE_CURLY@74..76 "#}""##]],
-        )
+        );
     }
 
     #[test]
@@ -215,7 +256,7 @@ mod tests {
                       TK_WORD@42..68 "twig-block-name-snake ..."
                     TK_WHITESPACE@68..69 " "
                     TK_HASHTAG_CLOSE_CURLY@69..71 "#}""##]],
-        )
+        );
     }
 
     #[test]
@@ -236,7 +277,7 @@ mod tests {
                       TK_WORD@49..75 "twig-block-name-snake ..."
                     TK_WHITESPACE@75..76 " "
                     TK_MINUS_MINUS_GREATER_THAN@76..79 "-->""#]],
-        )
+        );
     }
 
     #[test]
@@ -257,6 +298,52 @@ mod tests {
                       TK_WORD@44..70 "twig-block-name-snake ..."
                     TK_WHITESPACE@70..71 " "
                     TK_MINUS_MINUS_GREATER_THAN@71..74 "-->""#]],
-        )
+        );
+    }
+
+    #[test]
+    fn parse_twig_comment_ludtwig_directive_config() {
+        check_parse(
+            "{# ludtwig-config indentation-count=2, twig-quotation=single #}",
+            expect![[r##"
+                ROOT@0..63
+                  LUDTWIG_DIRECTIVE_CONFIG@0..63
+                    TK_OPEN_CURLY_HASHTAG@0..2 "{#"
+                    TK_WHITESPACE@2..3 " "
+                    TK_LUDTWIG_CONFIG@3..17 "ludtwig-config"
+                    LUDTWIG_DIRECTIVE_CONFIG_OPTION@17..37
+                      TK_WHITESPACE@17..18 " "
+                      TK_WORD@18..35 "indentation-count"
+                      TK_EQUAL@35..36 "="
+                      TK_NUMBER@36..37 "2"
+                    TK_COMMA@37..38 ","
+                    LUDTWIG_DIRECTIVE_CONFIG_OPTION@38..60
+                      TK_WHITESPACE@38..39 " "
+                      TK_WORD@39..53 "twig-quotation"
+                      TK_EQUAL@53..54 "="
+                      TK_WORD@54..60 "single"
+                    TK_WHITESPACE@60..61 " "
+                    TK_HASHTAG_CLOSE_CURLY@61..63 "#}""##]],
+        );
+    }
+
+    #[test]
+    fn parse_html_comment_ludtwig_directive_config_missing_value() {
+        check_parse(
+            "<!-- ludtwig-config indentation-count= -->",
+            expect![[r#"
+                ROOT@0..42
+                  LUDTWIG_DIRECTIVE_CONFIG@0..42
+                    TK_LESS_THAN_EXCLAMATION_MARK_MINUS_MINUS@0..4 "<!--"
+                    TK_WHITESPACE@4..5 " "
+                    TK_LUDTWIG_CONFIG@5..19 "ludtwig-config"
+                    LUDTWIG_DIRECTIVE_CONFIG_OPTION@19..38
+                      TK_WHITESPACE@19..20 " "
+                      TK_WORD@20..37 "indentation-count"
+                      TK_EQUAL@37..38 "="
+                    TK_WHITESPACE@38..39 " "
+                    TK_MINUS_MINUS_GREATER_THAN@39..42 "-->"
+                error at 39..42: expected ludtwig-config option value but found -->"#]],
+        );
     }
 }
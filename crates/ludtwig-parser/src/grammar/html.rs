@@ -9,13 +9,19 @@ use regex::Regex;
 
 // Every token value that matches this regex is allowed for html attribute names
 static HTML_NAME_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r#"^([a-zA-Z]|([:@\#_\$][a-zA-Z]))[a-zA-Z0-9_\-]*$"#).unwrap());
+    Lazy::new(|| Regex::new(r"^([a-zA-Z]|([:@\#_\$][a-zA-Z]))[a-zA-Z0-9_\-]*$").unwrap());
+
+static HTML_NAME_MODIFIER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-zA-Z0-9_\-]+$").unwrap());
 
 static HTML_VOID_ELEMENTS: &[&str] = &[
     "area", "base", "br", "col", "command", "embed", "hr", "img", "input", "keygen", "link",
     "meta", "param", "source", "track", "wbr",
 ];
 
+/// Elements whose children are raw text (only twig syntax is parsed inside of them)
+static HTML_RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
 pub(super) fn parse_any_html(parser: &mut Parser) -> Option<CompletedMarker> {
     if parser.at(T!["<"]) {
         Some(parse_html_element(parser))
@@ -63,7 +69,11 @@ fn parse_html_comment(parser: &mut Parser) -> CompletedMarker {
     let m = parser.start();
     parser.bump();
 
-    if parser.at_set(&[T!["ludtwig-ignore-file"], T!["ludtwig-ignore"]]) {
+    if parser.at_set(&[
+        T!["ludtwig-ignore-file"],
+        T!["ludtwig-ignore"],
+        T!["ludtwig-config"],
+    ]) {
         parse_ludtwig_directive(parser, m, T!["-->"])
     } else {
         parse_plain_html_comment(parser, m)
@@ -90,10 +100,7 @@ fn parse_html_element(parser: &mut Parser) -> CompletedMarker {
     // parse start tag
     let starting_tag_m = parser.start();
     parser.bump();
-    let tag_name = parser
-        .expect(T![word], &[T![">"], T!["/>"], T!["</"], T![word], T![">"]])
-        .map_or("", |t| t.text)
-        .to_owned();
+    let tag_name = expect_html_tag_name(parser, &[T![">"], T!["/>"], T!["</"], T![word], T![">"]]);
 
     // parse attributes (can include twig)
     let attributes_m = parser.start();
@@ -115,7 +122,7 @@ fn parse_html_element(parser: &mut Parser) -> CompletedMarker {
         false
     };
 
-    if HTML_VOID_ELEMENTS.contains(&&*tag_name) {
+    if HTML_VOID_ELEMENTS.contains(&&*tag_name) || parser.is_additional_void_element(&tag_name) {
         is_self_closing = true; // void elements never have children or an end tag
     }
 
@@ -129,25 +136,40 @@ fn parse_html_element(parser: &mut Parser) -> CompletedMarker {
     // parse all the children
     let body_m = parser.start();
     let mut matching_end_tag_encountered = false;
+    parser.push_open_html_tag(tag_name.clone());
 
-    parse_many(
-        parser,
-        |p| {
-            if p.at_following_content(&[(T!["</"], None), (T![word], Some(&tag_name))]) {
-                matching_end_tag_encountered = true;
-                return true; // found matching closing tag
-            }
+    if HTML_RAW_TEXT_ELEMENTS
+        .iter()
+        .any(|e| e.eq_ignore_ascii_case(&tag_name))
+    {
+        matching_end_tag_encountered = parse_html_raw_text_children(parser, &tag_name);
+    } else {
+        parse_many(
+            parser,
+            |p| {
+                if p.at_following_content(&[(T!["</"], None), (T![word], Some(&tag_name))]) {
+                    matching_end_tag_encountered = true;
+                    return true; // found matching closing tag
+                }
 
-            if at_twig_termination_tag(p) {
-                return true; // endblock in the wild may mean this tag has a missing closing tag
-            }
+                if at_twig_termination_tag(p) {
+                    return true; // endblock in the wild may mean this tag has a missing closing tag
+                }
 
-            false
-        },
-        |p| {
-            parse_any_element(p);
-        },
-    );
+                if p.at_open_html_ending_tag() {
+                    return true; // an outer element is closed before this one (misnested tags)
+                }
+
+                false
+            },
+            |p| {
+                if parse_any_element(p).is_none() && p.at(T!["</"]) {
+                    parse_html_stray_ending_tag(p, &tag_name);
+                }
+            },
+        );
+    }
+    parser.pop_open_html_tag();
     parser.complete(body_m, SyntaxKind::BODY);
 
     // parse matching end tag or report missing (the tag itself is not self closing!)
@@ -155,27 +177,115 @@ fn parse_html_element(parser: &mut Parser) -> CompletedMarker {
     if matching_end_tag_encountered {
         // found matching closing tag
         parser.expect(T!["</"], &[T![word], T![">"]]);
-        parser.expect(T![word], &[T![">"]]);
+        expect_html_tag_name(parser, &[T![">"]]);
         parser.expect(T![">"], &[]);
     } else {
         // no matching end tag found!
-        parser.add_error(ParseErrorBuilder::new(format!(
-            "</{}> ending tag",
-            tag_name
-        )));
-        parser.recover(&[]);
+        parser.add_error(ParseErrorBuilder::new(format!("</{tag_name}> ending tag")));
+        if !parser.at_open_html_ending_tag() {
+            parser.recover(&[]);
+        }
+        // otherwise leave the ending tag for the outer element it belongs to
     }
     parser.complete(end_tag_m, SyntaxKind::HTML_ENDING_TAG);
 
     parser.complete(m, SyntaxKind::HTML_TAG)
 }
 
+/// Expect a html tag name and return it (or an empty string if it is missing).
+/// Tag names like `style` are lexed as keywords and get bumped as a word instead.
+fn expect_html_tag_name(parser: &mut Parser, recovery_set: &[SyntaxKind]) -> String {
+    if parser
+        .peek_token()
+        .is_some_and(|t| t.kind != T![word] && HTML_NAME_REGEX.is_match(t.text))
+    {
+        return parser.bump_as(T![word]).text.to_owned();
+    }
+
+    parser
+        .expect(T![word], recovery_set)
+        .map_or("", |t| t.text)
+        .to_owned()
+}
+
+/// Parse the children of a raw text element until its (case-insensitive) ending tag.
+/// Like in browsers the first ending tag closes the element, even if it is inside of a string.
+/// Returns true if the matching ending tag was found.
+fn parse_html_raw_text_children(parser: &mut Parser, tag_name: &str) -> bool {
+    let mut matching_end_tag_encountered = false;
+    parse_many(
+        parser,
+        |p| {
+            if at_html_raw_text_ending_tag(p, Some(tag_name)) {
+                matching_end_tag_encountered = true;
+                return true;
+            }
+
+            at_twig_termination_tag(p)
+        },
+        |p| {
+            parse_html_raw_text_or_twig(p);
+        },
+    );
+
+    matching_end_tag_encountered
+}
+
+fn parse_html_raw_text_or_twig(parser: &mut Parser) -> Option<CompletedMarker> {
+    parse_any_twig(parser, parse_html_raw_text_or_twig).or_else(|| parse_html_raw_text(parser))
+}
+
+fn parse_html_raw_text(parser: &mut Parser) -> Option<CompletedMarker> {
+    let at_raw_text_end = |p: &mut Parser| {
+        p.at_set(&[T!["{%"], T!["{{"], T!["{#"]]) || at_html_raw_text_ending_tag(p, None)
+    };
+    if parser.at_end() || at_raw_text_end(parser) {
+        return None;
+    }
+
+    let m = parser.start();
+    parse_many(parser, at_raw_text_end, |p| {
+        p.bump();
+    });
+
+    Some(parser.complete(m, SyntaxKind::HTML_RAW_TEXT))
+}
+
+/// At the ending tag of the given raw text element (or any raw text element if there is no name)
+fn at_html_raw_text_ending_tag(parser: &mut Parser, tag_name: Option<&str>) -> bool {
+    parser.at(T!["</"])
+        && parser.peek_token_raw_at(1).is_some_and(|t| match tag_name {
+            Some(name) => t.text.eq_ignore_ascii_case(name),
+            None => HTML_RAW_TEXT_ELEMENTS
+                .iter()
+                .any(|e| t.text.eq_ignore_ascii_case(e)),
+        })
+}
+
+/// ending tag which does not belong to any html element that is currently being parsed
+/// (for example left over by misnested tags)
+fn parse_html_stray_ending_tag(parser: &mut Parser, tag_name: &str) -> CompletedMarker {
+    debug_assert!(parser.at(T!["</"]));
+    parser.add_error(ParseErrorBuilder::new(format!("</{tag_name}> ending tag")));
+
+    let m = parser.start();
+    parser.bump();
+    if parser.at(T![word]) {
+        parser.bump();
+    }
+    if parser.at(T![">"]) {
+        parser.bump();
+    }
+    parser.complete(m, SyntaxKind::ERROR)
+}
+
 fn parse_html_attribute_or_twig(parser: &mut Parser) -> Option<CompletedMarker> {
     let token_text = parser.peek_token()?.text;
     let attribute_m = if HTML_NAME_REGEX.is_match(token_text) {
         // normal html attribute name
         let attribute_m = parser.start();
-        parser.bump_as(T![word]);
+        let name_token_amount = html_attribute_name_token_amount(parser);
+        parser.bump_merged_as(T![word], name_token_amount);
         attribute_m
     } else {
         // is the attribute name a twig var expression?
@@ -199,6 +309,33 @@ fn parse_html_attribute_or_twig(parser: &mut Parser) -> Option<CompletedMarker>
     Some(parser.complete(attribute_m, SyntaxKind::HTML_ATTRIBUTE))
 }
 
+/// Amount of adjacent tokens which form the attribute name.
+/// Framework directives like `v-on:click.prevent` or `@click.outside` are lexed as multiple tokens
+/// which are merged into one name.
+fn html_attribute_name_token_amount(parser: &mut Parser) -> usize {
+    let mut amount = 1;
+    loop {
+        let Some((kind, is_colon_word)) = parser
+            .peek_token_raw_at(amount)
+            .map(|t| (t.kind, t.text.starts_with(':')))
+        else {
+            return amount;
+        };
+
+        if kind == T![word] && is_colon_word {
+            amount += 1;
+        } else if kind == T!["."]
+            && parser
+                .peek_token_raw_at(amount + 1)
+                .is_some_and(|t| HTML_NAME_MODIFIER_REGEX.is_match(t.text))
+        {
+            amount += 2;
+        } else {
+            return amount;
+        }
+    }
+}
+
 /// html attribute value can be either a single word or twig var expression or
 /// a single / double quoted string (which can contain arbitrary twig syntax)
 /// In either case it will be wrapped into an `HTML_STRING` node which may or may
@@ -235,8 +372,19 @@ fn parse_html_attribute_value_string(parser: &mut Parser) -> CompletedMarker {
     }
 
     fn inner_no_quote_parser(parser: &mut Parser) -> Option<CompletedMarker> {
-        if parser.at(T![word]) {
-            parser.bump();
+        if parser
+            .peek_token()
+            .is_some_and(|t| !ends_unquoted_value(t.kind))
+        {
+            // adjacent tokens like in `100px` or `/some/path` are merged into a single word
+            let mut amount = 1;
+            while parser
+                .peek_token_raw_at(amount)
+                .is_some_and(|t| !t.kind.is_trivia() && !ends_unquoted_value(t.kind))
+            {
+                amount += 1;
+            }
+            parser.bump_merged_as(T![word], amount);
         } else if parser.at(T!["{{"]) {
             // a single twig var expression with missing quotes should also count as an html attribute value
             parse_twig_var_statement(parser);
@@ -279,10 +427,10 @@ fn parse_html_attribute_value_string(parser: &mut Parser) -> CompletedMarker {
     let inner_m = parser.start();
     // run the appropriate inner parser
     match quote_kind {
-        Some(quote_kind) if quote_kind == T!["\""] => {
+        Some(T!["\""]) => {
             inner_double_quote_parser(parser);
         }
-        Some(quote_kind) if quote_kind == T!["'"] => {
+        Some(T!["'"]) => {
             inner_single_quote_parser(parser);
         }
         None => {
@@ -318,11 +466,30 @@ fn parse_html_attribute_value_string(parser: &mut Parser) -> CompletedMarker {
     parser.complete(m, SyntaxKind::HTML_STRING)
 }
 
+/// Tokens which can't be part of an unquoted attribute value
+fn ends_unquoted_value(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        T![">"]
+            | T!["/>"]
+            | T!["\""]
+            | T!["'"]
+            | T!["="]
+            | T!["<"]
+            | T!["</"]
+            | T!["{{"]
+            | T!["{%"]
+            | T!["{#"]
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
 
-    use crate::parser::check_parse;
+    use crate::parser::{check_parse, parse, parse_with_options, ParseOptions};
+    use crate::syntax::typed::{AstNode, HtmlTag};
+    use crate::syntax::untyped::SyntaxNode;
 
     #[test]
     fn parse_simple_html_element() {
@@ -534,10 +701,10 @@ mod tests {
                       TK_WORD@1..4 "div"
                       HTML_ATTRIBUTE_LIST@4..4
                       TK_GREATER_THAN@4..5 ">"
-                    BODY@5..28
+                    BODY@5..22
                       HTML_TEXT@5..10
                         TK_WORD@5..10 "hello"
-                      HTML_TAG@10..28
+                      HTML_TAG@10..22
                         HTML_STARTING_TAG@10..16
                           TK_LESS_THAN@10..11 "<"
                           TK_WORD@11..15 "span"
@@ -547,28 +714,26 @@ mod tests {
                           HTML_TEXT@16..22
                             TK_WORD@16..21 "world"
                             TK_EXCLAMATION_MARK@21..22 "!"
-                        HTML_ENDING_TAG@22..28
-                          ERROR@22..28
-                            TK_LESS_THAN_SLASH@22..24 "</"
-                            TK_WORD@24..27 "div"
-                            TK_GREATER_THAN@27..28 ">"
-                    HTML_ENDING_TAG@28..28
-                error at 22..24: expected </span> ending tag but found </
-                error at 27..28: expected </div> ending tag but reached end of file"#]],
+                        HTML_ENDING_TAG@22..22
+                    HTML_ENDING_TAG@22..28
+                      TK_LESS_THAN_SLASH@22..24 "</"
+                      TK_WORD@24..27 "div"
+                      TK_GREATER_THAN@27..28 ">"
+                error at 22..24: expected </span> ending tag but found </"#]],
         );
     }
 
     #[test]
     fn parse_html_element_with_cutoff_closing_tag() {
         check_parse(
-            r#"<div>
+            r"<div>
             {% block a %}
             <p>
                 hello
             {% endblock %}
             <span>world</span>
             </p>
-            </div>"#,
+            </div>",
             expect![[r#"
                 ROOT@0..163
                   HTML_TAG@0..163
@@ -577,7 +742,7 @@ mod tests {
                       TK_WORD@1..4 "div"
                       HTML_ATTRIBUTE_LIST@4..4
                       TK_GREATER_THAN@4..5 ">"
-                    BODY@5..127
+                    BODY@5..144
                       TWIG_BLOCK@5..96
                         TWIG_STARTING_BLOCK@5..31
                           TK_LINE_BREAK@5..6 "\n"
@@ -627,18 +792,18 @@ mod tests {
                           TK_LESS_THAN_SLASH@120..122 "</"
                           TK_WORD@122..126 "span"
                           TK_GREATER_THAN@126..127 ">"
-                    HTML_ENDING_TAG@127..163
-                      ERROR@127..163
+                      ERROR@127..144
                         TK_LINE_BREAK@127..128 "\n"
                         TK_WHITESPACE@128..140 "            "
                         TK_LESS_THAN_SLASH@140..142 "</"
                         TK_WORD@142..143 "p"
                         TK_GREATER_THAN@143..144 ">"
-                        TK_LINE_BREAK@144..145 "\n"
-                        TK_WHITESPACE@145..157 "            "
-                        TK_LESS_THAN_SLASH@157..159 "</"
-                        TK_WORD@159..162 "div"
-                        TK_GREATER_THAN@162..163 ">"
+                    HTML_ENDING_TAG@144..163
+                      TK_LINE_BREAK@144..145 "\n"
+                      TK_WHITESPACE@145..157 "            "
+                      TK_LESS_THAN_SLASH@157..159 "</"
+                      TK_WORD@159..162 "div"
+                      TK_GREATER_THAN@162..163 ">"
                 error at 82..84: expected </p> ending tag but found {%
                 error at 140..142: expected </div> ending tag but found </"#]],
         );
@@ -679,7 +844,7 @@ mod tests {
                 error at 29..31: expected {% but found </
                 error at 29..31: expected endblock but found </
                 error at 29..31: expected %} but found </"#]],
-        )
+        );
     }
 
     #[test]
@@ -694,8 +859,8 @@ mod tests {
                       TK_WORD@1..4 "div"
                       HTML_ATTRIBUTE_LIST@4..4
                       TK_GREATER_THAN@4..5 ">"
-                    BODY@5..39
-                      TWIG_BLOCK@5..39
+                    BODY@5..33
+                      TWIG_BLOCK@5..33
                         TWIG_STARTING_BLOCK@5..22
                           TK_CURLY_PERCENT@5..7 "{%"
                           TK_WHITESPACE@7..8 " "
@@ -704,8 +869,8 @@ mod tests {
                           TK_WORD@14..19 "inner"
                           TK_WHITESPACE@19..20 " "
                           TK_PERCENT_CURLY@20..22 "%}"
-                        BODY@22..39
-                          HTML_TAG@22..39
+                        BODY@22..33
+                          HTML_TAG@22..33
                             HTML_STARTING_TAG@22..28
                               TK_LESS_THAN@22..23 "<"
                               TK_WORD@23..27 "span"
@@ -714,19 +879,17 @@ mod tests {
                             BODY@28..33
                               HTML_TEXT@28..33
                                 TK_WORD@28..33 "hello"
-                            HTML_ENDING_TAG@33..39
-                              ERROR@33..39
-                                TK_LESS_THAN_SLASH@33..35 "</"
-                                TK_WORD@35..38 "div"
-                                TK_GREATER_THAN@38..39 ">"
-                        TWIG_ENDING_BLOCK@39..39
-                    HTML_ENDING_TAG@39..39
+                            HTML_ENDING_TAG@33..33
+                        TWIG_ENDING_BLOCK@33..33
+                    HTML_ENDING_TAG@33..39
+                      TK_LESS_THAN_SLASH@33..35 "</"
+                      TK_WORD@35..38 "div"
+                      TK_GREATER_THAN@38..39 ">"
                 error at 33..35: expected </span> ending tag but found </
-                error at 38..39: expected {% but reached end of file
-                error at 38..39: expected endblock but reached end of file
-                error at 38..39: expected %} but reached end of file
-                error at 38..39: expected </div> ending tag but reached end of file"#]],
-        )
+                error at 33..35: expected {% but found </
+                error at 33..35: expected endblock but found </
+                error at 33..35: expected %} but found </"#]],
+        );
     }
 
     #[test]
@@ -1081,6 +1244,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_html_attribute_with_single_quotes_containing_double_quotes() {
+        check_parse(
+            r#"<button onclick='alert("hi")'></button>"#,
+            expect![[r#"
+                ROOT@0..39
+                  HTML_TAG@0..39
+                    HTML_STARTING_TAG@0..30
+                      TK_LESS_THAN@0..1 "<"
+                      TK_WORD@1..7 "button"
+                      HTML_ATTRIBUTE_LIST@7..29
+                        HTML_ATTRIBUTE@7..29
+                          TK_WHITESPACE@7..8 " "
+                          TK_WORD@8..15 "onclick"
+                          TK_EQUAL@15..16 "="
+                          HTML_STRING@16..29
+                            TK_SINGLE_QUOTES@16..17 "'"
+                            HTML_STRING_INNER@17..28
+                              TK_WORD@17..22 "alert"
+                              TK_OPEN_PARENTHESIS@22..23 "("
+                              TK_DOUBLE_QUOTES@23..24 "\""
+                              TK_WORD@24..26 "hi"
+                              TK_DOUBLE_QUOTES@26..27 "\""
+                              TK_CLOSE_PARENTHESIS@27..28 ")"
+                            TK_SINGLE_QUOTES@28..29 "'"
+                      TK_GREATER_THAN@29..30 ">"
+                    BODY@30..30
+                    HTML_ENDING_TAG@30..39
+                      TK_LESS_THAN_SLASH@30..32 "</"
+                      TK_WORD@32..38 "button"
+                      TK_GREATER_THAN@38..39 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_attribute_with_double_quotes_containing_single_quotes() {
+        check_parse(
+            r#"<button onclick="alert('hi')"></button>"#,
+            expect![[r#"
+                ROOT@0..39
+                  HTML_TAG@0..39
+                    HTML_STARTING_TAG@0..30
+                      TK_LESS_THAN@0..1 "<"
+                      TK_WORD@1..7 "button"
+                      HTML_ATTRIBUTE_LIST@7..29
+                        HTML_ATTRIBUTE@7..29
+                          TK_WHITESPACE@7..8 " "
+                          TK_WORD@8..15 "onclick"
+                          TK_EQUAL@15..16 "="
+                          HTML_STRING@16..29
+                            TK_DOUBLE_QUOTES@16..17 "\""
+                            HTML_STRING_INNER@17..28
+                              TK_WORD@17..22 "alert"
+                              TK_OPEN_PARENTHESIS@22..23 "("
+                              TK_SINGLE_QUOTES@23..24 "'"
+                              TK_WORD@24..26 "hi"
+                              TK_SINGLE_QUOTES@26..27 "'"
+                              TK_CLOSE_PARENTHESIS@27..28 ")"
+                            TK_DOUBLE_QUOTES@28..29 "\""
+                      TK_GREATER_THAN@29..30 ">"
+                    BODY@30..30
+                    HTML_ENDING_TAG@30..39
+                      TK_LESS_THAN_SLASH@30..32 "</"
+                      TK_WORD@32..38 "button"
+                      TK_GREATER_THAN@38..39 ">""#]],
+        );
+    }
+
     #[test]
     fn parse_html_attribute_with_trailing_single_quote_missing() {
         check_parse(
@@ -1284,6 +1515,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_html_attribute_with_no_quotes_and_number() {
+        check_parse(
+            r"<img width=100 height=50px disabled>",
+            expect![[r#"
+                ROOT@0..36
+                  HTML_TAG@0..36
+                    HTML_STARTING_TAG@0..36
+                      TK_LESS_THAN@0..1 "<"
+                      TK_WORD@1..4 "img"
+                      HTML_ATTRIBUTE_LIST@4..35
+                        HTML_ATTRIBUTE@4..14
+                          TK_WHITESPACE@4..5 " "
+                          TK_WORD@5..10 "width"
+                          TK_EQUAL@10..11 "="
+                          HTML_STRING@11..14
+                            HTML_STRING_INNER@11..14
+                              TK_WORD@11..14 "100"
+                        HTML_ATTRIBUTE@14..26
+                          TK_WHITESPACE@14..15 " "
+                          TK_WORD@15..21 "height"
+                          TK_EQUAL@21..22 "="
+                          HTML_STRING@22..26
+                            HTML_STRING_INNER@22..26
+                              TK_WORD@22..26 "50px"
+                        HTML_ATTRIBUTE@26..35
+                          TK_WHITESPACE@26..27 " "
+                          TK_WORD@27..35 "disabled"
+                      TK_GREATER_THAN@35..36 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_attribute_with_no_quotes_before_self_closing() {
+        check_parse(
+            r"<input value=foo/><a href=/some/path>link</a>",
+            expect![[r#"
+                ROOT@0..45
+                  HTML_TAG@0..18
+                    HTML_STARTING_TAG@0..18
+                      TK_LESS_THAN@0..1 "<"
+                      TK_WORD@1..6 "input"
+                      HTML_ATTRIBUTE_LIST@6..16
+                        HTML_ATTRIBUTE@6..16
+                          TK_WHITESPACE@6..7 " "
+                          TK_WORD@7..12 "value"
+                          TK_EQUAL@12..13 "="
+                          HTML_STRING@13..16
+                            HTML_STRING_INNER@13..16
+                              TK_WORD@13..16 "foo"
+                      TK_SLASH_GREATER_THAN@16..18 "/>"
+                  HTML_TAG@18..45
+                    HTML_STARTING_TAG@18..37
+                      TK_LESS_THAN@18..19 "<"
+                      TK_WORD@19..20 "a"
+                      HTML_ATTRIBUTE_LIST@20..36
+                        HTML_ATTRIBUTE@20..36
+                          TK_WHITESPACE@20..21 " "
+                          TK_WORD@21..25 "href"
+                          TK_EQUAL@25..26 "="
+                          HTML_STRING@26..36
+                            HTML_STRING_INNER@26..36
+                              TK_WORD@26..36 "/some/path"
+                      TK_GREATER_THAN@36..37 ">"
+                    BODY@37..41
+                      HTML_TEXT@37..41
+                        TK_WORD@37..41 "link"
+                    HTML_ENDING_TAG@41..45
+                      TK_LESS_THAN_SLASH@41..43 "</"
+                      TK_WORD@43..44 "a"
+                      TK_GREATER_THAN@44..45 ">""#]],
+        );
+    }
+
     #[test]
     fn parse_html_comment() {
         check_parse(
@@ -1321,6 +1626,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_html_comment_with_markup() {
+        check_parse(
+            "<div><!--\n<span>{{ debug }}</span>\n--></div>",
+            expect![[r#"
+                    ROOT@0..44
+                      HTML_TAG@0..44
+                        HTML_STARTING_TAG@0..5
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..4 "div"
+                          HTML_ATTRIBUTE_LIST@4..4
+                          TK_GREATER_THAN@4..5 ">"
+                        BODY@5..38
+                          HTML_COMMENT@5..38
+                            TK_LESS_THAN_EXCLAMATION_MARK_MINUS_MINUS@5..9 "<!--"
+                            TK_LINE_BREAK@9..10 "\n"
+                            TK_LESS_THAN@10..11 "<"
+                            TK_WORD@11..15 "span"
+                            TK_GREATER_THAN@15..16 ">"
+                            TK_OPEN_CURLY_CURLY@16..18 "{{"
+                            TK_WHITESPACE@18..19 " "
+                            TK_WORD@19..24 "debug"
+                            TK_WHITESPACE@24..25 " "
+                            TK_CLOSE_CURLY_CURLY@25..27 "}}"
+                            TK_LESS_THAN_SLASH@27..29 "</"
+                            TK_WORD@29..33 "span"
+                            TK_GREATER_THAN@33..34 ">"
+                            TK_LINE_BREAK@34..35 "\n"
+                            TK_MINUS_MINUS_GREATER_THAN@35..38 "-->"
+                        HTML_ENDING_TAG@38..44
+                          TK_LESS_THAN_SLASH@38..40 "</"
+                          TK_WORD@40..43 "div"
+                          TK_GREATER_THAN@43..44 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_void_elements_link_and_source() {
+        check_parse(
+            r#"<link rel="stylesheet" href="a.css"><video><source src="a.mp4"></video>"#,
+            expect![[r#"
+                    ROOT@0..71
+                      HTML_TAG@0..36
+                        HTML_STARTING_TAG@0..36
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..5 "link"
+                          HTML_ATTRIBUTE_LIST@5..35
+                            HTML_ATTRIBUTE@5..22
+                              TK_WHITESPACE@5..6 " "
+                              TK_WORD@6..9 "rel"
+                              TK_EQUAL@9..10 "="
+                              HTML_STRING@10..22
+                                TK_DOUBLE_QUOTES@10..11 "\""
+                                HTML_STRING_INNER@11..21
+                                  TK_WORD@11..21 "stylesheet"
+                                TK_DOUBLE_QUOTES@21..22 "\""
+                            HTML_ATTRIBUTE@22..35
+                              TK_WHITESPACE@22..23 " "
+                              TK_WORD@23..27 "href"
+                              TK_EQUAL@27..28 "="
+                              HTML_STRING@28..35
+                                TK_DOUBLE_QUOTES@28..29 "\""
+                                HTML_STRING_INNER@29..34
+                                  TK_WORD@29..30 "a"
+                                  TK_DOT@30..31 "."
+                                  TK_WORD@31..34 "css"
+                                TK_DOUBLE_QUOTES@34..35 "\""
+                          TK_GREATER_THAN@35..36 ">"
+                      HTML_TAG@36..71
+                        HTML_STARTING_TAG@36..43
+                          TK_LESS_THAN@36..37 "<"
+                          TK_WORD@37..42 "video"
+                          HTML_ATTRIBUTE_LIST@42..42
+                          TK_GREATER_THAN@42..43 ">"
+                        BODY@43..63
+                          HTML_TAG@43..63
+                            HTML_STARTING_TAG@43..63
+                              TK_LESS_THAN@43..44 "<"
+                              TK_WORD@44..50 "source"
+                              HTML_ATTRIBUTE_LIST@50..62
+                                HTML_ATTRIBUTE@50..62
+                                  TK_WHITESPACE@50..51 " "
+                                  TK_WORD@51..54 "src"
+                                  TK_EQUAL@54..55 "="
+                                  HTML_STRING@55..62
+                                    TK_DOUBLE_QUOTES@55..56 "\""
+                                    HTML_STRING_INNER@56..61
+                                      TK_WORD@56..57 "a"
+                                      TK_DOT@57..58 "."
+                                      TK_WORD@58..61 "mp4"
+                                    TK_DOUBLE_QUOTES@61..62 "\""
+                              TK_GREATER_THAN@62..63 ">"
+                        HTML_ENDING_TAG@63..71
+                          TK_LESS_THAN_SLASH@63..65 "</"
+                          TK_WORD@65..70 "video"
+                          TK_GREATER_THAN@70..71 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_additional_void_elements() {
+        let options = ParseOptions {
+            additional_void_elements: vec!["sw-icon".to_string()],
+        };
+        let parse = parse_with_options(r#"<div><sw-icon name="x"><span>a</span></div>"#, &options);
+        expect![[r#"
+            ROOT@0..43
+              HTML_TAG@0..43
+                HTML_STARTING_TAG@0..5
+                  TK_LESS_THAN@0..1 "<"
+                  TK_WORD@1..4 "div"
+                  HTML_ATTRIBUTE_LIST@4..4
+                  TK_GREATER_THAN@4..5 ">"
+                BODY@5..37
+                  HTML_TAG@5..23
+                    HTML_STARTING_TAG@5..23
+                      TK_LESS_THAN@5..6 "<"
+                      TK_WORD@6..13 "sw-icon"
+                      HTML_ATTRIBUTE_LIST@13..22
+                        HTML_ATTRIBUTE@13..22
+                          TK_WHITESPACE@13..14 " "
+                          TK_WORD@14..18 "name"
+                          TK_EQUAL@18..19 "="
+                          HTML_STRING@19..22
+                            TK_DOUBLE_QUOTES@19..20 "\""
+                            HTML_STRING_INNER@20..21
+                              TK_WORD@20..21 "x"
+                            TK_DOUBLE_QUOTES@21..22 "\""
+                      TK_GREATER_THAN@22..23 ">"
+                  HTML_TAG@23..37
+                    HTML_STARTING_TAG@23..29
+                      TK_LESS_THAN@23..24 "<"
+                      TK_WORD@24..28 "span"
+                      HTML_ATTRIBUTE_LIST@28..28
+                      TK_GREATER_THAN@28..29 ">"
+                    BODY@29..30
+                      HTML_TEXT@29..30
+                        TK_WORD@29..30 "a"
+                    HTML_ENDING_TAG@30..37
+                      TK_LESS_THAN_SLASH@30..32 "</"
+                      TK_WORD@32..36 "span"
+                      TK_GREATER_THAN@36..37 ">"
+                HTML_ENDING_TAG@37..43
+                  TK_LESS_THAN_SLASH@37..39 "</"
+                  TK_WORD@39..42 "div"
+                  TK_GREATER_THAN@42..43 ">""#]]
+        .assert_eq(&parse.debug_parse());
+    }
+
     #[test]
     fn test_html_self_closing_tag() {
         check_parse(
@@ -1810,7 +2264,7 @@ mod tests {
     #[test]
     fn parse_html_void_element_self_closing() {
         check_parse(
-            r#"<hr/>"#,
+            r"<hr/>",
             expect![[r#"
                 ROOT@0..5
                   HTML_TAG@0..5
@@ -1918,7 +2372,7 @@ mod tests {
                       TK_LESS_THAN_SLASH@23..25 "</"
                       TK_WORD@25..28 "div"
                       TK_GREATER_THAN@28..29 ">""#]],
-        )
+        );
     }
 
     #[test]
@@ -1933,6 +2387,360 @@ mod tests {
                 TK_WHITESPACE@9..10 " "
                 TK_WORD@10..14 "html"
                 TK_GREATER_THAN@14..15 ">""#]],
-        )
+        );
+    }
+
+    #[test]
+    fn parse_html_element_misnested_closing_tags() {
+        check_parse(
+            "<div><b><i>text</b></i></div>",
+            expect![[r#"
+            ROOT@0..29
+              HTML_TAG@0..29
+                HTML_STARTING_TAG@0..5
+                  TK_LESS_THAN@0..1 "<"
+                  TK_WORD@1..4 "div"
+                  HTML_ATTRIBUTE_LIST@4..4
+                  TK_GREATER_THAN@4..5 ">"
+                BODY@5..23
+                  HTML_TAG@5..19
+                    HTML_STARTING_TAG@5..8
+                      TK_LESS_THAN@5..6 "<"
+                      TK_WORD@6..7 "b"
+                      HTML_ATTRIBUTE_LIST@7..7
+                      TK_GREATER_THAN@7..8 ">"
+                    BODY@8..15
+                      HTML_TAG@8..15
+                        HTML_STARTING_TAG@8..11
+                          TK_LESS_THAN@8..9 "<"
+                          TK_WORD@9..10 "i"
+                          HTML_ATTRIBUTE_LIST@10..10
+                          TK_GREATER_THAN@10..11 ">"
+                        BODY@11..15
+                          HTML_TEXT@11..15
+                            TK_WORD@11..15 "text"
+                        HTML_ENDING_TAG@15..15
+                    HTML_ENDING_TAG@15..19
+                      TK_LESS_THAN_SLASH@15..17 "</"
+                      TK_WORD@17..18 "b"
+                      TK_GREATER_THAN@18..19 ">"
+                  ERROR@19..23
+                    TK_LESS_THAN_SLASH@19..21 "</"
+                    TK_WORD@21..22 "i"
+                    TK_GREATER_THAN@22..23 ">"
+                HTML_ENDING_TAG@23..29
+                  TK_LESS_THAN_SLASH@23..25 "</"
+                  TK_WORD@25..28 "div"
+                  TK_GREATER_THAN@28..29 ">"
+            error at 15..17: expected </i> ending tag but found </
+            error at 19..21: expected </div> ending tag but found </"#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_element_stray_closing_tag() {
+        check_parse(
+            "<div>a</span>b</div><p>c</p>",
+            expect![[r#"
+            ROOT@0..28
+              HTML_TAG@0..20
+                HTML_STARTING_TAG@0..5
+                  TK_LESS_THAN@0..1 "<"
+                  TK_WORD@1..4 "div"
+                  HTML_ATTRIBUTE_LIST@4..4
+                  TK_GREATER_THAN@4..5 ">"
+                BODY@5..14
+                  HTML_TEXT@5..6
+                    TK_WORD@5..6 "a"
+                  ERROR@6..13
+                    TK_LESS_THAN_SLASH@6..8 "</"
+                    TK_WORD@8..12 "span"
+                    TK_GREATER_THAN@12..13 ">"
+                  HTML_TEXT@13..14
+                    TK_WORD@13..14 "b"
+                HTML_ENDING_TAG@14..20
+                  TK_LESS_THAN_SLASH@14..16 "</"
+                  TK_WORD@16..19 "div"
+                  TK_GREATER_THAN@19..20 ">"
+              HTML_TAG@20..28
+                HTML_STARTING_TAG@20..23
+                  TK_LESS_THAN@20..21 "<"
+                  TK_WORD@21..22 "p"
+                  HTML_ATTRIBUTE_LIST@22..22
+                  TK_GREATER_THAN@22..23 ">"
+                BODY@23..24
+                  HTML_TEXT@23..24
+                    TK_WORD@23..24 "c"
+                HTML_ENDING_TAG@24..28
+                  TK_LESS_THAN_SLASH@24..26 "</"
+                  TK_WORD@26..27 "p"
+                  TK_GREATER_THAN@27..28 ">"
+            error at 6..8: expected </div> ending tag but found </"#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_element_with_framework_directive_attributes() {
+        check_parse(
+            r#"<div v-on:click.prevent="x" @click.outside="y" :class="z"></div>"#,
+            expect![[r#"
+                ROOT@0..64
+                  HTML_TAG@0..64
+                    HTML_STARTING_TAG@0..58
+                      TK_LESS_THAN@0..1 "<"
+                      TK_WORD@1..4 "div"
+                      HTML_ATTRIBUTE_LIST@4..57
+                        HTML_ATTRIBUTE@4..27
+                          TK_WHITESPACE@4..5 " "
+                          TK_WORD@5..23 "v-on:click.prevent"
+                          TK_EQUAL@23..24 "="
+                          HTML_STRING@24..27
+                            TK_DOUBLE_QUOTES@24..25 "\""
+                            HTML_STRING_INNER@25..26
+                              TK_WORD@25..26 "x"
+                            TK_DOUBLE_QUOTES@26..27 "\""
+                        HTML_ATTRIBUTE@27..46
+                          TK_WHITESPACE@27..28 " "
+                          TK_WORD@28..42 "@click.outside"
+                          TK_EQUAL@42..43 "="
+                          HTML_STRING@43..46
+                            TK_DOUBLE_QUOTES@43..44 "\""
+                            HTML_STRING_INNER@44..45
+                              TK_WORD@44..45 "y"
+                            TK_DOUBLE_QUOTES@45..46 "\""
+                        HTML_ATTRIBUTE@46..57
+                          TK_WHITESPACE@46..47 " "
+                          TK_WORD@47..53 ":class"
+                          TK_EQUAL@53..54 "="
+                          HTML_STRING@54..57
+                            TK_DOUBLE_QUOTES@54..55 "\""
+                            HTML_STRING_INNER@55..56
+                              TK_WORD@55..56 "z"
+                            TK_DOUBLE_QUOTES@56..57 "\""
+                      TK_GREATER_THAN@57..58 ">"
+                    BODY@58..58
+                    HTML_ENDING_TAG@58..64
+                      TK_LESS_THAN_SLASH@58..60 "</"
+                      TK_WORD@60..63 "div"
+                      TK_GREATER_THAN@63..64 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_script_content_as_raw_text() {
+        check_parse(
+            "<script>if (a < b) { c = '<div>'; }</script>",
+            expect![[r#"
+                    ROOT@0..44
+                      HTML_TAG@0..44
+                        HTML_STARTING_TAG@0..8
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..7 "script"
+                          HTML_ATTRIBUTE_LIST@7..7
+                          TK_GREATER_THAN@7..8 ">"
+                        BODY@8..35
+                          HTML_RAW_TEXT@8..35
+                            TK_IF@8..10 "if"
+                            TK_WHITESPACE@10..11 " "
+                            TK_OPEN_PARENTHESIS@11..12 "("
+                            TK_WORD@12..13 "a"
+                            TK_WHITESPACE@13..14 " "
+                            TK_LESS_THAN@14..15 "<"
+                            TK_WHITESPACE@15..16 " "
+                            TK_WORD@16..17 "b"
+                            TK_CLOSE_PARENTHESIS@17..18 ")"
+                            TK_WHITESPACE@18..19 " "
+                            TK_OPEN_CURLY@19..20 "{"
+                            TK_WHITESPACE@20..21 " "
+                            TK_WORD@21..22 "c"
+                            TK_WHITESPACE@22..23 " "
+                            TK_EQUAL@23..24 "="
+                            TK_WHITESPACE@24..25 " "
+                            TK_SINGLE_QUOTES@25..26 "'"
+                            TK_LESS_THAN@26..27 "<"
+                            TK_WORD@27..30 "div"
+                            TK_GREATER_THAN@30..31 ">"
+                            TK_SINGLE_QUOTES@31..32 "'"
+                            TK_SEMICOLON@32..33 ";"
+                            TK_WHITESPACE@33..34 " "
+                            TK_CLOSE_CURLY@34..35 "}"
+                        HTML_ENDING_TAG@35..44
+                          TK_LESS_THAN_SLASH@35..37 "</"
+                          TK_WORD@37..43 "script"
+                          TK_GREATER_THAN@43..44 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_script_content_with_twig() {
+        check_parse(
+            "<script>const url = '{{ path }}';{% if debug %}console.log(url < 1);{% endif %}</script>",
+            expect![[r#"
+                    ROOT@0..88
+                      HTML_TAG@0..88
+                        HTML_STARTING_TAG@0..8
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..7 "script"
+                          HTML_ATTRIBUTE_LIST@7..7
+                          TK_GREATER_THAN@7..8 ">"
+                        BODY@8..79
+                          HTML_RAW_TEXT@8..21
+                            TK_WORD@8..13 "const"
+                            TK_WHITESPACE@13..14 " "
+                            TK_WORD@14..17 "url"
+                            TK_WHITESPACE@17..18 " "
+                            TK_EQUAL@18..19 "="
+                            TK_WHITESPACE@19..20 " "
+                            TK_SINGLE_QUOTES@20..21 "'"
+                          TWIG_VAR@21..31
+                            TK_OPEN_CURLY_CURLY@21..23 "{{"
+                            TWIG_EXPRESSION@23..28
+                              TWIG_LITERAL_NAME@23..28
+                                TK_WHITESPACE@23..24 " "
+                                TK_WORD@24..28 "path"
+                            TK_WHITESPACE@28..29 " "
+                            TK_CLOSE_CURLY_CURLY@29..31 "}}"
+                          HTML_RAW_TEXT@31..33
+                            TK_SINGLE_QUOTES@31..32 "'"
+                            TK_SEMICOLON@32..33 ";"
+                          TWIG_IF@33..79
+                            TWIG_IF_BLOCK@33..47
+                              TK_CURLY_PERCENT@33..35 "{%"
+                              TK_WHITESPACE@35..36 " "
+                              TK_IF@36..38 "if"
+                              TWIG_EXPRESSION@38..44
+                                TWIG_LITERAL_NAME@38..44
+                                  TK_WHITESPACE@38..39 " "
+                                  TK_WORD@39..44 "debug"
+                              TK_WHITESPACE@44..45 " "
+                              TK_PERCENT_CURLY@45..47 "%}"
+                            BODY@47..68
+                              HTML_RAW_TEXT@47..68
+                                TK_WORD@47..54 "console"
+                                TK_DOT@54..55 "."
+                                TK_WORD@55..58 "log"
+                                TK_OPEN_PARENTHESIS@58..59 "("
+                                TK_WORD@59..62 "url"
+                                TK_WHITESPACE@62..63 " "
+                                TK_LESS_THAN@63..64 "<"
+                                TK_WHITESPACE@64..65 " "
+                                TK_NUMBER@65..66 "1"
+                                TK_CLOSE_PARENTHESIS@66..67 ")"
+                                TK_SEMICOLON@67..68 ";"
+                            TWIG_ENDIF_BLOCK@68..79
+                              TK_CURLY_PERCENT@68..70 "{%"
+                              TK_WHITESPACE@70..71 " "
+                              TK_ENDIF@71..76 "endif"
+                              TK_WHITESPACE@76..77 " "
+                              TK_PERCENT_CURLY@77..79 "%}"
+                        HTML_ENDING_TAG@79..88
+                          TK_LESS_THAN_SLASH@79..81 "</"
+                          TK_WORD@81..87 "script"
+                          TK_GREATER_THAN@87..88 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_style_content_with_uppercase_ending_tag() {
+        check_parse(
+            "<style>a > b { color: red; }</STYLE>",
+            expect![[r#"
+                    ROOT@0..36
+                      HTML_TAG@0..36
+                        HTML_STARTING_TAG@0..7
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..6 "style"
+                          HTML_ATTRIBUTE_LIST@6..6
+                          TK_GREATER_THAN@6..7 ">"
+                        BODY@7..28
+                          HTML_RAW_TEXT@7..28
+                            TK_WORD@7..8 "a"
+                            TK_WHITESPACE@8..9 " "
+                            TK_GREATER_THAN@9..10 ">"
+                            TK_WHITESPACE@10..11 " "
+                            TK_WORD@11..12 "b"
+                            TK_WHITESPACE@12..13 " "
+                            TK_OPEN_CURLY@13..14 "{"
+                            TK_WHITESPACE@14..15 " "
+                            TK_WORD@15..20 "color"
+                            TK_COLON@20..21 ":"
+                            TK_WHITESPACE@21..22 " "
+                            TK_WORD@22..25 "red"
+                            TK_SEMICOLON@25..26 ";"
+                            TK_WHITESPACE@26..27 " "
+                            TK_CLOSE_CURLY@27..28 "}"
+                        HTML_ENDING_TAG@28..36
+                          TK_LESS_THAN_SLASH@28..30 "</"
+                          TK_WORD@30..35 "STYLE"
+                          TK_GREATER_THAN@35..36 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_script_content_with_escaped_ending_tag_in_string() {
+        check_parse(
+            r#"<script>document.write("<script><\/script>");</script>"#,
+            expect![[r#"
+                    ROOT@0..54
+                      HTML_TAG@0..54
+                        HTML_STARTING_TAG@0..8
+                          TK_LESS_THAN@0..1 "<"
+                          TK_WORD@1..7 "script"
+                          HTML_ATTRIBUTE_LIST@7..7
+                          TK_GREATER_THAN@7..8 ">"
+                        BODY@8..45
+                          HTML_RAW_TEXT@8..45
+                            TK_WORD@8..16 "document"
+                            TK_DOT@16..17 "."
+                            TK_WORD@17..22 "write"
+                            TK_OPEN_PARENTHESIS@22..23 "("
+                            TK_DOUBLE_QUOTES@23..24 "\""
+                            TK_LESS_THAN@24..25 "<"
+                            TK_WORD@25..31 "script"
+                            TK_GREATER_THAN@31..32 ">"
+                            TK_LESS_THAN@32..33 "<"
+                            TK_BACKWARD_SLASH@33..34 "\\"
+                            TK_FORWARD_SLASH@34..35 "/"
+                            TK_WORD@35..41 "script"
+                            TK_GREATER_THAN@41..42 ">"
+                            TK_DOUBLE_QUOTES@42..43 "\""
+                            TK_CLOSE_PARENTHESIS@43..44 ")"
+                            TK_SEMICOLON@44..45 ";"
+                        HTML_ENDING_TAG@45..54
+                          TK_LESS_THAN_SLASH@45..47 "</"
+                          TK_WORD@47..53 "script"
+                          TK_GREATER_THAN@53..54 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_html_attributes_in_source_order() {
+        let parse = parse("<div style=\"a\" id=\"b\" class=\"c\" data-z data-a class=\"d\"></div>");
+        let root = SyntaxNode::new_root(parse.green_node);
+        let tag = root.children().find_map(HtmlTag::cast).unwrap();
+
+        let attributes: Vec<(String, Option<String>)> = tag
+            .attributes()
+            .map(|attribute| {
+                (
+                    attribute.name().unwrap().text().to_owned(),
+                    attribute
+                        .value()
+                        .and_then(|value| value.get_inner())
+                        .map(|inner| inner.syntax().text().to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            attributes,
+            vec![
+                ("style".to_owned(), Some("a".to_owned())),
+                ("id".to_owned(), Some("b".to_owned())),
+                ("class".to_owned(), Some("c".to_owned())),
+                ("data-z".to_owned(), None),
+                ("data-a".to_owned(), None),
+                ("class".to_owned(), Some("d".to_owned())),
+            ]
+        );
     }
 }
@@ -34,7 +34,11 @@ fn parse_twig_comment_statement(parser: &mut Parser) -> CompletedMarker {
     let m = parser.start();
     parser.bump();
 
-    if parser.at_set(&[T!["ludtwig-ignore-file"], T!["ludtwig-ignore"]]) {
+    if parser.at_set(&[
+        T!["ludtwig-ignore-file"],
+        T!["ludtwig-ignore"],
+        T!["ludtwig-config"],
+    ]) {
         parse_ludtwig_directive(parser, m, T!["#}"])
     } else {
         parse_twig_plain_comment(parser, m)
@@ -100,7 +104,7 @@ mod tests {
                         TK_WORD@25..29 "else"
                     TK_WHITESPACE@29..30 " "
                     TK_CLOSE_CURLY_CURLY@30..32 "}}""#]],
-        )
+        );
     }
 
     #[test]
@@ -137,6 +141,6 @@ mod tests {
                     TK_PERCENT_CURLY@53..55 "%}"
                     TK_WHITESPACE@55..56 " "
                     TK_HASHTAG_CLOSE_CURLY@56..58 "#}""##]],
-        )
+        );
     }
 }
@@ -9,7 +9,7 @@ use regex::Regex;
 
 // TODO: maybe allow more here to partly support twig.js. Needs testing on real world templates
 pub static TWIG_NAME_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r#"^[a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*$"#).unwrap());
+    Lazy::new(|| Regex::new(r"^[a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*$").unwrap());
 
 pub(crate) fn parse_twig_literal(parser: &mut Parser) -> Option<CompletedMarker> {
     if parser.at(T![number]) {
@@ -36,7 +36,7 @@ pub(crate) fn parse_twig_literal(parser: &mut Parser) -> Option<CompletedMarker>
     }
 }
 
-/// Parses any amount of postfix operators like accessors (.), indexer ([]), function calls() or filters(|)
+/// Parses any amount of postfix operators like accessors (.), indexer ([]), function `calls()` or filters(|)
 pub(crate) fn parse_postfix_operators(
     parser: &mut Parser,
     mut node: CompletedMarker,
@@ -1218,7 +1218,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_get_attribute_expression() {
         check_parse(
-            r#"{{ product.prices.euro }}"#,
+            r"{{ product.prices.euro }}",
             expect![[r#"
                 ROOT@0..25
                   TWIG_VAR@0..25
@@ -1247,7 +1247,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_with_filters() {
         check_parse(
-            r#"{{ product.price|striptags|title }}"#,
+            r"{{ product.price|striptags|title }}",
             expect![[r#"
                 ROOT@0..35
                   TWIG_VAR@0..35
@@ -1282,7 +1282,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_array_accessor() {
         check_parse(
-            r#"{{ product.prices['eur'] }}"#,
+            r"{{ product.prices['eur'] }}",
             expect![[r#"
                 ROOT@0..27
                   TWIG_VAR@0..27
@@ -1316,7 +1316,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_nested_array_accessor() {
         check_parse(
-            r#"{{ product.prices['eur'][0] }}"#,
+            r"{{ product.prices['eur'][0] }}",
             expect![[r#"
                 ROOT@0..30
                   TWIG_VAR@0..30
@@ -1358,7 +1358,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_array_range_accessor() {
         check_parse(
-            r#"{{ prices[0:10] }}"#,
+            r"{{ prices[0:10] }}",
             expect![[r#"
                 ROOT@0..18
                   TWIG_VAR@0..18
@@ -1387,7 +1387,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_array_range_left_accessor() {
         check_parse(
-            r#"{{ prices[10:] }}"#,
+            r"{{ prices[10:] }}",
             expect![[r#"
                 ROOT@0..17
                   TWIG_VAR@0..17
@@ -1414,7 +1414,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_array_range_right_accessor() {
         check_parse(
-            r#"{{ prices[:10] }}"#,
+            r"{{ prices[:10] }}",
             expect![[r#"
                 ROOT@0..17
                   TWIG_VAR@0..17
@@ -1440,7 +1440,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_accessor_indexer_and_filter() {
         check_parse(
-            r#"{{ product.prices['eur'][0]|title }}"#,
+            r"{{ product.prices['eur'][0]|title }}",
             expect![[r#"
                 ROOT@0..36
                   TWIG_VAR@0..36
@@ -1488,7 +1488,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_function_accessor() {
         check_parse(
-            r#"{{ product.prices('eur').gross }}"#,
+            r"{{ product.prices('eur').gross }}",
             expect![[r#"
                 ROOT@0..33
                   TWIG_VAR@0..33
@@ -1528,7 +1528,7 @@ mod tests {
     #[test]
     fn parse_twig_variable_deep_function_accessor() {
         check_parse(
-            r#"{{ product.prices.gross('eur').gross }}"#,
+            r"{{ product.prices.gross('eur').gross }}",
             expect![[r#"
                 ROOT@0..39
                   TWIG_VAR@0..39
@@ -1574,7 +1574,7 @@ mod tests {
     #[test]
     fn parse_twig_function() {
         check_parse(
-            r#"{{ doIt() }}"#,
+            r"{{ doIt() }}",
             expect![[r#"
                 ROOT@0..12
                   TWIG_VAR@0..12
@@ -1596,7 +1596,7 @@ mod tests {
     #[test]
     fn parse_twig_function_arguments() {
         check_parse(
-            r#"{{ sum(1, 2) }}"#,
+            r"{{ sum(1, 2) }}",
             expect![[r#"
                 ROOT@0..15
                   TWIG_VAR@0..15
@@ -1626,7 +1626,7 @@ mod tests {
     #[test]
     fn parse_twig_function_named_arguments() {
         check_parse(
-            r#"{{ sum(a=1, b=2) }}"#,
+            r"{{ sum(a=1, b=2) }}",
             expect![[r#"
                 ROOT@0..19
                   TWIG_VAR@0..19
@@ -1662,7 +1662,7 @@ mod tests {
     #[test]
     fn parse_twig_function_mixed_named_arguments() {
         check_parse(
-            r#"{{ sum(1, b=my_number) }}"#,
+            r"{{ sum(1, b=my_number) }}",
             expect![[r#"
                 ROOT@0..25
                   TWIG_VAR@0..25
@@ -1695,7 +1695,7 @@ mod tests {
     #[test]
     fn parse_twig_function_nested_call() {
         check_parse(
-            r#"{{ sum(1, sin(1)) }}"#,
+            r"{{ sum(1, sin(1)) }}",
             expect![[r#"
                 ROOT@0..20
                   TWIG_VAR@0..20
@@ -1733,7 +1733,7 @@ mod tests {
     #[test]
     fn parse_twig_filter_arguments() {
         check_parse(
-            r#"{{ list|join(', ') }}"#,
+            r"{{ list|join(', ') }}",
             expect![[r#"
                 ROOT@0..21
                   TWIG_VAR@0..21
@@ -1766,7 +1766,7 @@ mod tests {
     #[test]
     fn parse_twig_double_filter_arguments() {
         check_parse(
-            r#"{{ list|join(', ')|trim }}"#,
+            r"{{ list|join(', ')|trim }}",
             expect![[r#"
                 ROOT@0..26
                   TWIG_VAR@0..26
@@ -1860,7 +1860,7 @@ mod tests {
     #[test]
     fn parse_twig_filter_within_binary_comparison() {
         check_parse(
-            r#"{{ users|length > 0 }}"#,
+            r"{{ users|length > 0 }}",
             expect![[r#"
                 ROOT@0..22
                   TWIG_VAR@0..22
@@ -1891,7 +1891,7 @@ mod tests {
     #[test]
     fn parse_twig_include_function_call() {
         check_parse(
-            r#"{{ include('sections/articles/sidebar.html') }}"#,
+            r"{{ include('sections/articles/sidebar.html') }}",
             expect![[r#"
                 ROOT@0..47
                   TWIG_VAR@0..47
@@ -225,7 +225,7 @@ mod tests {
                       TK_SINGLE_QUOTES@52..53 "'"
                     TK_WHITESPACE@53..54 " "
                     TK_PERCENT_CURLY@54..56 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -257,7 +257,7 @@ mod tests {
                         TK_SINGLE_QUOTES@59..60 "'"
                     TK_WHITESPACE@60..61 " "
                     TK_PERCENT_CURLY@61..63 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -337,7 +337,7 @@ mod tests {
                           TK_CLOSE_CURLY@117..118 "}"
                     TK_WHITESPACE@118..119 " "
                     TK_PERCENT_CURLY@119..121 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -394,13 +394,13 @@ mod tests {
                       TK_ENDSW_SILENT_FEATURE_CALL@85..110 "endsw_silent_feature_ ..."
                       TK_WHITESPACE@110..111 " "
                       TK_PERCENT_CURLY@111..113 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_return() {
         check_parse(
-            r#"{% return %}"#,
+            r"{% return %}",
             expect![[r#"
             ROOT@0..12
               SHOPWARE_RETURN@0..12
@@ -409,13 +409,13 @@ mod tests {
                 TK_RETURN@3..9 "return"
                 TK_WHITESPACE@9..10 " "
                 TK_PERCENT_CURLY@10..12 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_return_value() {
         check_parse(
-            r#"{% return 5 %}"#,
+            r"{% return 5 %}",
             expect![[r#"
             ROOT@0..14
               SHOPWARE_RETURN@0..14
@@ -428,13 +428,13 @@ mod tests {
                     TK_NUMBER@10..11 "5"
                 TK_WHITESPACE@11..12 " "
                 TK_PERCENT_CURLY@12..14 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_return_expression() {
         check_parse(
-            r#"{% return not compare('=', 'foo', test) %}"#,
+            r"{% return not compare('=', 'foo', test) %}",
             expect![[r#"
                 ROOT@0..42
                   SHOPWARE_RETURN@0..42
@@ -475,13 +475,13 @@ mod tests {
                             TK_CLOSE_PARENTHESIS@38..39 ")"
                     TK_WHITESPACE@39..40 " "
                     TK_PERCENT_CURLY@40..42 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_icon() {
         check_parse(
-            r#"{% sw_icon 'clock' %}"#,
+            r"{% sw_icon 'clock' %}",
             expect![[r#"
             ROOT@0..21
               SHOPWARE_ICON@0..21
@@ -497,13 +497,13 @@ mod tests {
                     TK_SINGLE_QUOTES@17..18 "'"
                 TK_WHITESPACE@18..19 " "
                 TK_PERCENT_CURLY@19..21 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_icon_with_style() {
         check_parse(
-            r#"{% sw_icon 'minus' style { 'size': 'xs' } %}"#,
+            r"{% sw_icon 'minus' style { 'size': 'xs' } %}",
             expect![[r#"
                 ROOT@0..44
                   SHOPWARE_ICON@0..44
@@ -545,13 +545,13 @@ mod tests {
                           TK_CLOSE_CURLY@40..41 "}"
                     TK_WHITESPACE@41..42 " "
                     TK_PERCENT_CURLY@42..44 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_thumbnails() {
         check_parse(
-            r#"{% sw_thumbnails 'cart-item-img-thumbnails' %}"#,
+            r"{% sw_thumbnails 'cart-item-img-thumbnails' %}",
             expect![[r#"
                 ROOT@0..46
                   SHOPWARE_THUMBNAILS@0..46
@@ -567,13 +567,13 @@ mod tests {
                         TK_SINGLE_QUOTES@42..43 "'"
                     TK_WHITESPACE@43..44 " "
                     TK_PERCENT_CURLY@44..46 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_shopware_thumbnails_with() {
         check_parse(
-            r#"{% sw_thumbnails 'product-image-thumbnails' with { media: product.cover.media } %}"#,
+            r"{% sw_thumbnails 'product-image-thumbnails' with { media: product.cover.media } %}",
             expect![[r#"
                 ROOT@0..82
                   SHOPWARE_THUMBNAILS@0..82
@@ -620,6 +620,6 @@ mod tests {
                           TK_CLOSE_CURLY@78..79 "}"
                     TK_WHITESPACE@79..80 " "
                     TK_PERCENT_CURLY@80..82 "%}""#]],
-        )
+        );
     }
 }
@@ -32,6 +32,7 @@ pub(crate) fn at_twig_termination_tag(p: &mut Parser) -> bool {
         || p.at_following(&[T!["{%"], T!["endmacro"]])
         || p.at_following(&[T!["{%"], T!["endwith"]])
         || p.at_following(&[T!["{%"], T!["endcache"]])
+        || p.at_following(&[T!["{%"], T!["endtrans"]])
         || p.at_following(&[T!["{%"], T!["endsw_silent_feature_call"]])
         || p.at_following(&[T!["{%"], T!["else"]])
         || p.at_following(&[T!["{%"], T!["else"]])
@@ -85,6 +86,8 @@ pub(crate) fn parse_twig_block_statement(
         Some(parse_twig_with(parser, m, child_parser))
     } else if parser.at(T!["cache"]) {
         Some(parse_twig_cache(parser, m, child_parser))
+    } else if parser.at(T!["trans"]) {
+        Some(parse_twig_trans(parser, m, child_parser))
     } else {
         match parse_shopware_twig_block_statement(parser, m, child_parser) {
             BlockParseResult::NothingFound(m) => {
@@ -170,6 +173,67 @@ fn parse_twig_cache(
     parser.complete(wrapper_m, SyntaxKind::TWIG_CACHE)
 }
 
+fn parse_twig_trans(
+    parser: &mut Parser,
+    outer: Marker,
+    child_parser: ParseFunction,
+) -> CompletedMarker {
+    debug_assert!(parser.at(T!["trans"]));
+    parser.bump();
+
+    if parser.at(T!["with"]) {
+        parser.bump();
+        if parse_twig_expression(parser).is_none() {
+            parser.add_error(ParseErrorBuilder::new(
+                "twig expression as translation variables",
+            ));
+            parser.recover(&[T!["from"], T!["into"], T!["endtrans"], T!["%}"], T!["</"]]);
+        }
+    }
+    if parser.at(T!["from"]) {
+        parser.bump();
+        if parse_twig_expression(parser).is_none() {
+            parser.add_error(ParseErrorBuilder::new(
+                "twig expression as translation domain",
+            ));
+            parser.recover(&[T!["into"], T!["endtrans"], T!["%}"], T!["</"]]);
+        }
+    }
+    if parser.at(T!["into"]) {
+        parser.bump();
+        if parse_twig_expression(parser).is_none() {
+            parser.add_error(ParseErrorBuilder::new(
+                "twig expression as translation locale",
+            ));
+            parser.recover(&[T!["endtrans"], T!["%}"], T!["</"]]);
+        }
+    }
+    parser.expect(T!["%}"], &[T!["endtrans"], T!["%}"], T!["</"]]);
+
+    let wrapper_m = parser.complete(outer, SyntaxKind::TWIG_TRANS_STARTING_BLOCK);
+    let wrapper_m = parser.precede(wrapper_m);
+
+    // parse all the children except endtrans
+    let body_m = parser.start();
+    parse_many(
+        parser,
+        |p| p.at_following(&[T!["{%"], T!["endtrans"]]),
+        |p| {
+            child_parser(p);
+        },
+    );
+    parser.complete(body_m, SyntaxKind::BODY);
+
+    let end_block_m = parser.start();
+    parser.expect(T!["{%"], &[T!["endtrans"], T!["%}"], T!["</"]]);
+    parser.expect(T!["endtrans"], &[T!["%}"], T!["</"]]);
+    parser.expect(T!["%}"], &[T!["</"]]);
+    parser.complete(end_block_m, SyntaxKind::TWIG_TRANS_ENDING_BLOCK);
+
+    // close overall twig trans
+    parser.complete(wrapper_m, SyntaxKind::TWIG_TRANS)
+}
+
 fn parse_twig_with(
     parser: &mut Parser,
     outer: Marker,
@@ -264,8 +328,7 @@ fn parse_twig_macro(
         if let Some(macro_name) = macro_name {
             if end_macro_name_token.text != macro_name {
                 let parser_err = ParseErrorBuilder::new(format!(
-                    "nothing or same twig macro name as opening ({})",
-                    macro_name
+                    "nothing or same twig macro name as opening ({macro_name})"
                 ))
                 .at_token(end_macro_name_token);
 
@@ -697,22 +760,28 @@ fn parse_twig_include(parser: &mut Parser, outer: Marker) -> CompletedMarker {
         ]);
     }
 
-    if parser.at(T!["ignore missing"]) {
-        parser.bump();
-    }
-
-    if parser.at(T!["with"]) {
-        let with_value_m = parser.start();
-        parser.bump();
-        if parse_twig_expression(parser).is_none() {
-            parser.add_error(ParseErrorBuilder::new("twig expression as with value"));
-            parser.recover(&[T!["only"], T!["%}"], T!["</"]]);
+    // twig only accepts the order 'ignore missing', 'with', 'only' but any order is parsed here
+    // (each modifier at most once), so a rule can report and fix the order
+    let mut parsed_modifiers: Vec<SyntaxKind> = vec![];
+    loop {
+        if parser.at(T!["ignore missing"]) && !parsed_modifiers.contains(&T!["ignore missing"]) {
+            parser.bump();
+            parsed_modifiers.push(T!["ignore missing"]);
+        } else if parser.at(T!["with"]) && !parsed_modifiers.contains(&T!["with"]) {
+            let with_value_m = parser.start();
+            parser.bump();
+            if parse_twig_expression(parser).is_none() {
+                parser.add_error(ParseErrorBuilder::new("twig expression as with value"));
+                parser.recover(&[T!["ignore missing"], T!["only"], T!["%}"], T!["</"]]);
+            }
+            parser.complete(with_value_m, SyntaxKind::TWIG_INCLUDE_WITH);
+            parsed_modifiers.push(T!["with"]);
+        } else if parser.at(T!["only"]) && !parsed_modifiers.contains(&T!["only"]) {
+            parser.bump();
+            parsed_modifiers.push(T!["only"]);
+        } else {
+            break;
         }
-        parser.complete(with_value_m, SyntaxKind::TWIG_INCLUDE_WITH);
-    }
-
-    if parser.at(T!["only"]) {
-        parser.bump();
     }
 
     parser.expect(T!["%}"], &[T!["</"]]);
@@ -875,14 +944,12 @@ fn parse_twig_set(
 
         if declaration_count != assignment_count {
             parser.add_error(ParseErrorBuilder::new(format!(
-                "a total of {} twig expressions (same amount as declarations) instead of {}",
-                declaration_count, assignment_count
+                "a total of {declaration_count} twig expressions (same amount as declarations) instead of {assignment_count}"
             )));
         }
     } else if declaration_count > 1 {
         parser.add_error(ParseErrorBuilder::new(format!(
-            "= followed by {} twig expressions",
-            declaration_count
+            "= followed by {declaration_count} twig expressions"
         )));
     }
 
@@ -963,8 +1030,7 @@ fn parse_twig_block(
             if let Some(block_name) = block_name {
                 if end_block_name_token.text != block_name {
                     let parser_err = ParseErrorBuilder::new(format!(
-                        "nothing or same twig block name as opening ({})",
-                        block_name
+                        "nothing or same twig block name as opening ({block_name})"
                     ))
                     .at_token(end_block_name_token);
 
@@ -1071,7 +1137,7 @@ mod tests {
                     TK_WHITESPACE@2..3 " "
                     TK_WORD@3..7 "asdf"
                 error at 3..7: expected twig tag but found word"#]],
-        )
+        );
     }
 
     #[test]
@@ -1210,7 +1276,7 @@ mod tests {
                   TK_ENDIF@24..29 "endif"
                   TK_WHITESPACE@29..30 " "
                   TK_PERCENT_CURLY@30..32 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1264,7 +1330,7 @@ mod tests {
                   TK_ENDIF@55..60 "endif"
                   TK_WHITESPACE@60..61 " "
                   TK_PERCENT_CURLY@61..63 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1306,7 +1372,7 @@ mod tests {
                   TK_ENDIF@41..46 "endif"
                   TK_WHITESPACE@46..47 " "
                   TK_PERCENT_CURLY@47..49 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1352,7 +1418,7 @@ mod tests {
                   TK_ENDIF@37..42 "endif"
                   TK_WHITESPACE@42..43 " "
                   TK_PERCENT_CURLY@43..45 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1409,7 +1475,7 @@ mod tests {
                   TK_ENDIF@54..59 "endif"
                   TK_WHITESPACE@59..60 " "
                   TK_PERCENT_CURLY@60..62 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1481,7 +1547,7 @@ mod tests {
                   TK_ENDIF@73..78 "endif"
                   TK_WHITESPACE@78..79 " "
                   TK_PERCENT_CURLY@79..81 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1515,7 +1581,7 @@ mod tests {
                   TK_ENDBLOCK@41..49 "endblock"
                   TK_WHITESPACE@49..50 " "
                   TK_PERCENT_CURLY@50..52 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1538,7 +1604,7 @@ mod tests {
             error at 18..20: expected {% but reached end of file
             error at 18..20: expected endblock but reached end of file
             error at 18..20: expected %} but reached end of file"#]],
-        )
+        );
     }
 
     #[test]
@@ -1597,7 +1663,7 @@ mod tests {
                   TK_WORD@107..114 "sidebar"
                   TK_WHITESPACE@114..115 " "
                   TK_PERCENT_CURLY@115..117 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1657,7 +1723,7 @@ mod tests {
                   TK_WHITESPACE@108..109 " "
                   TK_PERCENT_CURLY@109..111 "%}"
             error at 78..85: expected nothing or same twig block name as opening (inner_sidebar) but found word"#]],
-        )
+        );
     }
 
     #[test]
@@ -1685,7 +1751,7 @@ mod tests {
                               TK_WORD@26..31 "title"
                       TK_WHITESPACE@31..32 " "
                       TK_PERCENT_CURLY@32..34 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -1714,15 +1780,15 @@ mod tests {
                     TK_SINGLE_QUOTES@17..18 "'"
               TK_WHITESPACE@18..19 " "
               TK_PERCENT_CURLY@19..21 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_capturing_set() {
         check_parse(
-            r#"{% set foo %}
+            r"{% set foo %}
     hello world
-{% endset %}"#,
+{% endset %}",
             expect![[r#"
                 ROOT@0..42
                   TWIG_SET@0..42
@@ -1750,13 +1816,13 @@ mod tests {
                       TK_ENDSET@33..39 "endset"
                       TK_WHITESPACE@39..40 " "
                       TK_PERCENT_CURLY@40..42 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_multi_set() {
         check_parse(
-            r#"{% set foo, bar, baz = 'foo', 'bar', 'baz' %}"#,
+            r"{% set foo, bar, baz = 'foo', 'bar', 'baz' %}",
             expect![[r#"
             ROOT@0..45
               TWIG_SET@0..45
@@ -1803,13 +1869,13 @@ mod tests {
                         TK_SINGLE_QUOTES@41..42 "'"
                   TK_WHITESPACE@42..43 " "
                   TK_PERCENT_CURLY@43..45 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_multi_set_non_equal_declarations() {
         check_parse(
-            r#"{% set foo, bar, baz = 'foo', 'bar' %}"#,
+            r"{% set foo, bar, baz = 'foo', 'bar' %}",
             expect![[r#"
             ROOT@0..38
               TWIG_SET@0..38
@@ -1849,15 +1915,15 @@ mod tests {
                   TK_WHITESPACE@35..36 " "
                   TK_PERCENT_CURLY@36..38 "%}"
             error at 36..38: expected a total of 3 twig expressions (same amount as declarations) instead of 2 but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_multi_with_capturing() {
         check_parse(
-            r#"{% set foo, bar, baz %}
+            r"{% set foo, bar, baz %}
     hello world
-{% endset %}"#,
+{% endset %}",
             expect![[r#"
                 ROOT@0..52
                   TWIG_SET@0..52
@@ -1894,13 +1960,42 @@ mod tests {
                       TK_WHITESPACE@49..50 " "
                       TK_PERCENT_CURLY@50..52 "%}"
                 error at 21..23: expected = followed by 3 twig expressions but found %}"#]],
-        )
+        );
+    }
+
+    #[test]
+    fn parse_twig_set_with_whitespace_control() {
+        check_parse(
+            r"{%- set a = 'foo' -%}",
+            expect![[r#"
+                ROOT@0..21
+                  TWIG_SET@0..21
+                    TWIG_SET_BLOCK@0..21
+                      TK_CURLY_PERCENT@0..3 "{%-"
+                      TK_WHITESPACE@3..4 " "
+                      TK_SET@4..7 "set"
+                      TWIG_ASSIGNMENT@7..17
+                        TWIG_LITERAL_NAME@7..9
+                          TK_WHITESPACE@7..8 " "
+                          TK_WORD@8..9 "a"
+                        TK_WHITESPACE@9..10 " "
+                        TK_EQUAL@10..11 "="
+                        TWIG_EXPRESSION@11..17
+                          TWIG_LITERAL_STRING@11..17
+                            TK_WHITESPACE@11..12 " "
+                            TK_SINGLE_QUOTES@12..13 "'"
+                            TWIG_LITERAL_STRING_INNER@13..16
+                              TK_WORD@13..16 "foo"
+                            TK_SINGLE_QUOTES@16..17 "'"
+                      TK_WHITESPACE@17..18 " "
+                      TK_PERCENT_CURLY@18..21 "-%}""#]],
+        );
     }
 
     #[test]
     fn parse_twig_set_missing_declaration() {
         check_parse(
-            r#"{% set = 'foo' %}"#,
+            r"{% set = 'foo' %}",
             expect![[r#"
                 ROOT@0..17
                   TWIG_SET@0..17
@@ -1922,13 +2017,13 @@ mod tests {
                       TK_PERCENT_CURLY@15..17 "%}"
                 error at 7..8: expected twig variable name but found =
                 error at 15..17: expected a total of 0 twig expressions (same amount as declarations) instead of 1 but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_set_missing_assignment() {
         check_parse(
-            r#"{% set foo = %}"#,
+            r"{% set foo = %}",
             expect![[r#"
                 ROOT@0..15
                   TWIG_SET@0..15
@@ -1945,13 +2040,13 @@ mod tests {
                       TK_WHITESPACE@12..13 " "
                       TK_PERCENT_CURLY@13..15 "%}"
                 error at 13..15: expected a total of 1 twig expressions (same amount as declarations) instead of 0 but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_set_missing_equal() {
         check_parse(
-            r#"{% set foo, bar, baz %}"#,
+            r"{% set foo, bar, baz %}",
             expect![[r#"
                 ROOT@0..23
                   TWIG_SET@0..23
@@ -1979,15 +2074,15 @@ mod tests {
                 error at 21..23: expected {% but reached end of file
                 error at 21..23: expected endset but reached end of file
                 error at 21..23: expected %} but reached end of file"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_in_users() {
         check_parse(
-            r#"{% for user in users %}
+            r"{% for user in users %}
     <li>{{ user.username }}</li>
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..69
                   TWIG_FOR@0..69
@@ -2041,15 +2136,15 @@ mod tests {
                       TK_ENDFOR@60..66 "endfor"
                       TK_WHITESPACE@66..67 " "
                       TK_PERCENT_CURLY@67..69 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_in_number_range() {
         check_parse(
-            r#"{% for i in 0..10 %}
+            r"{% for i in 0..10 %}
     * {{ i }}
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
             ROOT@0..47
               TWIG_FOR@0..47
@@ -2095,15 +2190,15 @@ mod tests {
                   TK_ENDFOR@38..44 "endfor"
                   TK_WHITESPACE@44..45 " "
                   TK_PERCENT_CURLY@45..47 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_in_letter_range_with_filters() {
         check_parse(
-            r#"{% for letter in 'a'|upper..'z'|upper %}
+            r"{% for letter in 'a'|upper..'z'|upper %}
     * {{ letter }}
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..72
                   TWIG_FOR@0..72
@@ -2167,15 +2262,15 @@ mod tests {
                       TK_ENDFOR@63..69 "endfor"
                       TK_WHITESPACE@69..70 " "
                       TK_PERCENT_CURLY@70..72 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_key_value_in_users() {
         check_parse(
-            r#"{% for key, user in users %}
+            r"{% for key, user in users %}
     <li>{{ key }}: {{ user.username|e }}</li>
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..87
                   TWIG_FOR@0..87
@@ -2250,17 +2345,17 @@ mod tests {
                       TK_ENDFOR@78..84 "endfor"
                       TK_WHITESPACE@84..85 " "
                       TK_PERCENT_CURLY@85..87 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_with_else() {
         check_parse(
-            r#"{% for user in users %}
+            r"{% for user in users %}
     <li>{{ user.username }}</li>
 {% else %}
     <li><em>no user found</em></li>
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..116
                   TWIG_FOR@0..116
@@ -2352,15 +2447,15 @@ mod tests {
                       TK_ENDFOR@107..113 "endfor"
                       TK_WHITESPACE@113..114 " "
                       TK_PERCENT_CURLY@114..116 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_with_missing_variable() {
         check_parse(
-            r#"{% for in users %}
+            r"{% for in users %}
     <li>{{ user.username }}</li>
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..64
                   TWIG_FOR@0..64
@@ -2413,15 +2508,15 @@ mod tests {
                       TK_PERCENT_CURLY@62..64 "%}"
                 error at 10..15: expected in but found word
                 error at 16..18: expected twig expression but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_for_with_missing_expression() {
         check_parse(
-            r#"{% for user in %}
+            r"{% for user in %}
     <li>{{ user.username }}</li>
-{% endfor %}"#,
+{% endfor %}",
             expect![[r#"
                 ROOT@0..63
                   TWIG_FOR@0..63
@@ -2472,7 +2567,7 @@ mod tests {
                       TK_WHITESPACE@60..61 " "
                       TK_PERCENT_CURLY@61..63 "%}"
                 error at 15..17: expected twig expression but found %}"#]],
-        )
+        );
     }
 
     #[test]
@@ -2496,13 +2591,13 @@ mod tests {
                 TK_DOUBLE_QUOTES@21..22 "\""
             TK_WHITESPACE@22..23 " "
             TK_PERCENT_CURLY@23..25 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_extends_with_variable() {
         check_parse(
-            r#"{% extends some_var %}"#,
+            r"{% extends some_var %}",
             expect![[r#"
         ROOT@0..22
           TWIG_EXTENDS@0..22
@@ -2515,13 +2610,13 @@ mod tests {
                 TK_WORD@11..19 "some_var"
             TK_WHITESPACE@19..20 " "
             TK_PERCENT_CURLY@20..22 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_extends_with_array() {
         check_parse(
-            r#"{% extends ['layout.html', 'base_layout.html'] %}"#,
+            r"{% extends ['layout.html', 'base_layout.html'] %}",
             expect![[r#"
                 ROOT@0..49
                   TWIG_EXTENDS@0..49
@@ -2554,7 +2649,7 @@ mod tests {
                         TK_CLOSE_SQUARE@45..46 "]"
                     TK_WHITESPACE@46..47 " "
                     TK_PERCENT_CURLY@47..49 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -2597,13 +2692,13 @@ mod tests {
                         TK_DOUBLE_QUOTES@51..52 "\""
                 TK_WHITESPACE@52..53 " "
                 TK_PERCENT_CURLY@53..55 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_extends_missing_expression() {
         check_parse(
-            r#"{% extends %}"#,
+            r"{% extends %}",
             expect![[r#"
         ROOT@0..13
           TWIG_EXTENDS@0..13
@@ -2613,13 +2708,13 @@ mod tests {
             TK_WHITESPACE@10..11 " "
             TK_PERCENT_CURLY@11..13 "%}"
         error at 11..13: expected twig expression but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_string() {
         check_parse(
-            r#"{% include 'header.html' %}"#,
+            r"{% include 'header.html' %}",
             expect![[r#"
         ROOT@0..27
           TWIG_INCLUDE@0..27
@@ -2637,13 +2732,13 @@ mod tests {
                 TK_SINGLE_QUOTES@23..24 "'"
             TK_WHITESPACE@24..25 " "
             TK_PERCENT_CURLY@25..27 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_with_variable() {
         check_parse(
-            r#"{% include 'template.html' with vars %}"#,
+            r"{% include 'template.html' with vars %}",
             expect![[r#"
             ROOT@0..39
               TWIG_INCLUDE@0..39
@@ -2668,13 +2763,13 @@ mod tests {
                       TK_WORD@32..36 "vars"
                 TK_WHITESPACE@36..37 " "
                 TK_PERCENT_CURLY@37..39 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_with_hash() {
         check_parse(
-            r#"{% include 'template.html' with {'foo': 'bar'} %}"#,
+            r"{% include 'template.html' with {'foo': 'bar'} %}",
             expect![[r#"
                 ROOT@0..49
                   TWIG_INCLUDE@0..49
@@ -2716,13 +2811,13 @@ mod tests {
                           TK_CLOSE_CURLY@45..46 "}"
                     TK_WHITESPACE@46..47 " "
                     TK_PERCENT_CURLY@47..49 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_with_hash_only() {
         check_parse(
-            r#"{% include 'template.html' with {'foo': 'bar'} only %}"#,
+            r"{% include 'template.html' with {'foo': 'bar'} only %}",
             expect![[r#"
                 ROOT@0..54
                   TWIG_INCLUDE@0..54
@@ -2766,13 +2861,13 @@ mod tests {
                     TK_ONLY@47..51 "only"
                     TK_WHITESPACE@51..52 " "
                     TK_PERCENT_CURLY@52..54 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_only() {
         check_parse(
-            r#"{% include 'template.html' only %}"#,
+            r"{% include 'template.html' only %}",
             expect![[r#"
         ROOT@0..34
           TWIG_INCLUDE@0..34
@@ -2792,13 +2887,13 @@ mod tests {
             TK_ONLY@27..31 "only"
             TK_WHITESPACE@31..32 " "
             TK_PERCENT_CURLY@32..34 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_expression() {
         check_parse(
-            r#"{% include ajax ? 'ajax.html' : 'not_ajax.html' %}"#,
+            r"{% include ajax ? 'ajax.html' : 'not_ajax.html' %}",
             expect![[r#"
             ROOT@0..50
               TWIG_INCLUDE@0..50
@@ -2835,13 +2930,13 @@ mod tests {
                         TK_SINGLE_QUOTES@46..47 "'"
                 TK_WHITESPACE@47..48 " "
                 TK_PERCENT_CURLY@48..50 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_variable_ignore_missing_with_hash_only() {
         check_parse(
-            r#"{% include some_var ignore missing with {'foo': 'bar'} only %}"#,
+            r"{% include some_var ignore missing with {'foo': 'bar'} only %}",
             expect![[r#"
                 ROOT@0..62
                   TWIG_INCLUDE@0..62
@@ -2882,13 +2977,95 @@ mod tests {
                     TK_ONLY@55..59 "only"
                     TK_WHITESPACE@59..60 " "
                     TK_PERCENT_CURLY@60..62 "%}""#]],
-        )
+        );
+    }
+
+    #[test]
+    fn parse_twig_include_modifiers_in_any_order() {
+        check_parse(
+            r"{% include 'header.html' only with {'foo': 'bar'} ignore missing %}",
+            expect![[r#"
+                ROOT@0..67
+                  TWIG_INCLUDE@0..67
+                    TK_CURLY_PERCENT@0..2 "{%"
+                    TK_WHITESPACE@2..3 " "
+                    TK_INCLUDE@3..10 "include"
+                    TWIG_EXPRESSION@10..24
+                      TWIG_LITERAL_STRING@10..24
+                        TK_WHITESPACE@10..11 " "
+                        TK_SINGLE_QUOTES@11..12 "'"
+                        TWIG_LITERAL_STRING_INNER@12..23
+                          TK_WORD@12..18 "header"
+                          TK_DOT@18..19 "."
+                          TK_WORD@19..23 "html"
+                        TK_SINGLE_QUOTES@23..24 "'"
+                    TK_WHITESPACE@24..25 " "
+                    TK_ONLY@25..29 "only"
+                    TWIG_INCLUDE_WITH@29..49
+                      TK_WHITESPACE@29..30 " "
+                      TK_WITH@30..34 "with"
+                      TWIG_EXPRESSION@34..49
+                        TWIG_LITERAL_HASH@34..49
+                          TK_WHITESPACE@34..35 " "
+                          TK_OPEN_CURLY@35..36 "{"
+                          TWIG_LITERAL_HASH_ITEMS@36..48
+                            TWIG_LITERAL_HASH_PAIR@36..48
+                              TWIG_LITERAL_HASH_KEY@36..41
+                                TWIG_LITERAL_STRING@36..41
+                                  TK_SINGLE_QUOTES@36..37 "'"
+                                  TWIG_LITERAL_STRING_INNER@37..40
+                                    TK_WORD@37..40 "foo"
+                                  TK_SINGLE_QUOTES@40..41 "'"
+                              TK_COLON@41..42 ":"
+                              TWIG_EXPRESSION@42..48
+                                TWIG_LITERAL_STRING@42..48
+                                  TK_WHITESPACE@42..43 " "
+                                  TK_SINGLE_QUOTES@43..44 "'"
+                                  TWIG_LITERAL_STRING_INNER@44..47
+                                    TK_WORD@44..47 "bar"
+                                  TK_SINGLE_QUOTES@47..48 "'"
+                          TK_CLOSE_CURLY@48..49 "}"
+                    TK_WHITESPACE@49..50 " "
+                    TK_IGNORE_MISSING@50..64 "ignore missing"
+                    TK_WHITESPACE@64..65 " "
+                    TK_PERCENT_CURLY@65..67 "%}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_twig_include_duplicate_modifier() {
+        check_parse(
+            r"{% include 'header.html' only only %}",
+            expect![[r#"
+            ROOT@0..37
+              TWIG_INCLUDE@0..37
+                TK_CURLY_PERCENT@0..2 "{%"
+                TK_WHITESPACE@2..3 " "
+                TK_INCLUDE@3..10 "include"
+                TWIG_EXPRESSION@10..24
+                  TWIG_LITERAL_STRING@10..24
+                    TK_WHITESPACE@10..11 " "
+                    TK_SINGLE_QUOTES@11..12 "'"
+                    TWIG_LITERAL_STRING_INNER@12..23
+                      TK_WORD@12..18 "header"
+                      TK_DOT@18..19 "."
+                      TK_WORD@19..23 "html"
+                    TK_SINGLE_QUOTES@23..24 "'"
+                TK_WHITESPACE@24..25 " "
+                TK_ONLY@25..29 "only"
+                ERROR@29..34
+                  TK_WHITESPACE@29..30 " "
+                  TK_ONLY@30..34 "only"
+                TK_WHITESPACE@34..35 " "
+                TK_PERCENT_CURLY@35..37 "%}"
+            error at 30..34: expected %} but found only"#]],
+        );
     }
 
     #[test]
     fn parse_twig_include_missing_template() {
         check_parse(
-            r#"{% include %}"#,
+            r"{% include %}",
             expect![[r#"
         ROOT@0..13
           TWIG_INCLUDE@0..13
@@ -2898,13 +3075,13 @@ mod tests {
             TK_WHITESPACE@10..11 " "
             TK_PERCENT_CURLY@11..13 "%}"
         error at 11..13: expected twig expression as template name but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_missing_with_value() {
         check_parse(
-            r#"{% include 'template.html' with %}"#,
+            r"{% include 'template.html' with %}",
             expect![[r#"
             ROOT@0..34
               TWIG_INCLUDE@0..34
@@ -2926,13 +3103,13 @@ mod tests {
                 TK_WHITESPACE@31..32 " "
                 TK_PERCENT_CURLY@32..34 "%}"
             error at 32..34: expected twig expression as with value but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_include_array() {
         check_parse(
-            r#"{% include ['page_detailed.html', 'page.html'] %}"#,
+            r"{% include ['page_detailed.html', 'page.html'] %}",
             expect![[r#"
                 ROOT@0..49
                   TWIG_INCLUDE@0..49
@@ -2965,7 +3142,7 @@ mod tests {
                         TK_CLOSE_SQUARE@45..46 "]"
                     TK_WHITESPACE@46..47 " "
                     TK_PERCENT_CURLY@47..49 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -2988,7 +3165,7 @@ mod tests {
               TK_DOUBLE_QUOTES@19..20 "\""
             TK_WHITESPACE@20..21 " "
             TK_PERCENT_CURLY@21..23 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -3020,7 +3197,7 @@ mod tests {
                     TK_WHITESPACE@26..27 " "
                     TK_PERCENT_CURLY@27..29 "%}"
                 error at 14..15: expected no string interpolation, because it isn't allowed here but found #"##]],
-        )
+        );
     }
 
     #[test]
@@ -3064,7 +3241,7 @@ mod tests {
                         TK_WORD@60..70 "base_title"
                     TK_WHITESPACE@70..71 " "
                     TK_PERCENT_CURLY@71..73 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -3090,7 +3267,7 @@ mod tests {
             TK_WHITESPACE@25..26 " "
             TK_PERCENT_CURLY@26..28 "%}"
         error at 26..28: expected at least one block name as block name but found %}"#]],
-        )
+        );
     }
 
     #[test]
@@ -3119,15 +3296,15 @@ mod tests {
                         TK_WORD@26..27 "a"
                     TK_WHITESPACE@27..28 " "
                     TK_PERCENT_CURLY@28..30 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_apply_filter() {
         check_parse(
-            r#"{% apply upper %}
+            r"{% apply upper %}
     This text becomes uppercase
-{% endapply %}"#,
+{% endapply %}",
             expect![[r#"
                 ROOT@0..64
                   TWIG_APPLY@0..64
@@ -3158,15 +3335,15 @@ mod tests {
                       TK_ENDAPPLY@53..61 "endapply"
                       TK_WHITESPACE@61..62 " "
                       TK_PERCENT_CURLY@62..64 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_apply_filter_with_arguments() {
         check_parse(
-            r#"{% apply trim('-', side='left') %}
+            r"{% apply trim('-', side='left') %}
     This text becomes trimmed
-{% endapply %}"#,
+{% endapply %}",
             expect![[r#"
                 ROOT@0..79
                   TWIG_APPLY@0..79
@@ -3217,15 +3394,15 @@ mod tests {
                       TK_ENDAPPLY@68..76 "endapply"
                       TK_WHITESPACE@76..77 " "
                       TK_PERCENT_CURLY@77..79 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_apply_filter_chained() {
         check_parse(
-            r#"{% apply lower|escape('html')|trim('-', side='left') %}
+            r"{% apply lower|escape('html')|trim('-', side='left') %}
     <strong>SOME TEXT</strong>
-{% endapply %}"#,
+{% endapply %}",
             expect![[r#"
                 ROOT@0..101
                   TWIG_APPLY@0..101
@@ -3304,15 +3481,15 @@ mod tests {
                       TK_ENDAPPLY@90..98 "endapply"
                       TK_WHITESPACE@98..99 " "
                       TK_PERCENT_CURLY@99..101 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_apply_missing_filter() {
         check_parse(
-            r#"{% apply %}
+            r"{% apply %}
     SOME TEXT
-{% endapply %}"#,
+{% endapply %}",
             expect![[r#"
                 ROOT@0..40
                   TWIG_APPLY@0..40
@@ -3337,15 +3514,15 @@ mod tests {
                       TK_WHITESPACE@37..38 " "
                       TK_PERCENT_CURLY@38..40 "%}"
                 error at 9..11: expected twig filter but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_apply_wrong_type() {
         check_parse(
-            r#"{% apply 5 %}
+            r"{% apply 5 %}
     SOME TEXT
-{% endapply %}"#,
+{% endapply %}",
             expect![[r#"
                 ROOT@0..42
                   TWIG_APPLY@0..42
@@ -3373,16 +3550,16 @@ mod tests {
                       TK_WHITESPACE@39..40 " "
                       TK_PERCENT_CURLY@40..42 "%}"
                 error at 9..10: expected twig filter but found number"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_autoescape() {
         check_parse(
-            r#"{% autoescape %}
+            r"{% autoescape %}
     Everything will be automatically escaped in this block
     using the HTML strategy
-{% endautoescape %}"#,
+{% endautoescape %}",
             expect![[r#"
                 ROOT@0..123
                   TWIG_AUTOESCAPE@0..123
@@ -3427,16 +3604,16 @@ mod tests {
                       TK_ENDAUTOESCAPE@107..120 "endautoescape"
                       TK_WHITESPACE@120..121 " "
                       TK_PERCENT_CURLY@121..123 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_autoescape_strategy() {
         check_parse(
-            r#"{% autoescape 'js' %}
+            r"{% autoescape 'js' %}
     Everything will be automatically escaped in this block
     using the js escaping strategy
-{% endautoescape %}"#,
+{% endautoescape %}",
             expect![[r#"
                 ROOT@0..135
                   TWIG_AUTOESCAPE@0..135
@@ -3489,15 +3666,15 @@ mod tests {
                       TK_ENDAUTOESCAPE@119..132 "endautoescape"
                       TK_WHITESPACE@132..133 " "
                       TK_PERCENT_CURLY@133..135 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_autoescape_false() {
         check_parse(
-            r#"{% autoescape false %}
+            r"{% autoescape false %}
     Everything will be outputted as is in this block
-{% endautoescape %}"#,
+{% endautoescape %}",
             expect![[r#"
                 ROOT@0..95
                   TWIG_AUTOESCAPE@0..95
@@ -3537,16 +3714,16 @@ mod tests {
                       TK_ENDAUTOESCAPE@79..92 "endautoescape"
                       TK_WHITESPACE@92..93 " "
                       TK_PERCENT_CURLY@93..95 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_autoescape_wrong_var() {
         check_parse(
-            r#"{% autoescape my_var %}
+            r"{% autoescape my_var %}
     Everything will be automatically escaped in this block
     using the js escaping strategy
-{% endautoescape %}"#,
+{% endautoescape %}",
             expect![[r#"
                 ROOT@0..137
                   TWIG_AUTOESCAPE@0..137
@@ -3597,7 +3774,7 @@ mod tests {
                       TK_WHITESPACE@134..135 " "
                       TK_PERCENT_CURLY@135..137 "%}"
                 error at 14..20: expected twig escape strategy as string or 'false' but found word"#]],
-        )
+        );
     }
 
     #[test]
@@ -3642,13 +3819,13 @@ mod tests {
                       TK_SINGLE_QUOTES@81..82 "'"
                     TK_WHITESPACE@82..83 " "
                     TK_PERCENT_CURLY@83..85 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_deprecated_missing_string() {
         check_parse(
-            r#"{% deprecated %}"#,
+            r"{% deprecated %}",
             expect![[r#"
                 ROOT@0..16
                   TWIG_DEPRECATED@0..16
@@ -3658,13 +3835,13 @@ mod tests {
                     TK_WHITESPACE@13..14 " "
                     TK_PERCENT_CURLY@14..16 "%}"
                 error at 14..16: expected twig deprecation message as string but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_do() {
         check_parse(
-            r#"{% do 1 + 2 %}"#,
+            r"{% do 1 + 2 %}",
             expect![[r#"
             ROOT@0..14
               TWIG_DO@0..14
@@ -3685,13 +3862,13 @@ mod tests {
                         TK_NUMBER@10..11 "2"
                 TK_WHITESPACE@11..12 " "
                 TK_PERCENT_CURLY@12..14 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_do_missing_expression() {
         check_parse(
-            r#"{% do %}"#,
+            r"{% do %}",
             expect![[r#"
             ROOT@0..8
               TWIG_DO@0..8
@@ -3701,7 +3878,7 @@ mod tests {
                 TK_WHITESPACE@5..6 " "
                 TK_PERCENT_CURLY@6..8 "%}"
             error at 6..8: expected twig expression but found %}"#]],
-        )
+        );
     }
 
     #[test]
@@ -3763,7 +3940,7 @@ mod tests {
                       TK_ENDEMBED@50..58 "endembed"
                       TK_WHITESPACE@58..59 " "
                       TK_PERCENT_CURLY@59..61 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -3827,7 +4004,7 @@ mod tests {
                       TK_ENDEMBED@55..63 "endembed"
                       TK_WHITESPACE@63..64 " "
                       TK_PERCENT_CURLY@64..66 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -3867,13 +4044,13 @@ mod tests {
                       TK_ENDEMBED@45..53 "endembed"
                       TK_WHITESPACE@53..54 " "
                       TK_PERCENT_CURLY@54..56 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_flush() {
         check_parse(
-            r#"{% flush %}"#,
+            r"{% flush %}",
             expect![[r#"
             ROOT@0..11
               TWIG_FLUSH@0..11
@@ -3882,13 +4059,13 @@ mod tests {
                 TK_FLUSH@3..8 "flush"
                 TK_WHITESPACE@8..9 " "
                 TK_PERCENT_CURLY@9..11 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_from_template_import() {
         check_parse(
-            r#"{% from 'forms.html' import input as input_field, textarea %}"#,
+            r"{% from 'forms.html' import input as input_field, textarea %}",
             expect![[r#"
                 ROOT@0..61
                   TWIG_FROM@0..61
@@ -3922,13 +4099,13 @@ mod tests {
                         TK_WORD@50..58 "textarea"
                     TK_WHITESPACE@58..59 " "
                     TK_PERCENT_CURLY@59..61 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_from_expression_import() {
         check_parse(
-            r#"{% from my_var|trim import input as input_field, textarea %}"#,
+            r"{% from my_var|trim import input as input_field, textarea %}",
             expect![[r#"
                 ROOT@0..60
                   TWIG_FROM@0..60
@@ -3963,13 +4140,13 @@ mod tests {
                         TK_WORD@49..57 "textarea"
                     TK_WHITESPACE@57..58 " "
                     TK_PERCENT_CURLY@58..60 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_from_missing_macros() {
         check_parse(
-            r#"{% from 'forms.html' import %}"#,
+            r"{% from 'forms.html' import %}",
             expect![[r#"
             ROOT@0..30
               TWIG_FROM@0..30
@@ -3990,13 +4167,13 @@ mod tests {
                 TK_WHITESPACE@27..28 " "
                 TK_PERCENT_CURLY@28..30 "%}"
             error at 28..30: expected at least one macro name as macro name but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_from_missing_import_and_macros() {
         check_parse(
-            r#"{% from 'forms.html' %}"#,
+            r"{% from 'forms.html' %}",
             expect![[r#"
             ROOT@0..23
               TWIG_FROM@0..23
@@ -4016,7 +4193,7 @@ mod tests {
                 TK_PERCENT_CURLY@21..23 "%}"
             error at 21..23: expected import but found %}
             error at 21..23: expected at least one macro name as macro name but found %}"#]],
-        )
+        );
     }
 
     #[test]
@@ -4045,13 +4222,13 @@ mod tests {
                   TK_WORD@26..31 "forms"
                 TK_WHITESPACE@31..32 " "
                 TK_PERCENT_CURLY@32..34 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_import_expression_as_macro() {
         check_parse(
-            r#"{% import my_var|trim as forms %}"#,
+            r"{% import my_var|trim as forms %}",
             expect![[r#"
                 ROOT@0..33
                   TWIG_IMPORT@0..33
@@ -4075,7 +4252,7 @@ mod tests {
                       TK_WORD@25..30 "forms"
                     TK_WHITESPACE@30..31 " "
                     TK_PERCENT_CURLY@31..33 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -4102,7 +4279,7 @@ mod tests {
                 TK_WHITESPACE@25..26 " "
                 TK_PERCENT_CURLY@26..28 "%}"
             error at 26..28: expected name for twig macro but found %}"#]],
-        )
+        );
     }
 
     #[test]
@@ -4128,15 +4305,15 @@ mod tests {
                 TK_PERCENT_CURLY@23..25 "%}"
             error at 23..25: expected as but found %}
             error at 23..25: expected name for twig macro but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_sandbox() {
         check_parse(
-            r#"{% sandbox %}
+            r"{% sandbox %}
     {% include 'user.html' %}
-{% endsandbox %}"#,
+{% endsandbox %}",
             expect![[r#"
                 ROOT@0..60
                   TWIG_SANDBOX@0..60
@@ -4171,19 +4348,19 @@ mod tests {
                       TK_ENDSANDBOX@47..57 "endsandbox"
                       TK_WHITESPACE@57..58 " "
                       TK_PERCENT_CURLY@58..60 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_verbatim() {
         check_parse(
-            r#"{% verbatim %}
+            r"{% verbatim %}
     <ul>
     {% for item in seq %}
         <li>{{ item }}</li>
     {% endfor %}
     </ul>
-{% endverbatim %}"#,
+{% endverbatim %}",
             expect![[r#"
                 ROOT@0..122
                   TWIG_VERBATIM@0..122
@@ -4264,10 +4441,11 @@ mod tests {
                       TK_ENDVERBATIM@108..119 "endverbatim"
                       TK_WHITESPACE@119..120 " "
                       TK_PERCENT_CURLY@120..122 "%}""#]],
-        )
+        );
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn parse_twig_macro() {
         check_parse(
             r#"{% macro input(name, value, type = "text", size = 20) %}
@@ -4404,15 +4582,15 @@ mod tests {
                       TK_ENDMACRO@149..157 "endmacro"
                       TK_WHITESPACE@157..158 " "
                       TK_PERCENT_CURLY@158..160 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_macro_with_matching_end_tag() {
         check_parse(
-            r#"{% macro input() %}
+            r"{% macro input() %}
     ...
-{% endmacro input %}"#,
+{% endmacro input %}",
             expect![[r#"
                 ROOT@0..48
                   TWIG_MACRO@0..48
@@ -4442,15 +4620,15 @@ mod tests {
                       TK_WORD@40..45 "input"
                       TK_WHITESPACE@45..46 " "
                       TK_PERCENT_CURLY@46..48 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_macro_with_non_matching_end_tag() {
         check_parse(
-            r#"{% macro input() %}
+            r"{% macro input() %}
     ...
-{% endmacro inp %}"#,
+{% endmacro inp %}",
             expect![[r#"
                 ROOT@0..46
                   TWIG_MACRO@0..46
@@ -4481,15 +4659,15 @@ mod tests {
                       TK_WHITESPACE@43..44 " "
                       TK_PERCENT_CURLY@44..46 "%}"
                 error at 40..43: expected nothing or same twig macro name as opening (input) but found word"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_macro_missing_arguments() {
         check_parse(
-            r#"{% macro input %}
+            r"{% macro input %}
     ...
-{% endmacro input %}"#,
+{% endmacro input %}",
             expect![[r#"
                 ROOT@0..46
                   TWIG_MACRO@0..46
@@ -4519,16 +4697,16 @@ mod tests {
                       TK_PERCENT_CURLY@44..46 "%}"
                 error at 15..17: expected ( but found %}
                 error at 15..17: expected ) but found %}"#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_with() {
         check_parse(
-            r#"{% with %}
+            r"{% with %}
     {% set foo = 42 %}
     {{ foo }} {# foo is 42 here #}
-{% endwith %}"#,
+{% endwith %}",
             expect![[r##"
                 ROOT@0..82
                   TWIG_WITH@0..82
@@ -4588,15 +4766,15 @@ mod tests {
                       TK_ENDWITH@72..79 "endwith"
                       TK_WHITESPACE@79..80 " "
                       TK_PERCENT_CURLY@80..82 "%}""##]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_with_and_hash() {
         check_parse(
-            r#"{% with { foo: 42 } %}
+            r"{% with { foo: 42 } %}
     {{ foo }} {# foo is 42 here #}
-{% endwith %}"#,
+{% endwith %}",
             expect![[r##"
                 ROOT@0..71
                   TWIG_WITH@0..71
@@ -4653,16 +4831,16 @@ mod tests {
                       TK_ENDWITH@61..68 "endwith"
                       TK_WHITESPACE@68..69 " "
                       TK_PERCENT_CURLY@69..71 "%}""##]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_with_and_expression() {
         check_parse(
-            r#"{% set vars = { foo: 42 } %}
+            r"{% set vars = { foo: 42 } %}
 {% with vars %}
     ...
-{% endwith %}"#,
+{% endwith %}",
             expect![[r#"
                 ROOT@0..66
                   TWIG_SET@0..28
@@ -4719,17 +4897,17 @@ mod tests {
                       TK_ENDWITH@56..63 "endwith"
                       TK_WHITESPACE@63..64 " "
                       TK_PERCENT_CURLY@64..66 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_with_and_hash_only() {
         check_parse(
-            r#"{% set bar = 'bar' %}
+            r"{% set bar = 'bar' %}
 {% with { foo: 42 } only %}
     {# only foo is defined #}
     {# bar is not defined #}
-{% endwith %}"#,
+{% endwith %}",
             expect![[r##"
                 ROOT@0..122
                   TWIG_SET@0..21
@@ -4814,7 +4992,7 @@ mod tests {
                       TK_ENDWITH@112..119 "endwith"
                       TK_WHITESPACE@119..120 " "
                       TK_PERCENT_CURLY@120..122 "%}""##]],
-        )
+        );
     }
 
     #[test]
@@ -4867,7 +5045,7 @@ mod tests {
                       TK_ENDCACHE@86..94 "endcache"
                       TK_WHITESPACE@94..95 " "
                       TK_PERCENT_CURLY@95..97 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -4920,7 +5098,7 @@ mod tests {
                       TK_ENDCACHE@63..71 "endcache"
                       TK_WHITESPACE@71..72 " "
                       TK_PERCENT_CURLY@72..74 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -4985,7 +5163,7 @@ mod tests {
                       TK_ENDCACHE@63..71 "endcache"
                       TK_WHITESPACE@71..72 " "
                       TK_PERCENT_CURLY@72..74 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -5058,7 +5236,7 @@ mod tests {
                       TK_ENDCACHE@70..78 "endcache"
                       TK_WHITESPACE@78..79 " "
                       TK_PERCENT_CURLY@79..81 "%}""#]],
-        )
+        );
     }
 
     #[test]
@@ -5137,15 +5315,15 @@ mod tests {
                       TK_ENDCACHE@79..87 "endcache"
                       TK_WHITESPACE@87..88 " "
                       TK_PERCENT_CURLY@88..90 "%}""#]],
-        )
+        );
     }
 
     #[test]
     fn parse_twig_cache_missing_key() {
         check_parse(
-            r#"{% cache %}
+            r"{% cache %}
     Some code
-{% endcache %}"#,
+{% endcache %}",
             expect![[r#"
                 ROOT@0..40
                   TWIG_CACHE@0..40
@@ -5170,6 +5348,166 @@ mod tests {
                       TK_WHITESPACE@37..38 " "
                       TK_PERCENT_CURLY@38..40 "%}"
                 error at 9..11: expected twig expression as cache key but found %}"#]],
-        )
+        );
+    }
+
+    #[test]
+    fn parse_twig_trans() {
+        check_parse(
+            r"{% trans %}Hello %name%{% endtrans %}",
+            expect![[r#"
+                ROOT@0..37
+                  TWIG_TRANS@0..37
+                    TWIG_TRANS_STARTING_BLOCK@0..11
+                      TK_CURLY_PERCENT@0..2 "{%"
+                      TK_WHITESPACE@2..3 " "
+                      TK_TRANS@3..8 "trans"
+                      TK_WHITESPACE@8..9 " "
+                      TK_PERCENT_CURLY@9..11 "%}"
+                    BODY@11..23
+                      HTML_TEXT@11..23
+                        TK_WORD@11..16 "Hello"
+                        TK_WHITESPACE@16..17 " "
+                        TK_PERCENT@17..18 "%"
+                        TK_WORD@18..22 "name"
+                        TK_PERCENT@22..23 "%"
+                    TWIG_TRANS_ENDING_BLOCK@23..37
+                      TK_CURLY_PERCENT@23..25 "{%"
+                      TK_WHITESPACE@25..26 " "
+                      TK_ENDTRANS@26..34 "endtrans"
+                      TK_WHITESPACE@34..35 " "
+                      TK_PERCENT_CURLY@35..37 "%}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_twig_trans_with_from_into() {
+        check_parse(
+            r"{% trans with {'%name%': 'Fabien'} from 'app' into 'fr' %}
+    Hello %name%
+{% endtrans %}",
+            expect![[r#"
+                ROOT@0..90
+                  TWIG_TRANS@0..90
+                    TWIG_TRANS_STARTING_BLOCK@0..58
+                      TK_CURLY_PERCENT@0..2 "{%"
+                      TK_WHITESPACE@2..3 " "
+                      TK_TRANS@3..8 "trans"
+                      TK_WHITESPACE@8..9 " "
+                      TK_WITH@9..13 "with"
+                      TWIG_EXPRESSION@13..34
+                        TWIG_LITERAL_HASH@13..34
+                          TK_WHITESPACE@13..14 " "
+                          TK_OPEN_CURLY@14..15 "{"
+                          TWIG_LITERAL_HASH_ITEMS@15..33
+                            TWIG_LITERAL_HASH_PAIR@15..33
+                              TWIG_LITERAL_HASH_KEY@15..23
+                                TWIG_LITERAL_STRING@15..23
+                                  TK_SINGLE_QUOTES@15..16 "'"
+                                  TWIG_LITERAL_STRING_INNER@16..22
+                                    TK_PERCENT@16..17 "%"
+                                    TK_WORD@17..21 "name"
+                                    TK_PERCENT@21..22 "%"
+                                  TK_SINGLE_QUOTES@22..23 "'"
+                              TK_COLON@23..24 ":"
+                              TWIG_EXPRESSION@24..33
+                                TWIG_LITERAL_STRING@24..33
+                                  TK_WHITESPACE@24..25 " "
+                                  TK_SINGLE_QUOTES@25..26 "'"
+                                  TWIG_LITERAL_STRING_INNER@26..32
+                                    TK_WORD@26..32 "Fabien"
+                                  TK_SINGLE_QUOTES@32..33 "'"
+                          TK_CLOSE_CURLY@33..34 "}"
+                      TK_WHITESPACE@34..35 " "
+                      TK_FROM@35..39 "from"
+                      TWIG_EXPRESSION@39..45
+                        TWIG_LITERAL_STRING@39..45
+                          TK_WHITESPACE@39..40 " "
+                          TK_SINGLE_QUOTES@40..41 "'"
+                          TWIG_LITERAL_STRING_INNER@41..44
+                            TK_WORD@41..44 "app"
+                          TK_SINGLE_QUOTES@44..45 "'"
+                      TK_WHITESPACE@45..46 " "
+                      TK_INTO@46..50 "into"
+                      TWIG_EXPRESSION@50..55
+                        TWIG_LITERAL_STRING@50..55
+                          TK_WHITESPACE@50..51 " "
+                          TK_SINGLE_QUOTES@51..52 "'"
+                          TWIG_LITERAL_STRING_INNER@52..54
+                            TK_WORD@52..54 "fr"
+                          TK_SINGLE_QUOTES@54..55 "'"
+                      TK_WHITESPACE@55..56 " "
+                      TK_PERCENT_CURLY@56..58 "%}"
+                    BODY@58..75
+                      HTML_TEXT@58..75
+                        TK_LINE_BREAK@58..59 "\n"
+                        TK_WHITESPACE@59..63 "    "
+                        TK_WORD@63..68 "Hello"
+                        TK_WHITESPACE@68..69 " "
+                        TK_PERCENT@69..70 "%"
+                        TK_WORD@70..74 "name"
+                        TK_PERCENT@74..75 "%"
+                    TWIG_TRANS_ENDING_BLOCK@75..90
+                      TK_LINE_BREAK@75..76 "\n"
+                      TK_CURLY_PERCENT@76..78 "{%"
+                      TK_WHITESPACE@78..79 " "
+                      TK_ENDTRANS@79..87 "endtrans"
+                      TK_WHITESPACE@87..88 " "
+                      TK_PERCENT_CURLY@88..90 "%}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_twig_trans_missing_domain() {
+        check_parse(
+            r"{% trans from %}Hello{% endtrans %}",
+            expect![[r#"
+                ROOT@0..35
+                  TWIG_TRANS@0..35
+                    TWIG_TRANS_STARTING_BLOCK@0..16
+                      TK_CURLY_PERCENT@0..2 "{%"
+                      TK_WHITESPACE@2..3 " "
+                      TK_TRANS@3..8 "trans"
+                      TK_WHITESPACE@8..9 " "
+                      TK_FROM@9..13 "from"
+                      TK_WHITESPACE@13..14 " "
+                      TK_PERCENT_CURLY@14..16 "%}"
+                    BODY@16..21
+                      HTML_TEXT@16..21
+                        TK_WORD@16..21 "Hello"
+                    TWIG_TRANS_ENDING_BLOCK@21..35
+                      TK_CURLY_PERCENT@21..23 "{%"
+                      TK_WHITESPACE@23..24 " "
+                      TK_ENDTRANS@24..32 "endtrans"
+                      TK_WHITESPACE@32..33 " "
+                      TK_PERCENT_CURLY@33..35 "%}"
+                error at 14..16: expected twig expression as translation domain but found %}"#]],
+        );
+    }
+
+    #[test]
+    fn parse_twig_trans_filter_is_not_a_block() {
+        check_parse(
+            r"{{ 'hello'|trans }}",
+            expect![[r#"
+                ROOT@0..19
+                  TWIG_VAR@0..19
+                    TK_OPEN_CURLY_CURLY@0..2 "{{"
+                    TWIG_EXPRESSION@2..16
+                      TWIG_FILTER@2..16
+                        TWIG_OPERAND@2..10
+                          TWIG_LITERAL_STRING@2..10
+                            TK_WHITESPACE@2..3 " "
+                            TK_SINGLE_QUOTES@3..4 "'"
+                            TWIG_LITERAL_STRING_INNER@4..9
+                              TK_WORD@4..9 "hello"
+                            TK_SINGLE_QUOTES@9..10 "'"
+                        TK_SINGLE_PIPE@10..11 "|"
+                        TWIG_OPERAND@11..16
+                          TWIG_LITERAL_NAME@11..16
+                            TK_WORD@11..16 "trans"
+                    TK_WHITESPACE@16..17 " "
+                    TK_CLOSE_CURLY_CURLY@17..19 "}}""#]],
+        );
     }
 }
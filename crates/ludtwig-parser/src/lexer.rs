@@ -4,7 +4,7 @@ use crate::syntax::untyped::{SyntaxKind, TextRange, TextSize};
 
 /// Lex the source code into a Vec of tokens with their corresponding span (position in source code).
 /// These tokens are produced by a dumb lexer and don't have any meaning / semantic attached to them.
-pub(crate) fn lex(source: &str) -> Vec<Token> {
+pub(crate) fn lex(source: &str) -> Vec<Token<'_>> {
     let mut lexer = SyntaxKind::lexer(source);
     let mut result = vec![];
 
@@ -35,13 +35,12 @@ pub(crate) struct Token<'source> {
     pub(crate) range: TextRange,
 }
 
+#[cfg(test)]
 impl<'source> Token<'source> {
-    #[cfg(test)]
     pub(crate) fn new(kind: SyntaxKind, text: &'source str, range: TextRange) -> Self {
         Self { kind, text, range }
     }
 
-    #[cfg(test)]
     pub(crate) fn new_wrong_range(kind: SyntaxKind, text: &'source str) -> Self {
         use crate::syntax::untyped::TextLen;
         let range = TextRange::up_to(text.text_len());
@@ -131,7 +130,7 @@ mod tests {
                 T![ws],
                 T!["}}"],
             ]
-        )
+        );
     }
 
     #[test]
@@ -139,17 +138,18 @@ mod tests {
         let results = lex("#{{");
         let syntax_kinds: Vec<SyntaxKind> = results.into_iter().map(|t| t.kind).collect();
 
-        assert_eq!(syntax_kinds, vec![T!["#"], T!["{{"],])
+        assert_eq!(syntax_kinds, vec![T!["#"], T!["{{"],]);
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn lex_all_tokens_chained_together() {
         use std::fmt::Write;
 
         let mut source = String::new();
         let mut expected_kinds: Vec<SyntaxKind> = vec![];
         let mut add = |text: &str, kind: SyntaxKind| {
-            write!(source, "{} ", text).unwrap();
+            write!(source, "{text} ").unwrap();
             expected_kinds.push(kind);
             expected_kinds.push(T![ws]);
         };
@@ -251,6 +251,9 @@ mod tests {
         add("endwith", T!["endwith"]);
         add("ttl", T!["ttl"]);
         add("tags", T!["tags"]);
+        add("trans", T!["trans"]);
+        add("endtrans", T!["endtrans"]);
+        add("into", T!["into"]);
         add("not", T!["not"]);
         add("or", T!["or"]);
         add("and", T!["and"]);
@@ -291,12 +294,13 @@ mod tests {
         add("style", T!["style"]);
         add("ludtwig-ignore-file", T!["ludtwig-ignore-file"]);
         add("ludtwig-ignore", T!["ludtwig-ignore"]);
+        add("ludtwig-config", T!["ludtwig-config"]);
         add("€", T![unknown]);
 
         // lex and compare
         let results = lex(&source);
         let found_syntax_kinds: Vec<SyntaxKind> = results.into_iter().map(|t| t.kind).collect();
-        assert_eq!(found_syntax_kinds, expected_kinds)
+        assert_eq!(found_syntax_kinds, expected_kinds);
     }
 
     #[test]
@@ -616,6 +620,18 @@ mod tests {
         check_token("}}", T!["}}"]);
     }
 
+    #[test]
+    fn lex_whitespace_control() {
+        check_regex("{%-", T!["{%"], "{%");
+        check_regex("{%~", T!["{%"], "{%");
+        check_regex("-%}", T!["%}"], "%}");
+        check_regex("~%}", T!["%}"], "%}");
+        check_regex("{{-", T!["{{"], "{{");
+        check_regex("{{~", T!["{{"], "{{");
+        check_regex("-}}", T!["}}"], "}}");
+        check_regex("~}}", T!["}}"], "}}");
+    }
+
     #[test]
     fn lex_open_curly_hashtag() {
         check_token("{#", T!["{#"]);
@@ -826,6 +842,21 @@ mod tests {
         check_token("tags", T!["tags"]);
     }
 
+    #[test]
+    fn lex_trans() {
+        check_token("trans", T!["trans"]);
+    }
+
+    #[test]
+    fn lex_endtrans() {
+        check_token("endtrans", T!["endtrans"]);
+    }
+
+    #[test]
+    fn lex_into() {
+        check_token("into", T!["into"]);
+    }
+
     #[test]
     fn lex_not() {
         check_token("not", T!["not"]);
@@ -1025,4 +1056,9 @@ mod tests {
     fn lex_ludtwig_ignore() {
         check_token("ludtwig-ignore", T!["ludtwig-ignore"]);
     }
+
+    #[test]
+    fn lex_ludtwig_config() {
+        check_token("ludtwig-config", T!["ludtwig-config"]);
+    }
 }
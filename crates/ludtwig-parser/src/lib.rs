@@ -1,9 +1,12 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
+#![allow(clippy::non_std_lazy_statics)]
 
 pub use parser::parse;
+pub use parser::parse_with_options;
 pub use parser::Parse;
 pub use parser::ParseError;
+pub use parser::ParseOptions;
 
 use crate::lexer::lex;
 
@@ -19,6 +22,7 @@ mod tests {
     use super::*;
     use crate::syntax::typed::AstNode;
     use crate::syntax::typed::HtmlTag;
+    use crate::syntax::typed::TwigBlock;
     use crate::syntax::untyped::SyntaxNode;
     use rowan::ast::support;
 
@@ -47,4 +51,27 @@ mod tests {
         // println!("{:?} prev sibling: {:?}", child, prev);
         assert!(prev.is_some());
     }
+
+    #[test]
+    fn it_should_traverse_children_with_the_typed_layer() {
+        let parse =
+            parse("{% block a %}<div class=\"b\" id=\"c\"><span>d</span>e</div>{% endblock %}");
+        let root = SyntaxNode::new_root(parse.green_node);
+
+        let block: TwigBlock = support::child(&root).unwrap();
+        assert_eq!(block.name().unwrap().text(), "a");
+
+        let div = block.children().find_map(HtmlTag::cast).unwrap();
+        assert_eq!(div.name().unwrap().text(), "div");
+        let attribute_names: Vec<String> = div
+            .attributes()
+            .filter_map(|a| a.name())
+            .map(|n| n.text().to_owned())
+            .collect();
+        assert_eq!(attribute_names, vec!["class", "id"]);
+
+        let span = div.children().find_map(HtmlTag::cast).unwrap();
+        assert_eq!(span.name().unwrap().text(), "span");
+        assert_eq!(span.children().count(), 1);
+    }
 }
@@ -25,13 +25,29 @@ pub(crate) static GENERAL_RECOVERY_SET: &[SyntaxKind] =
 
 #[must_use]
 pub fn parse(input_text: &str) -> Parse {
+    parse_with_options(input_text, &ParseOptions::default())
+}
+
+#[must_use]
+pub fn parse_with_options(input_text: &str, options: &ParseOptions) -> Parse {
     let lex_result = lex(input_text);
-    let parser = Parser::new(&lex_result);
+    let mut parser = Parser::new(&lex_result);
+    parser
+        .additional_void_elements
+        .clone_from(&options.additional_void_elements);
     let (parse_events, parse_errors) = parser.parse();
     let sink = Sink::new(&lex_result, parse_events, parse_errors);
     sink.finish()
 }
 
+/// Options which change how the input is parsed
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// Names of html elements which never have children or an ending tag
+    /// (in addition to the standard html void elements)
+    pub additional_void_elements: Vec<String>,
+}
+
 /// Result of the parser
 pub struct Parse {
     pub green_node: GreenNode,
@@ -45,7 +61,7 @@ impl Parse {
         let mut s = debug_tree(&syntax_node);
 
         for error in &self.errors {
-            let _ = write!(s, "\n{}", error);
+            let _ = write!(s, "\n{error}");
         }
 
         s
@@ -57,6 +73,10 @@ pub(crate) struct Parser<'source> {
     source: Source<'source>,
     event_collection: EventCollection,
     parse_errors: Vec<ParseError>,
+    /// Names of the html elements which are currently being parsed (innermost last)
+    open_html_tags: Vec<String>,
+    /// Names of html elements which are treated as void elements in addition to the standard ones
+    additional_void_elements: Vec<String>,
 }
 
 impl<'source> Parser<'source> {
@@ -65,6 +85,8 @@ impl<'source> Parser<'source> {
             source: Source::new(tokens),
             event_collection: EventCollection::new(),
             parse_errors: vec![],
+            open_html_tags: vec![],
+            additional_void_elements: vec![],
         }
     }
 
@@ -77,7 +99,7 @@ impl<'source> Parser<'source> {
         self.source.peek_kind()
     }
 
-    pub(crate) fn peek_token(&mut self) -> Option<&Token> {
+    pub(crate) fn peek_token(&mut self) -> Option<&Token<'_>> {
         self.source.peek_token()
     }
 
@@ -86,7 +108,7 @@ impl<'source> Parser<'source> {
     }
 
     pub(crate) fn at_set(&mut self, set: &[SyntaxKind]) -> bool {
-        self.peek().map_or(false, |k| set.contains(&k))
+        self.peek().is_some_and(|k| set.contains(&k))
     }
 
     pub(crate) fn at(&mut self, kind: SyntaxKind) -> bool {
@@ -103,12 +125,42 @@ impl<'source> Parser<'source> {
         self.source.at_following_content(set)
     }
 
+    /// Is the parser at an ending tag which belongs to any html element that is currently being parsed?
+    pub(crate) fn at_open_html_ending_tag(&mut self) -> bool {
+        if !self.at(T!["</"]) {
+            return false;
+        }
+
+        let open_html_tags = std::mem::take(&mut self.open_html_tags);
+        let found = open_html_tags.iter().any(|name| {
+            self.source
+                .at_following_content(&[(T!["</"], None), (T![word], Some(name))])
+        });
+        self.open_html_tags = open_html_tags;
+        found
+    }
+
+    /// Is the given html element one of the configured additional void elements?
+    pub(crate) fn is_additional_void_element(&self, tag_name: &str) -> bool {
+        self.additional_void_elements
+            .iter()
+            .any(|e| e.eq_ignore_ascii_case(tag_name))
+    }
+
+    pub(crate) fn push_open_html_tag(&mut self, name: String) {
+        self.open_html_tags.push(name);
+    }
+
+    pub(crate) fn pop_open_html_tag(&mut self) {
+        self.open_html_tags.pop();
+    }
+
     pub(crate) fn at_end(&mut self) -> bool {
         self.peek().is_none()
     }
 
     #[track_caller]
-    pub(crate) fn bump(&mut self) -> &Token {
+    pub(crate) fn bump(&mut self) -> &Token<'_> {
         let consumed = self
             .source
             .next_token()
@@ -119,6 +171,28 @@ impl<'source> Parser<'source> {
         consumed
     }
 
+    /// Lookahead without skipping trivia, where offset 0 is the current (non trivia) token.
+    /// Can be used to check if tokens are directly adjacent to each other.
+    pub(crate) fn peek_token_raw_at(&mut self, offset: usize) -> Option<&Token<'_>> {
+        self.source.peek_token_raw_at(offset)
+    }
+
+    /// Bumps the given amount of adjacent tokens and merges them into a single token of the provided kind.
+    /// Make sure there is no trivia in between those tokens (see `peek_token_raw_at`).
+    #[track_caller]
+    pub(crate) fn bump_merged_as(&mut self, kind: SyntaxKind, amount: usize) {
+        debug_assert!(amount > 0);
+        for _ in 0..amount {
+            let consumed = self
+                .source
+                .next_token()
+                .expect("bump called, but there are no more tokens!");
+            debug_assert!(!consumed.kind.is_trivia());
+        }
+
+        self.event_collection.add_merged_tokens(kind, amount);
+    }
+
     /// In most cases trivia like whitespace comes before any Tokens in a Node
     /// But sometimes it is necessary to consume the trivia even after the last Token in a Node.
     ///
@@ -129,7 +203,7 @@ impl<'source> Parser<'source> {
     }
 
     #[track_caller]
-    pub(crate) fn bump_as(&mut self, kind: SyntaxKind) -> Token {
+    pub(crate) fn bump_as(&mut self, kind: SyntaxKind) -> Token<'_> {
         let consumed = self
             .source
             .next_token()
@@ -148,11 +222,11 @@ impl<'source> Parser<'source> {
         &mut self,
         kind: SyntaxKind,
         recovery_set: &[SyntaxKind],
-    ) -> Option<&Token> {
+    ) -> Option<&Token<'_>> {
         if self.at(kind) {
             Some(self.bump())
         } else {
-            self.add_error(ParseErrorBuilder::new(format!("{}", kind)));
+            self.add_error(ParseErrorBuilder::new(format!("{kind}")));
             self.recover_expect(Some(kind), recovery_set)
         }
     }
@@ -171,7 +245,7 @@ impl<'source> Parser<'source> {
         &mut self,
         expected_kind: Option<SyntaxKind>,
         recovery_set: &[SyntaxKind],
-    ) -> Option<&Token> {
+    ) -> Option<&Token<'_>> {
         if self.at_end() || self.at_set(GENERAL_RECOVERY_SET) || self.at_set(recovery_set) {
             return None;
         }
@@ -240,6 +314,7 @@ impl<'source> Parser<'source> {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_pass_by_value)]
 pub(crate) fn check_parse(input: &str, expected_tree: expect_test::Expect) {
     let parse = parse(input);
     expected_tree.assert_eq(&parse.debug_parse());
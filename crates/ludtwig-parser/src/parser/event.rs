@@ -10,6 +10,11 @@ pub(super) enum Event {
     AddToken {
         kind: SyntaxKind,
     },
+    /// Merges multiple adjacent (non trivia) tokens into a single token
+    AddMergedTokens {
+        kind: SyntaxKind,
+        amount: usize,
+    },
     FinishNode,
     /// Should consume any amount of trivia at this exact point in the tree
     ExplicitlyConsumeTrivia,
@@ -37,6 +42,10 @@ impl EventCollection {
         self.events.push(Event::AddToken { kind });
     }
 
+    pub(super) fn add_merged_tokens(&mut self, kind: SyntaxKind, amount: usize) {
+        self.events.push(Event::AddMergedTokens { kind, amount });
+    }
+
     pub(super) fn explicitly_consume_trivia(&mut self) {
         self.events.push(Event::ExplicitlyConsumeTrivia);
     }
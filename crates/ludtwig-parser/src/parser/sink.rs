@@ -35,7 +35,11 @@ impl<'source> Sink<'source> {
         let mut forward_kinds = Vec::new();
 
         for idx in 0..self.events.len() {
-            if matches!(self.events[idx], Event::AddToken { .. }) || idx == self.events.len() - 1 {
+            if matches!(
+                self.events[idx],
+                Event::AddToken { .. } | Event::AddMergedTokens { .. }
+            ) || idx == self.events.len() - 1
+            {
                 // consume trivia before any token event or the last event
                 self.consume_trivia();
             }
@@ -76,6 +80,7 @@ impl<'source> Sink<'source> {
                     }
                 }
                 Event::AddToken { kind } => self.token_as(kind),
+                Event::AddMergedTokens { kind, amount } => self.merged_tokens_as(kind, amount),
                 Event::ExplicitlyConsumeTrivia => self.consume_trivia(),
                 Event::FinishNode => self.builder.finish_node(),
                 Event::Placeholder => {}
@@ -123,6 +128,18 @@ impl<'source> Sink<'source> {
             .token(TemplateLanguage::kind_to_raw(kind), text);
         self.cursor += 1;
     }
+
+    /// add multiple adjacent tokens as a single token with another kind that the parser has specified
+    fn merged_tokens_as(&mut self, kind: SyntaxKind, amount: usize) {
+        let text: String = self.tokens[self.cursor..self.cursor + amount]
+            .iter()
+            .map(|t| t.text)
+            .collect();
+
+        self.builder
+            .token(TemplateLanguage::kind_to_raw(kind), &text);
+        self.cursor += amount;
+    }
 }
 
 #[cfg(test)]
@@ -29,7 +29,7 @@ impl<'source> Source<'source> {
         self.peek_kind_raw()
     }
 
-    pub(super) fn peek_token(&mut self) -> Option<&Token> {
+    pub(super) fn peek_token(&mut self) -> Option<&Token<'_>> {
         self.eat_trivia();
         self.peek_token_raw()
     }
@@ -48,7 +48,7 @@ impl<'source> Source<'source> {
 
         loop {
             match (tokens_iter.next(), set_iter.next()) {
-                (Some(token), Some(set)) if token == *set => continue,
+                (Some(token), Some(set)) if token == *set => {}
                 (None | Some(_), None) => return true,
                 _ => return false,
             }
@@ -70,16 +70,19 @@ impl<'source> Source<'source> {
             match (tokens_iter.next(), set_iter.next()) {
                 (Some(token), Some((set_kind, set_content)))
                     if token.kind == *set_kind
-                        && set_content.map_or(true, |content| content == token.text) =>
-                {
-                    continue
-                }
+                        && set_content.is_none_or(|content| content == token.text) => {}
                 (None | Some(_), None) => return true,
                 _ => return false,
             }
         }
     }
 
+    /// Token at the offset from the current token without skipping any trivia in between
+    pub(super) fn peek_token_raw_at(&mut self, offset: usize) -> Option<&Token<'_>> {
+        self.eat_trivia();
+        self.tokens.get(self.cursor + offset)
+    }
+
     pub(super) fn last_token_range(&self) -> Option<TextRange> {
         self.tokens.last().map(|Token { range, .. }| *range)
     }
@@ -95,14 +98,14 @@ impl<'source> Source<'source> {
     }
 
     fn at_trivia(&self) -> bool {
-        self.peek_kind_raw().map_or(false, SyntaxKind::is_trivia)
+        self.peek_kind_raw().is_some_and(SyntaxKind::is_trivia)
     }
 
     fn peek_kind_raw(&self) -> Option<SyntaxKind> {
         self.peek_token_raw().map(|Token { kind, .. }| *kind)
     }
 
-    fn peek_token_raw(&self) -> Option<&Token> {
+    fn peek_token_raw(&self) -> Option<&Token<'_>> {
         self.tokens.get(self.cursor)
     }
 }
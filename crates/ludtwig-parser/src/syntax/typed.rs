@@ -78,6 +78,12 @@ impl TwigBlock {
     pub fn ending_block(&self) -> Option<TwigEndingBlock> {
         support::child(&self.syntax)
     }
+
+    /// Child nodes inside the body of the twig block
+    #[must_use = "the child nodes are only iterated when the iterator is consumed"]
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.body().into_iter().flat_map(|b| b.syntax.children())
+    }
 }
 
 ast_node!(TwigStartingBlock, SyntaxKind::TWIG_STARTING_BLOCK);
@@ -100,6 +106,12 @@ impl TwigStartingBlock {
 
 ast_node!(TwigEndingBlock, SyntaxKind::TWIG_ENDING_BLOCK);
 impl TwigEndingBlock {
+    /// Optional name of the twig block behind endblock
+    #[must_use]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![word])
+    }
+
     /// Parent complete twig block
     #[must_use]
     pub fn twig_block(&self) -> Option<TwigBlock> {
@@ -145,6 +157,12 @@ impl HtmlTag {
     pub fn ending_tag(&self) -> Option<HtmlEndingTag> {
         support::child(&self.syntax)
     }
+
+    /// Child nodes inside the body of the tag
+    #[must_use = "the child nodes are only iterated when the iterator is consumed"]
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.body().into_iter().flat_map(|b| b.syntax.children())
+    }
 }
 
 ast_node!(HtmlStartingTag, SyntaxKind::HTML_STARTING_TAG);
@@ -268,6 +286,39 @@ impl LudtwigDirectiveIgnore {
     }
 }
 
+ast_node!(LudtwigDirectiveConfig, SyntaxKind::LUDTWIG_DIRECTIVE_CONFIG);
+impl LudtwigDirectiveConfig {
+    #[must_use]
+    pub fn get_options(&self) -> AstChildren<LudtwigDirectiveConfigOption> {
+        support::children(&self.syntax)
+    }
+}
+
+ast_node!(
+    LudtwigDirectiveConfigOption,
+    SyntaxKind::LUDTWIG_DIRECTIVE_CONFIG_OPTION
+);
+impl LudtwigDirectiveConfigOption {
+    /// Name of the config option (left side of the equal sign)
+    #[must_use]
+    pub fn get_key(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![word])
+    }
+
+    /// Value of the config option (right side of the equal sign)
+    #[must_use]
+    pub fn get_value(&self) -> Option<SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|element| element.kind() != T!["="])
+            .skip(1)
+            .find_map(|element| match element {
+                NodeOrToken::Token(t) if !t.kind().is_trivia() => Some(t),
+                _ => None,
+            })
+    }
+}
+
 ast_node!(TwigLiteralString, SyntaxKind::TWIG_LITERAL_STRING);
 impl TwigLiteralString {
     #[must_use]
@@ -481,6 +532,28 @@ ast_node!(
     SyntaxKind::TWIG_CACHE_STARTING_BLOCK
 );
 ast_node!(TwigCacheEndingBlock, SyntaxKind::TWIG_CACHE_ENDING_BLOCK);
+ast_node!(TwigTrans, SyntaxKind::TWIG_TRANS);
+impl TwigTrans {
+    #[must_use]
+    pub fn starting_block(&self) -> Option<TwigTransStartingBlock> {
+        support::child(&self.syntax)
+    }
+
+    #[must_use]
+    pub fn body(&self) -> Option<Body> {
+        support::child(&self.syntax)
+    }
+
+    #[must_use]
+    pub fn ending_block(&self) -> Option<TwigTransEndingBlock> {
+        support::child(&self.syntax)
+    }
+}
+ast_node!(
+    TwigTransStartingBlock,
+    SyntaxKind::TWIG_TRANS_STARTING_BLOCK
+);
+ast_node!(TwigTransEndingBlock, SyntaxKind::TWIG_TRANS_ENDING_BLOCK);
 ast_node!(ShopwareTwigExtends, SyntaxKind::SHOPWARE_TWIG_SW_EXTENDS);
 ast_node!(ShopwareTwigInclude, SyntaxKind::SHOPWARE_TWIG_SW_INCLUDE);
 ast_node!(
@@ -504,6 +577,22 @@ ast_node!(HtmlDoctype, SyntaxKind::HTML_DOCTYPE);
 ast_node!(HtmlAttributeList, SyntaxKind::HTML_ATTRIBUTE_LIST);
 ast_node!(HtmlStringInner, SyntaxKind::HTML_STRING_INNER);
 ast_node!(HtmlText, SyntaxKind::HTML_TEXT);
+ast_node!(HtmlRawText, SyntaxKind::HTML_RAW_TEXT);
 ast_node!(HtmlComment, SyntaxKind::HTML_COMMENT);
+impl HtmlComment {
+    /// Raw text between the `<!--` and `-->` markers (including surrounding whitespace)
+    #[must_use]
+    pub fn get_text(&self) -> String {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .skip_while(|t| t.kind() != T!["<!--"])
+            .skip(1)
+            .take_while(|t| t.kind() != T!["-->"])
+            .map(|t| t.text().to_owned())
+            .collect()
+    }
+}
+
 ast_node!(Error, SyntaxKind::ERROR);
 ast_node!(Root, SyntaxKind::ROOT);
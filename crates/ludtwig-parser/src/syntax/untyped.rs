@@ -130,13 +130,13 @@ pub enum SyntaxKind {
     TK_SINGLE_QUOTES,
     #[token("`")]
     TK_GRAVE_ACCENT_QUOTES,
-    #[token("{%")]
+    #[regex(r"\{%[-~]?")]
     TK_CURLY_PERCENT,
-    #[token("%}")]
+    #[regex(r"[-~]?%\}")]
     TK_PERCENT_CURLY,
-    #[token("{{")]
+    #[regex(r"\{\{[-~]?")]
     TK_OPEN_CURLY_CURLY,
-    #[token("}}")]
+    #[regex(r"[-~]?\}\}")]
     TK_CLOSE_CURLY_CURLY,
     #[token("{#")]
     TK_OPEN_CURLY_HASHTAG,
@@ -225,6 +225,12 @@ pub enum SyntaxKind {
     TK_TTL,
     #[token("tags")]
     TK_TAGS,
+    #[token("trans")]
+    TK_TRANS,
+    #[token("endtrans")]
+    TK_ENDTRANS,
+    #[token("into")]
+    TK_INTO,
     /* twig operators */
     #[token("not")]
     TK_NOT,
@@ -312,6 +318,8 @@ pub enum SyntaxKind {
     TK_LUDTWIG_IGNORE_FILE,
     #[token("ludtwig-ignore", ignore(ascii_case))]
     TK_LUDTWIG_IGNORE,
+    #[token("ludtwig-config", ignore(ascii_case))]
+    TK_LUDTWIG_CONFIG,
     #[error]
     TK_UNKNOWN, // contains invalid / unrecognized syntax (used for error recovery).
 
@@ -429,6 +437,10 @@ pub enum SyntaxKind {
     TWIG_CACHE_TAGS,
     TWIG_CACHE_STARTING_BLOCK,
     TWIG_CACHE_ENDING_BLOCK,
+    // twig trans (symfony translation)
+    TWIG_TRANS,
+    TWIG_TRANS_STARTING_BLOCK,
+    TWIG_TRANS_ENDING_BLOCK,
 
     // shopware specific
     SHOPWARE_TWIG_SW_EXTENDS,
@@ -449,6 +461,7 @@ pub enum SyntaxKind {
     HTML_STRING,       // used as attribute values
     HTML_STRING_INNER, // content inside the quotes of html attribute values
     HTML_TEXT,         // used as plain text between html tags / twig blocks
+    HTML_RAW_TEXT,     // content of script and style tags (which is not parsed as html)
     HTML_COMMENT,
     HTML_TAG,
     HTML_STARTING_TAG,
@@ -458,6 +471,8 @@ pub enum SyntaxKind {
     LUDTWIG_DIRECTIVE_FILE_IGNORE,
     LUDTWIG_DIRECTIVE_IGNORE,
     LUDTWIG_DIRECTIVE_RULE_LIST,
+    LUDTWIG_DIRECTIVE_CONFIG,
+    LUDTWIG_DIRECTIVE_CONFIG_OPTION,
     /*
     Special Nodes
      */
@@ -566,6 +581,9 @@ macro_rules! T {
     ["endwith"] => { $crate::syntax::untyped::SyntaxKind::TK_ENDWITH };
     ["ttl"] => { $crate::syntax::untyped::SyntaxKind::TK_TTL };
     ["tags"] => { $crate::syntax::untyped::SyntaxKind::TK_TAGS };
+    ["trans"] => { $crate::syntax::untyped::SyntaxKind::TK_TRANS };
+    ["endtrans"] => { $crate::syntax::untyped::SyntaxKind::TK_ENDTRANS };
+    ["into"] => { $crate::syntax::untyped::SyntaxKind::TK_INTO };
     ["not"] => { $crate::syntax::untyped::SyntaxKind::TK_NOT };
     ["or"] => { $crate::syntax::untyped::SyntaxKind::TK_OR };
     ["and"] => { $crate::syntax::untyped::SyntaxKind::TK_AND };
@@ -606,6 +624,7 @@ macro_rules! T {
     ["style"] => { $crate::syntax::untyped::SyntaxKind::TK_STYLE };
     ["ludtwig-ignore-file"] => { $crate::syntax::untyped::SyntaxKind::TK_LUDTWIG_IGNORE_FILE };
     ["ludtwig-ignore"] => { $crate::syntax::untyped::SyntaxKind::TK_LUDTWIG_IGNORE };
+    ["ludtwig-config"] => { $crate::syntax::untyped::SyntaxKind::TK_LUDTWIG_CONFIG };
 }
 
 impl SyntaxKind {
@@ -717,6 +736,9 @@ impl fmt::Display for SyntaxKind {
             SyntaxKind::TK_ENDWITH => "endwith",
             SyntaxKind::TK_TTL => "ttl",
             SyntaxKind::TK_TAGS => "tags",
+            SyntaxKind::TK_TRANS => "trans",
+            SyntaxKind::TK_ENDTRANS => "endtrans",
+            SyntaxKind::TK_INTO => "into",
             SyntaxKind::TK_NOT => "not",
             SyntaxKind::TK_OR => "or",
             SyntaxKind::TK_AND => "and",
@@ -757,6 +779,7 @@ impl fmt::Display for SyntaxKind {
             SyntaxKind::TK_STYLE => "style",
             SyntaxKind::TK_LUDTWIG_IGNORE_FILE => "ludtwig-ignore-file",
             SyntaxKind::TK_LUDTWIG_IGNORE => "ludtwig-ignore",
+            SyntaxKind::TK_LUDTWIG_CONFIG => "ludtwig-config",
             SyntaxKind::TK_UNKNOWN => "unknown",
             SyntaxKind::ERROR => "error",
             t => unreachable!("Display not implemented for {:?}", t),
@@ -796,7 +819,6 @@ impl Language for TemplateLanguage {
 /// It is also immutable, like a `GreenNode`,
 /// but it contains parent pointers, offsets, and
 /// has identity semantics.
-
 pub type SyntaxNode = rowan::SyntaxNode<TemplateLanguage>;
 pub type SyntaxToken = rowan::SyntaxToken<TemplateLanguage>;
 pub type SyntaxElement = rowan::NodeOrToken<SyntaxNode, SyntaxToken>;
@@ -806,7 +828,7 @@ pub type PreorderWithTokens = rowan::api::PreorderWithTokens<TemplateLanguage>;
 
 #[must_use]
 pub fn debug_tree(syntax_node: &SyntaxNode) -> String {
-    let formatted = format!("{:#?}", syntax_node);
+    let formatted = format!("{syntax_node:#?}");
     // We cut off the last byte because formatting the SyntaxNode adds on a newline at the end.
     formatted[0..formatted.len() - 1].to_string()
 }
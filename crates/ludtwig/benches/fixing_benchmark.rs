@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ludtwig::bench::CheckedTemplate;
+
+const TEMPLATE_LINES: usize = 5000;
+
+/// Template with `TEMPLATE_LINES` lines, every fifth line contains trailing whitespace
+/// which is fixed (fixes=false creates the same template without anything to fix).
+fn template(fixes: bool) -> String {
+    let mut template = String::new();
+    for i in 0..TEMPLATE_LINES / 5 {
+        let trailing = if fixes { "   " } else { "" };
+        template.push_str(&format!(
+            "<div class=\"card\">{trailing}\n    <p>{{{{ item{i}.title }}}}</p>\n    <span>{i}</span>\n</div>\n{{# item {i} #}}\n"
+        ));
+    }
+
+    template
+}
+
+fn fixing_5000_lines_benchmark(c: &mut Criterion) {
+    let template = template(true);
+
+    c.bench_function("fixing 5000 lines", |b| {
+        b.iter_batched(
+            || CheckedTemplate::new(&template),
+            |checked| black_box(checked.fix()),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+/// Suggestions which don't change anything end the fixing without another iteration.
+/// Compare it with the iteration they would cause otherwise (parsing and running all rules again).
+fn unchanged_suggestions_5000_lines_benchmark(c: &mut Criterion) {
+    let template = template(false);
+
+    let mut group = c.benchmark_group("unchanged suggestions on 5000 lines");
+    group.bench_function("skipped", |b| {
+        b.iter_batched(
+            || CheckedTemplate::new(&template).with_unchanged_suggestions(),
+            |checked| black_box(checked.fix()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("reparsed", |b| {
+        let checked = CheckedTemplate::new(&template).with_unchanged_suggestions();
+        b.iter(|| black_box(checked.reparse()))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = fixing_5000_lines_benchmark, unchanged_suggestions_5000_lines_benchmark
+);
+criterion_main!(benches);
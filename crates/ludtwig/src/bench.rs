@@ -0,0 +1,130 @@
+//! In-process access to the processing of a single template for the benchmarks in `benches/`.
+//! This is not a stable API and only available with the `bench` feature
+//! (`cargo bench --features bench`).
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::{mpsc, Arc};
+
+use figment::providers::{Format, Toml};
+use figment::Figment;
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange};
+
+use crate::check::rule::{CheckResult, Severity};
+use crate::check::rules::{get_config_active_rule_definitions, get_file_active_rule_definitions};
+use crate::check::run_rules;
+use crate::config::{get_file_config, DEFAULT_RAW_CONFIG};
+use crate::output::{OutputFormat, ProcessingEvent};
+use crate::process::{iteratively_apply_suggestions, parse_source, FileContext};
+use crate::{CliContext, CliSharedData, Config};
+
+/// A parsed template with the results of all rules which are active in the default config
+/// (the embedded one, so the results don't depend on the config of the current directory)
+pub struct CheckedTemplate {
+    file_context: FileContext,
+    check_results: Vec<CheckResult>,
+    /// keeps the output channel of the file context open
+    _output_rx: Receiver<ProcessingEvent>,
+}
+
+impl CheckedTemplate {
+    /// # Panics
+    /// If the default config or its active rules are invalid.
+    #[must_use]
+    pub fn new(source_code: &str) -> Self {
+        let config: Config = Figment::new()
+            .merge(Toml::string(DEFAULT_RAW_CONFIG))
+            .extract()
+            .expect("the default config should be valid");
+        let rule_definitions = get_config_active_rule_definitions(&config)
+            .expect("the default active rules should exist");
+
+        let (tx, rx) = mpsc::channel();
+        let parse = parse_source(source_code, &config);
+        let tree_root = SyntaxNode::new_root(parse.green_node);
+        let file_rule_definitions = get_file_active_rule_definitions(&tree_root, &rule_definitions);
+        let file_config = Arc::new(get_file_config(&tree_root, &config));
+
+        let file_context = FileContext {
+            cli_context: CliContext {
+                output_tx: tx,
+                data: Arc::new(CliSharedData {
+                    fix: true,
+                    fix_dry_run: false,
+                    inspect: false,
+                    deny_help: false,
+                    output_format: OutputFormat::Human,
+                    stdin: false,
+                    config,
+                    rule_definitions,
+                }),
+            },
+            file_path: PathBuf::from("./bench.html.twig"),
+            tree_root,
+            source_code: source_code.to_owned(),
+            parse_errors: parse.errors,
+            file_rule_definitions,
+            file_config,
+        };
+        let check_results = run_rules(&file_context);
+
+        Self {
+            file_context,
+            check_results,
+            _output_rx: rx,
+        }
+    }
+
+    /// Add a suggestion for every line break, which replaces it with itself (it doesn't change anything)
+    #[must_use]
+    pub fn with_unchanged_suggestions(mut self) -> Self {
+        let source_code = &self.file_context.source_code;
+        for (offset, _) in source_code.match_indices('\n') {
+            let range = TextRange::at(u32::try_from(offset).unwrap().into(), 1.into());
+            self.check_results.push(
+                CheckResult {
+                    rule_name: "bench",
+                    severity: Severity::Help,
+                    message: String::new(),
+                    primary: None,
+                    secondary: vec![],
+                    suggestions: vec![],
+                }
+                .suggestion(range, "\n", "unchanged"),
+            );
+        }
+
+        self
+    }
+
+    /// Apply the suggestions until nothing changes anymore and return the number of iterations
+    ///
+    /// # Panics
+    /// If the suggestions can't be applied.
+    #[must_use]
+    pub fn fix(self) -> usize {
+        let (.., iterations) = iteratively_apply_suggestions(self.file_context, self.check_results)
+            .expect("the suggestions should be applicable");
+        iterations
+    }
+
+    /// Parse the template again and run all rules on it (one fixing iteration without the changes)
+    /// and return the number of results
+    #[must_use]
+    pub fn reparse(&self) -> usize {
+        let parse = parse_source(
+            &self.file_context.source_code,
+            &self.file_context.cli_context.data.config,
+        );
+        let file_context = FileContext {
+            cli_context: self.file_context.cli_context.clone(),
+            file_path: self.file_context.file_path.clone(),
+            tree_root: SyntaxNode::new_root(parse.green_node),
+            source_code: self.file_context.source_code.clone(),
+            parse_errors: parse.errors,
+            file_rule_definitions: self.file_context.file_rule_definitions.clone(),
+            file_config: Arc::clone(&self.file_context.file_config),
+        };
+
+        run_rules(&file_context).len()
+    }
+}
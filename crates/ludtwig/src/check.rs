@@ -7,13 +7,16 @@ use codespan_reporting::term::termcolor::Buffer;
 
 use ludtwig_parser::syntax::typed;
 use ludtwig_parser::syntax::typed::{AstNode, HtmlTag, LudtwigDirectiveIgnore};
-use ludtwig_parser::syntax::untyped::{debug_tree, SyntaxElement, SyntaxToken, WalkEvent};
+use ludtwig_parser::syntax::untyped::{
+    debug_tree, SyntaxElement, SyntaxNode, SyntaxToken, TextRange, WalkEvent,
+};
 
 use crate::check::rule::{
     CheckResult, CheckSuggestion, RuleRunContext, Severity, TreeTraversalContext,
 };
+use crate::config::RuleSeverity;
 use crate::process::FileContext;
-use crate::ProcessingEvent;
+use crate::{Config, ProcessingEvent};
 
 pub mod rule;
 pub mod rules;
@@ -21,9 +24,10 @@ pub mod rules;
 pub fn run_rules(file_context: &FileContext) -> Vec<CheckResult> {
     let mut check_results = vec![];
     let mut run_context = RuleRunContext {
-        cli_data: Arc::clone(&file_context.cli_context.data),
+        file_config: Arc::clone(&file_context.file_config),
         traversal_ctx: TreeTraversalContext {
             inside_trivia_sensitive_node: false,
+            inside_foreign_element: false,
         },
     };
 
@@ -43,12 +47,7 @@ pub fn run_rules(file_context: &FileContext) -> Vec<CheckResult> {
      */
 
     // run root node checks once for each rule
-    let rule_results_iter = file_context
-        .file_rule_definitions
-        .iter()
-        .filter_map(|rule| rule.check_root(file_context.tree_root.clone(), &run_context))
-        .flatten();
-    check_results.extend(rule_results_iter);
+    check_results.extend(run_root_checks(file_context, &run_context));
 
     // iterate through syntax tree
     let mut ignored_rules: Vec<String> = vec![];
@@ -88,11 +87,7 @@ pub fn run_rules(file_context: &FileContext) -> Vec<CheckResult> {
 
                         // adjust traversal context when entering special nodes
                         if let Some(t) = HtmlTag::cast(n.clone()) {
-                            if let Some("pre" | "textarea") =
-                                t.name().as_ref().map(SyntaxToken::text)
-                            {
-                                run_context.traversal_ctx.inside_trivia_sensitive_node = true;
-                            }
+                            enter_special_node(&mut run_context.traversal_ctx, &t);
                         }
 
                         // run node checks for every rule
@@ -141,20 +136,92 @@ pub fn run_rules(file_context: &FileContext) -> Vec<CheckResult> {
                 }
 
                 // adjust traversal context when exiting special nodes
-                if let SyntaxElement::Node(n) = element {
-                    if let Some(t) = HtmlTag::cast(n) {
-                        if let Some("pre" | "textarea") = t.name().as_ref().map(SyntaxToken::text) {
-                            run_context.traversal_ctx.inside_trivia_sensitive_node = false;
-                        }
-                    }
+                if let Some(t) = element.into_node().and_then(HtmlTag::cast) {
+                    leave_special_node(&mut run_context.traversal_ctx, &t);
                 }
             }
         }
     }
 
+    apply_severity_overrides(&mut check_results, &file_context.file_config);
     check_results
 }
 
+/// Use the configured severity for the reports of rules from the severity config section
+fn apply_severity_overrides(check_results: &mut [CheckResult], config: &Config) {
+    for result in check_results {
+        result.severity = match config.severity.get(result.rule_name) {
+            Some(RuleSeverity::Error) => Severity::Error,
+            Some(RuleSeverity::Warning) => Severity::Warning,
+            Some(RuleSeverity::Help) => Severity::Help,
+            Some(RuleSeverity::Info) => Severity::Info,
+            Some(RuleSeverity::Off) | None => continue,
+        };
+    }
+}
+
+fn run_root_checks(file_context: &FileContext, run_context: &RuleRunContext) -> Vec<CheckResult> {
+    let ignored_ranges = ignored_node_ranges(&file_context.tree_root);
+    file_context
+        .file_rule_definitions
+        .iter()
+        .filter_map(|rule| rule.check_root(file_context.tree_root.clone(), run_context))
+        .flatten()
+        .filter(|result| {
+            // root checks see the whole tree, so their results inside of ignored nodes are dropped here
+            !result.primary.as_ref().is_some_and(|primary| {
+                ignored_ranges.iter().any(|(range, rules)| {
+                    range.contains_range(primary.syntax_range)
+                        && (rules.is_empty() || rules.iter().any(|r| r == result.rule_name))
+                })
+            })
+        })
+        .collect()
+}
+
+/// Ranges of the nodes following an ignore directive together with the ignored rules
+/// (no rules means all rules are ignored)
+fn ignored_node_ranges(root: &SyntaxNode) -> Vec<(TextRange, Vec<String>)> {
+    root.descendants()
+        .filter_map(LudtwigDirectiveIgnore::cast)
+        .filter_map(|directive| {
+            let ignored = directive.syntax().next_sibling_or_token()?;
+            Some((ignored.text_range(), directive.get_rules()))
+        })
+        .collect()
+}
+
+fn enter_special_node(traversal_ctx: &mut TreeTraversalContext, tag: &HtmlTag) {
+    if let Some("pre" | "textarea") = tag.name().as_ref().map(SyntaxToken::text) {
+        traversal_ctx.inside_trivia_sensitive_node = true;
+    }
+    if is_foreign_element(tag) {
+        traversal_ctx.inside_foreign_element = true;
+    }
+}
+
+fn leave_special_node(traversal_ctx: &mut TreeTraversalContext, tag: &HtmlTag) {
+    if let Some("pre" | "textarea") = tag.name().as_ref().map(SyntaxToken::text) {
+        traversal_ctx.inside_trivia_sensitive_node = false;
+    }
+    // foreign elements can be nested (like <math> inside of <svg>)
+    if is_foreign_element(tag)
+        && !tag
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(HtmlTag::cast)
+            .any(|a| is_foreign_element(&a))
+    {
+        traversal_ctx.inside_foreign_element = false;
+    }
+}
+
+fn is_foreign_element(tag: &HtmlTag) -> bool {
+    tag.name()
+        .is_some_and(|name| matches!(name.text(), "svg" | "math"))
+}
+
 pub fn get_rule_context_suggestions(
     check_results: &[CheckResult],
 ) -> Vec<(&'static str, &CheckSuggestion)> {
@@ -226,6 +293,12 @@ pub fn produce_diagnostics(
                 .push(Label::primary(file_id, primary.syntax_range).with_message(primary.message));
         }
 
+        for secondary in result.secondary {
+            labels.push(
+                Label::secondary(file_id, secondary.syntax_range).with_message(secondary.message),
+            );
+        }
+
         for suggestion in result.suggestions {
             labels.push(
                 Label::secondary(file_id, suggestion.syntax_range).with_message(format!(
@@ -1,4 +1,4 @@
-use crate::{CliSharedData, Config};
+use crate::Config;
 use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxToken, TextRange};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
@@ -7,6 +7,24 @@ pub trait Rule: Sync {
     /// A unique, kebab-case name for the rule.
     fn name(&self) -> &'static str;
 
+    /// A short description of what the rule checks (shown by `--list-rules`).
+    /// Defaults to an empty description.
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Whether the reports of the rule contain suggestions which can be applied with `--fix`.
+    /// Defaults to false.
+    fn supports_autofix(&self) -> bool {
+        false
+    }
+
+    /// The severity of the reports of the rule without an override in the `[severity]` config section.
+    /// Defaults to [`Severity::Warning`].
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check an individual untyped node in the syntax tree.
     /// The conversion to a typed AST node can be made at any time with a simple call to cast.
     /// Defaults to doing nothing.
@@ -63,6 +81,7 @@ impl<R: Rule> RuleExt for R {
             severity,
             message: message.into(),
             primary: None,
+            secondary: vec![],
             suggestions: vec![],
         }
     }
@@ -77,19 +96,22 @@ impl Debug for dyn Rule {
 #[derive(Debug)]
 pub struct TreeTraversalContext {
     pub inside_trivia_sensitive_node: bool,
+    /// Inside of `<svg>` or `<math>`, where HTML specific rules (like attribute naming) don't apply
+    pub inside_foreign_element: bool,
 }
 
 #[derive(Debug)]
 pub struct RuleRunContext {
     // file_id
     // source_text
-    pub(super) cli_data: Arc<CliSharedData>,
+    /// config for the current file (may contain overrides from ludtwig-config directives)
+    pub(super) file_config: Arc<Config>,
     pub(super) traversal_ctx: TreeTraversalContext,
 }
 
 impl RuleRunContext {
     pub fn config(&self) -> &Config {
-        &self.cli_data.config
+        &self.file_config
     }
 
     pub fn traversal_ctx(&self) -> &TreeTraversalContext {
@@ -100,11 +122,12 @@ impl RuleRunContext {
 #[derive(Debug)]
 pub struct CheckResult {
     // file_id
-    pub(super) rule_name: &'static str,
-    pub(super) severity: Severity,
-    pub(super) message: String,
-    pub(super) primary: Option<CheckNote>,
-    pub(super) suggestions: Vec<CheckSuggestion>,
+    pub(crate) rule_name: &'static str,
+    pub(crate) severity: Severity,
+    pub(crate) message: String,
+    pub(crate) primary: Option<CheckNote>,
+    pub(crate) secondary: Vec<CheckNote>,
+    pub(crate) suggestions: Vec<CheckSuggestion>,
 }
 
 impl CheckResult {
@@ -119,14 +142,14 @@ impl CheckResult {
         self
     }
 
-    // The secondary (blue) label which can provide more context and explain the error to a user.
-    // pub fn secondary_note<S: Into<String>>(mut self, syntax_range: TextRange, message: S) -> Self {
-    //     self.secondary.push(CheckNote {
-    //         syntax_range,
-    //         message: message.into(),
-    //     });
-    //     self
-    // }
+    /// The secondary (blue) label which can provide more context and explain the error to a user.
+    pub fn secondary_note<S: Into<String>>(mut self, syntax_range: TextRange, message: S) -> Self {
+        self.secondary.push(CheckNote {
+            syntax_range,
+            message: message.into(),
+        });
+        self
+    }
 
     /// Add a code suggestion which the user can follow or is replaced automatically
     pub fn suggestion<R: Into<String>, S: Into<String>>(
@@ -1,39 +1,188 @@
 use crate::check::rule::Rule;
+use crate::check::rules::anchor_needs_href::RuleAnchorNeedsHref;
+use crate::check::rules::attribute_expression_quote_style::RuleAttributeExpressionQuoteStyle;
+use crate::check::rules::autocomplete_security::RuleAutocompleteSecurity;
+use crate::check::rules::block_function_target_exists::RuleBlockFunctionTargetExists;
+use crate::check::rules::block_header_clean::RuleBlockHeaderClean;
+use crate::check::rules::block_max_nesting::RuleBlockMaxNesting;
+use crate::check::rules::block_name_content_contract::RuleBlockNameContentContract;
+use crate::check::rules::block_name_denylist::RuleBlockNameDenylist;
+use crate::check::rules::block_name_manifest::RuleBlockNameManifest;
+use crate::check::rules::boolean_literal_casing::RuleBooleanLiteralCasing;
+use crate::check::rules::class_attribute_typo::RuleClassAttributeTypo;
+use crate::check::rules::class_static_before_conditional::RuleClassStaticBeforeConditional;
+use crate::check::rules::closing_bracket_placement::RuleClosingBracketPlacement;
+use crate::check::rules::comment_spacing::RuleCommentSpacing;
+use crate::check::rules::commented_out_code::RuleCommentedOutCode;
+use crate::check::rules::consistent_indent_width::RuleConsistentIndentWidth;
+use crate::check::rules::constant_condition::RuleConstantCondition;
+use crate::check::rules::dangerous_twig_constructs::RuleDangerousTwigConstructs;
+use crate::check::rules::data_boolean_convention::RuleDataBooleanConvention;
+use crate::check::rules::declare_extends_or_standalone::RuleDeclareExtendsOrStandalone;
+use crate::check::rules::div_soup::RuleDivSoup;
+use crate::check::rules::duplicate_meta::RuleDuplicateMeta;
+use crate::check::rules::empty_conditional::RuleEmptyConditional;
+use crate::check::rules::empty_element_format::RuleEmptyElementFormat;
+use crate::check::rules::empty_trans_block::RuleEmptyTransBlock;
+use crate::check::rules::endblock_spacing::RuleEndblockSpacing;
+use crate::check::rules::enumerated_attribute_values::RuleEnumeratedAttributeValues;
+use crate::check::rules::final_newline::RuleFinalNewline;
+use crate::check::rules::form_file_enctype::RuleFormFileEnctype;
+use crate::check::rules::framework_directive_naming::RuleFrameworkDirectiveNaming;
+use crate::check::rules::hex_color_casing::RuleHexColorCasing;
 use crate::check::rules::html_attribute_name_kebab_case::RuleHtmlAttributeNameKebabCase;
 use crate::check::rules::html_string_quotation::RuleHtmlStringQuotation;
+use crate::check::rules::img_dimensions::RuleImgDimensions;
+use crate::check::rules::imports_at_top::RuleImportsAtTop;
+use crate::check::rules::include_modifier_order::RuleIncludeModifierOrder;
+use crate::check::rules::include_with_key_order::RuleIncludeWithKeyOrder;
 use crate::check::rules::indentation::RuleIndentation;
+use crate::check::rules::label_single_control::RuleLabelSingleControl;
+use crate::check::rules::large_inline_svg::RuleLargeInlineSvg;
 use crate::check::rules::line_ending::RuleLineEnding;
+use crate::check::rules::loop_item_key::RuleLoopItemKey;
+use crate::check::rules::ludtwig_config_valid::RuleLudtwigConfigValid;
 use crate::check::rules::ludtwig_ignore_file_not_on_top::RuleLudtwigIgnoreFileNotOnTop;
+use crate::check::rules::max_template_size::RuleMaxTemplateSize;
+use crate::check::rules::merge_conditional_class::RuleMergeConditionalClass;
+use crate::check::rules::misnested_tags::RuleMisnestedTags;
+use crate::check::rules::multiline_attribute_value_indent::RuleMultilineAttributeValueIndent;
+use crate::check::rules::no_access_key::RuleNoAccessKey;
+use crate::check::rules::no_duplicate_attribute::RuleNoDuplicateAttribute;
+use crate::check::rules::no_duplicate_set::RuleNoDuplicateSet;
+use crate::check::rules::no_duplicate_token_in_list::RuleNoDuplicateTokenInList;
+use crate::check::rules::no_inline_style::RuleNoInlineStyle;
+use crate::check::rules::no_internal_twig_variables::RuleNoInternalTwigVariables;
+use crate::check::rules::no_nested_form::RuleNoNestedForm;
+use crate::check::rules::no_trailing_content_after_close::RuleNoTrailingContentAfterClose;
+use crate::check::rules::non_standard_attribute::RuleNonStandardAttribute;
+use crate::check::rules::null_coalescing_style::RuleNullCoalescingStyle;
+use crate::check::rules::number_input_bounds::RuleNumberInputBounds;
+use crate::check::rules::redundant_role::RuleRedundantRole;
+use crate::check::rules::script_loading::RuleScriptLoading;
+use crate::check::rules::select_placeholder_option::RuleSelectPlaceholderOption;
+use crate::check::rules::set_block_for_long_values::RuleSetBlockForLongValues;
+use crate::check::rules::simplify_if_else::RuleSimplifyIfElse;
+use crate::check::rules::table_caption::RuleTableCaption;
+use crate::check::rules::table_cell_parent::RuleTableCellParent;
+use crate::check::rules::trailing_slash_links::RuleTrailingSlashLinks;
+use crate::check::rules::trailing_whitespace::RuleTrailingWhitespace;
+use crate::check::rules::twig_block_endblock_name::RuleTwigBlockEndblockName;
 use crate::check::rules::twig_block_line_breaks::RuleTwigBlockLineBreaks;
+use crate::check::rules::twig_block_name_prefix::RuleTwigBlockNamePrefix;
 use crate::check::rules::twig_block_name_snake_case::RuleTwigBlockNameSnakeCase;
+use crate::check::rules::twig_concat_spacing::RuleTwigConcatSpacing;
 use crate::check::rules::twig_hash_key_no_quotes::RuleTwigHashKeyNoQuotes;
 use crate::check::rules::twig_logic_and::RuleTwigLogicAnd;
 use crate::check::rules::twig_logic_or::RuleTwigLogicOr;
 use crate::check::rules::twig_string_quotation::RuleTwigStringQuotation;
 use crate::check::rules::unknown_token::RuleUnknownToken;
+use crate::check::rules::unknown_twig_filter::RuleUnknownTwigFilter;
+use crate::check::rules::unnecessary_interpolation::RuleUnnecessaryInterpolation;
+use crate::check::rules::unsafe_script_interpolation::RuleUnsafeScriptInterpolation;
+use crate::check::rules::unsafe_url_interpolation::RuleUnsafeUrlInterpolation;
+use crate::check::rules::valid_role::RuleValidRole;
+use crate::check::rules::valid_target::RuleValidTarget;
+use crate::check::rules::viewport_scalable::RuleViewportScalable;
 use crate::check::rules::whitespace_between_line_breaks::RuleWhitespaceBetweenLineBreaks;
+use crate::config::RuleSeverity;
 use crate::error::ConfigurationError;
 use crate::Config;
 use ludtwig_parser::syntax::typed::{AstNode, LudtwigDirectiveFileIgnore};
-use ludtwig_parser::syntax::untyped::SyntaxNode;
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxToken};
+use ludtwig_parser::T;
 
+mod anchor_needs_href;
+mod attribute_expression_quote_style;
+mod autocomplete_security;
+mod block_function_target_exists;
+mod block_header_clean;
+mod block_max_nesting;
+mod block_name_content_contract;
+mod block_name_denylist;
+mod block_name_manifest;
+mod boolean_literal_casing;
+mod class_attribute_typo;
+mod class_static_before_conditional;
+mod closing_bracket_placement;
+mod comment_spacing;
+mod commented_out_code;
+mod consistent_indent_width;
+mod constant_condition;
+mod dangerous_twig_constructs;
+mod data_boolean_convention;
+mod declare_extends_or_standalone;
+mod div_soup;
+mod duplicate_meta;
+mod empty_conditional;
+mod empty_element_format;
+mod empty_trans_block;
+mod endblock_spacing;
+mod enumerated_attribute_values;
+mod final_newline;
+mod form_file_enctype;
+mod framework_directive_naming;
+mod hex_color_casing;
 mod html_attribute_name_kebab_case;
 mod html_string_quotation;
+mod img_dimensions;
+mod imports_at_top;
+mod include_modifier_order;
+mod include_with_key_order;
 mod indentation;
+mod label_single_control;
+mod large_inline_svg;
 mod line_ending;
+mod loop_item_key;
+mod ludtwig_config_valid;
 mod ludtwig_ignore_file_not_on_top;
+mod max_template_size;
+mod merge_conditional_class;
+mod misnested_tags;
+mod multiline_attribute_value_indent;
+mod no_access_key;
+mod no_duplicate_attribute;
+mod no_duplicate_set;
+mod no_duplicate_token_in_list;
+mod no_inline_style;
+mod no_internal_twig_variables;
+mod no_nested_form;
+mod no_trailing_content_after_close;
+mod non_standard_attribute;
+mod null_coalescing_style;
+mod number_input_bounds;
+mod redundant_role;
+mod script_loading;
+mod select_placeholder_option;
+mod set_block_for_long_values;
+mod simplify_if_else;
+mod table_caption;
+mod table_cell_parent;
+mod trailing_slash_links;
+mod trailing_whitespace;
+mod twig_block_endblock_name;
 mod twig_block_line_breaks;
+mod twig_block_name_prefix;
 mod twig_block_name_snake_case;
+mod twig_concat_spacing;
 mod twig_hash_key_no_quotes;
 mod twig_logic_and;
 mod twig_logic_or;
 mod twig_string_quotation;
 mod unknown_token;
+mod unknown_twig_filter;
+mod unnecessary_interpolation;
+mod unsafe_script_interpolation;
+mod unsafe_url_interpolation;
+mod valid_role;
+mod valid_target;
+mod viewport_scalable;
 mod whitespace_between_line_breaks;
 
 /// List of all rule trait objects, also add them to the `active-rules` in `ludtwig-config.toml`!
 pub static RULE_DEFINITIONS: &[&'static dyn Rule] = &[
     &RuleLudtwigIgnoreFileNotOnTop,
+    &RuleLudtwigConfigValid,
     &RuleUnknownToken,
     &RuleWhitespaceBetweenLineBreaks,
     &RuleLineEnding,
@@ -46,6 +195,78 @@ pub static RULE_DEFINITIONS: &[&'static dyn Rule] = &[
     &RuleTwigStringQuotation,
     &RuleHtmlStringQuotation,
     &RuleTwigHashKeyNoQuotes,
+    &RuleImgDimensions,
+    &RuleEmptyTransBlock,
+    &RuleIncludeModifierOrder,
+    &RuleMergeConditionalClass,
+    &RuleNumberInputBounds,
+    &RuleScriptLoading,
+    &RuleBlockFunctionTargetExists,
+    &RuleNullCoalescingStyle,
+    &RuleMisnestedTags,
+    &RuleBooleanLiteralCasing,
+    &RuleEndblockSpacing,
+    &RuleValidRole,
+    &RuleBlockNameManifest,
+    &RuleNoTrailingContentAfterClose,
+    &RuleSelectPlaceholderOption,
+    &RuleFrameworkDirectiveNaming,
+    &RuleSimplifyIfElse,
+    &RuleEmptyElementFormat,
+    &RuleRedundantRole,
+    &RuleNoAccessKey,
+    &RuleClassAttributeTypo,
+    &RuleUnnecessaryInterpolation,
+    &RuleConstantCondition,
+    &RuleEnumeratedAttributeValues,
+    &RuleUnknownTwigFilter,
+    &RuleClosingBracketPlacement,
+    &RuleConsistentIndentWidth,
+    &RuleAnchorNeedsHref,
+    &RuleBlockNameDenylist,
+    &RuleTrailingSlashLinks,
+    &RuleDeclareExtendsOrStandalone,
+    &RuleCommentSpacing,
+    &RuleLargeInlineSvg,
+    &RuleNonStandardAttribute,
+    &RuleImportsAtTop,
+    &RuleNoDuplicateTokenInList,
+    &RuleLabelSingleControl,
+    &RuleTwigBlockEndblockName,
+    &RuleBlockHeaderClean,
+    &RuleNoDuplicateAttribute,
+    &RuleMaxTemplateSize,
+    &RuleNoNestedForm,
+    &RuleMultilineAttributeValueIndent,
+    &RuleNoInternalTwigVariables,
+    &RuleTableCellParent,
+    &RuleViewportScalable,
+    &RuleHexColorCasing,
+    &RuleValidTarget,
+    &RuleTrailingWhitespace,
+    &RuleLoopItemKey,
+    &RuleFinalNewline,
+    &RuleEmptyConditional,
+    &RuleTwigConcatSpacing,
+    &RuleUnsafeScriptInterpolation,
+    &RuleBlockNameContentContract,
+    &RuleDuplicateMeta,
+    &RuleClassStaticBeforeConditional,
+    &RuleDangerousTwigConstructs,
+    &RuleDataBooleanConvention,
+    &RuleTableCaption,
+    &RuleTwigBlockNamePrefix,
+    &RuleSetBlockForLongValues,
+    &RuleUnsafeUrlInterpolation,
+    &RuleIncludeWithKeyOrder,
+    &RuleDivSoup,
+    &RuleNoInlineStyle,
+    &RuleFormFileEnctype,
+    &RuleCommentedOutCode,
+    &RuleAttributeExpressionQuoteStyle,
+    &RuleBlockMaxNesting,
+    &RuleAutocompleteSecurity,
+    &RuleNoDuplicateSet,
 ];
 
 /// Get active rule definitions based on config
@@ -86,7 +307,21 @@ pub fn get_config_active_rule_definitions(
         }
     }
 
-    Ok(active_rules)
+    // rules with the "off" severity are never run
+    Ok(active_rules
+        .into_iter()
+        .filter(|r| config.severity.get(r.name()) != Some(&RuleSeverity::Off))
+        .collect())
+}
+
+/// Rule names in the severity config section which don't exist
+pub fn get_unknown_severity_rule_names(config: &Config) -> Vec<&str> {
+    config
+        .severity
+        .keys()
+        .map(String::as_str)
+        .filter(|name| !RULE_DEFINITIONS.iter().any(|r| r.name() == *name))
+        .collect()
 }
 
 /// filter down config active rule definitions for a specific file
@@ -118,6 +353,81 @@ pub fn get_file_active_rule_definitions(
         .collect()
 }
 
+/// Indentation in front of the token if it is the first one in its line (or file)
+pub(super) fn line_indentation(token: &SyntaxToken) -> Option<String> {
+    let mut indentation = String::new();
+    let mut previous = token.prev_token();
+    while let Some(t) = previous {
+        match t.kind() {
+            T![ws] => {
+                indentation.insert_str(0, t.text());
+                previous = t.prev_token();
+            }
+            T![lb] => return Some(indentation),
+            _ => return None,
+        }
+    }
+
+    Some(indentation)
+}
+
+/// Levenshtein distance between two strings
+pub(super) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Filters which are applied to the whole output (outermost first)
+/// together with the string value of their first argument
+pub(super) fn output_filters(expression: &SyntaxNode) -> Vec<(String, Option<String>)> {
+    let mut filters = vec![];
+    let mut current = expression.first_child();
+    while let Some(filter) = current.filter(|n| n.kind() == SyntaxKind::TWIG_FILTER) {
+        let mut operands = filter
+            .children()
+            .filter(|c| c.kind() == SyntaxKind::TWIG_OPERAND);
+        let filtered_value = operands.next();
+        let filter_call = operands.last();
+
+        if let Some(name) = filter_call
+            .as_ref()
+            .and_then(SyntaxNode::first_child)
+            .filter(|n| n.kind() == SyntaxKind::TWIG_LITERAL_NAME)
+            .and_then(|n| n.first_token())
+        {
+            let first_argument = filter_call
+                .as_ref()
+                .and_then(|call| {
+                    call.children()
+                        .find(|c| c.kind() == SyntaxKind::TWIG_ARGUMENTS)
+                })
+                .and_then(|arguments| {
+                    arguments
+                        .descendants()
+                        .find(|d| d.kind() == SyntaxKind::TWIG_LITERAL_STRING_INNER)
+                })
+                .map(|inner| inner.text().to_string());
+            filters.push((name.text().to_owned(), first_argument));
+        }
+
+        current = filtered_value.and_then(|operand| operand.first_child());
+    }
+
+    filters
+}
+
 #[cfg(test)]
 pub mod test {
     use std::path::PathBuf;
@@ -125,45 +435,59 @@ pub mod test {
     use std::sync::{mpsc, Arc};
 
     use codespan_reporting::term::termcolor::Buffer;
+    use expect_test::expect;
 
     use ludtwig_parser::parse;
     use ludtwig_parser::syntax::untyped::SyntaxNode;
 
+    use ludtwig_parser::syntax::untyped::{TextRange, TextSize};
+
     use crate::check::produce_diagnostics;
-    use crate::check::rule::CheckResult;
-    use crate::check::rules::RULE_DEFINITIONS;
+    use crate::check::rule::{CheckResult, CheckSuggestion, Severity};
+    use crate::check::rules::{
+        get_config_active_rule_definitions, get_unknown_severity_rule_names, RULE_DEFINITIONS,
+    };
     use crate::check::run_rules;
+    use crate::config::{get_file_config, RuleSeverity};
+    use crate::error::FileProcessingError;
+    use crate::output::OutputFormat;
     use crate::process::{iteratively_apply_suggestions, FileContext};
     use crate::{CliContext, CliSharedData, Config, ProcessingEvent};
 
     fn debug_rule(
         rule_name: &str,
         source_code: &str,
+        config: Config,
     ) -> (FileContext, Vec<CheckResult>, Receiver<ProcessingEvent>) {
-        let config = Config::new(crate::config::DEFAULT_CONFIG_PATH).unwrap();
-
         let rule = RULE_DEFINITIONS
             .iter()
             .find(|r| r.name() == rule_name)
             .unwrap();
         let (tx, rx) = mpsc::channel();
         let parse = parse(source_code);
+        let tree_root = SyntaxNode::new_root(parse.green_node);
+        let file_config = Arc::new(get_file_config(&tree_root, &config));
 
         let file_context = FileContext {
             cli_context: CliContext {
                 output_tx: tx,
                 data: Arc::new(CliSharedData {
                     fix: false,
+                    fix_dry_run: false,
                     inspect: false,
+                    output_format: OutputFormat::Human,
+                    stdin: false,
+                    deny_help: false,
                     config,
                     rule_definitions: vec![*rule],
                 }),
             },
             file_path: PathBuf::from("./debug-rule.html.twig"),
-            tree_root: SyntaxNode::new_root(parse.green_node),
+            tree_root,
             source_code: source_code.to_owned(),
             parse_errors: parse.errors,
             file_rule_definitions: vec![*rule],
+            file_config,
         };
 
         let rule_result_context = run_rules(&file_context);
@@ -171,8 +495,26 @@ pub mod test {
         (file_context, rule_result_context, rx)
     }
 
+    fn default_config() -> Config {
+        Config::new(crate::config::DEFAULT_CONFIG_PATH).unwrap()
+    }
+
     pub fn test_rule(rule_name: &str, source_code: &str, expected_report: expect_test::Expect) {
-        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code);
+        test_rule_with_config(rule_name, source_code, |_| {}, expected_report);
+    }
+
+    /// Same as [`test_rule`] but the default config can be adjusted before the rule runs.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn test_rule_with_config<C: FnOnce(&mut Config)>(
+        rule_name: &str,
+        source_code: &str,
+        adjust_config: C,
+        expected_report: expect_test::Expect,
+    ) {
+        let mut config = default_config();
+        adjust_config(&mut config);
+
+        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code, config);
         let mut buffer = Buffer::no_color();
         produce_diagnostics(&file_context, rule_result_context, &mut buffer);
         expected_report.assert_eq(&String::from_utf8_lossy(buffer.as_slice()));
@@ -184,7 +526,21 @@ pub mod test {
         source_code: &str,
         expected_source_code: expect_test::Expect,
     ) {
-        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code);
+        test_rule_fix_with_config(rule_name, source_code, |_| {}, expected_source_code);
+    }
+
+    /// Same as [`test_rule_fix`] but the default config can be adjusted before the rule runs.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn test_rule_fix_with_config<C: FnOnce(&mut Config)>(
+        rule_name: &str,
+        source_code: &str,
+        adjust_config: C,
+        expected_source_code: expect_test::Expect,
+    ) {
+        let mut config = default_config();
+        adjust_config(&mut config);
+
+        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code, config);
         let (file_context, _, dirty, iteration) =
             iteratively_apply_suggestions(file_context, rule_result_context).unwrap();
 
@@ -202,7 +558,21 @@ pub mod test {
         source_code: &str,
         expected_source_code: expect_test::Expect,
     ) {
-        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code);
+        test_rule_does_not_fix_with_config(rule_name, source_code, |_| {}, expected_source_code);
+    }
+
+    /// Same as [`test_rule_does_not_fix`] but the default config can be adjusted before the rule runs.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn test_rule_does_not_fix_with_config<C: FnOnce(&mut Config)>(
+        rule_name: &str,
+        source_code: &str,
+        adjust_config: C,
+        expected_source_code: expect_test::Expect,
+    ) {
+        let mut config = default_config();
+        adjust_config(&mut config);
+
+        let (file_context, rule_result_context, rx) = debug_rule(rule_name, source_code, config);
         let (file_context, _, dirty, iteration) =
             iteratively_apply_suggestions(file_context, rule_result_context).unwrap();
 
@@ -211,4 +581,132 @@ pub mod test {
         assert_eq!(iteration, 0, "No fixing should have no extra iterations!");
         drop(rx);
     }
+
+    #[test]
+    fn fixing_stops_at_max_fix_iterations() {
+        let mut config = default_config();
+        config.general.max_fix_iterations = 0;
+        let (file_context, rule_result_context, rx) = debug_rule(
+            "whitespace-between-line-breaks",
+            "<div>\n  \n</div>",
+            config,
+        );
+        let error = iteratively_apply_suggestions(file_context, rule_result_context)
+            .err()
+            .unwrap();
+
+        assert!(error
+            .to_string()
+            .contains("in file ./debug-rule.html.twig after 0 iterations"));
+        drop(rx);
+    }
+
+    fn apply_single_rule_suggestions(
+        source_code: &str,
+        ranges: &[(u32, u32, &str)],
+    ) -> Result<(String, bool, usize), FileProcessingError> {
+        let rule_name = "whitespace-between-line-breaks";
+        let (file_context, _, rx) = debug_rule(rule_name, source_code, default_config());
+        let result = CheckResult {
+            rule_name,
+            severity: Severity::Help,
+            message: "debug".to_owned(),
+            primary: None,
+            secondary: vec![],
+            suggestions: ranges
+                .iter()
+                .map(|(start, end, replace_with)| CheckSuggestion {
+                    syntax_range: TextRange::new(TextSize::from(*start), TextSize::from(*end)),
+                    replace_with: (*replace_with).to_owned(),
+                    message: "debug".to_owned(),
+                })
+                .collect(),
+        };
+        let fixed = iteratively_apply_suggestions(file_context, vec![result]).map(
+            |(file_context, _, dirty, iteration)| (file_context.source_code, dirty, iteration),
+        );
+        drop(rx);
+        fixed
+    }
+
+    #[test]
+    fn fixing_applies_adjacent_suggestions_of_single_rule() {
+        let (fixed, ..) =
+            apply_single_rule_suggestions("<a></a>", &[(5, 6, "b"), (1, 2, "b"), (2, 2, " c")])
+                .unwrap();
+
+        assert_eq!(fixed, "<b c></b>");
+    }
+
+    #[test]
+    fn fixing_errors_on_intersecting_suggestions_of_single_rule() {
+        let error =
+            apply_single_rule_suggestions("<a></a>", &[(0, 7, ""), (5, 6, "b")]).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("Suggestion collision inside the same rule"));
+    }
+
+    #[test]
+    fn fixing_skips_suggestions_without_changes() {
+        let (fixed, dirty, iteration) =
+            apply_single_rule_suggestions("<a></a>", &[(1, 2, "a"), (5, 6, "a")]).unwrap();
+
+        assert_eq!(fixed, "<a></a>");
+        assert!(!dirty);
+        assert_eq!(iteration, 0);
+    }
+
+    #[test]
+    fn severity_config_overrides_rule_severity() {
+        test_rule_with_config(
+            "no-duplicate-attribute",
+            r#"<div id="a" id="b"></div>"#,
+            |config| {
+                config
+                    .severity
+                    .insert("no-duplicate-attribute".to_owned(), RuleSeverity::Warning);
+            },
+            expect![[r#"
+                warning[no-duplicate-attribute]: Duplicate html attribute
+                  ┌─ ./debug-rule.html.twig:1:13
+                  │
+                1 │ <div id="a" id="b"></div>
+                  │      --     ^^ 'id' is already defined on this tag
+                  │      │       
+                  │      first defined here
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn severity_config_off_disables_rule() {
+        let mut config = default_config();
+        config
+            .severity
+            .insert("no-duplicate-attribute".to_owned(), RuleSeverity::Off);
+        config
+            .severity
+            .insert("not-a-rule".to_owned(), RuleSeverity::Error);
+
+        let active_rules = get_config_active_rule_definitions(&config).unwrap();
+        assert!(!active_rules
+            .iter()
+            .any(|r| r.name() == "no-duplicate-attribute"));
+        assert!(active_rules.iter().any(|r| r.name() == "indentation"));
+        assert_eq!(get_unknown_severity_rule_names(&config), vec!["not-a-rule"]);
+    }
+
+    #[test]
+    fn every_rule_has_a_description() {
+        for rule in RULE_DEFINITIONS {
+            assert!(
+                !rule.description().is_empty(),
+                "rule '{}' has no description",
+                rule.name()
+            );
+        }
+    }
 }
@@ -0,0 +1,114 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Attribute names which bind the href of an anchor
+static HREF_ATTRIBUTES: &[&str] = &["href", ":href", "v-bind:href", "x-bind:href"];
+
+pub struct RuleAnchorNeedsHref;
+
+impl Rule for RuleAnchorNeedsHref {
+    fn name(&self) -> &'static str {
+        "anchor-needs-href"
+    }
+
+    fn description(&self) -> &'static str {
+        "Anchors (<a>) need an href attribute to be focusable links"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if ctx.traversal_ctx().inside_foreign_element {
+            return None; // svg links are no html anchors
+        }
+
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        if !tag_name.text().eq_ignore_ascii_case("a") {
+            return None;
+        }
+
+        // also look at attributes inside twig blocks like {% if %}
+        let attribute_list = tag
+            .starting_tag()?
+            .syntax()
+            .children()
+            .find(|c| c.kind() == SyntaxKind::HTML_ATTRIBUTE_LIST);
+        let attribute_names: Vec<String> = attribute_list
+            .iter()
+            .flat_map(SyntaxNode::descendants)
+            .filter_map(HtmlAttribute::cast)
+            .filter_map(|a| a.name())
+            .map(|n| n.text().to_ascii_lowercase())
+            .collect();
+        let has_attribute = |name: &str| attribute_names.iter().any(|n| n == name);
+
+        // attributes printed by twig variables are unknown
+        let has_dynamic_attributes =
+            attribute_list
+                .iter()
+                .flat_map(SyntaxNode::descendants)
+                .any(|n| {
+                    n.kind() == SyntaxKind::TWIG_VAR
+                        && n.parent()
+                            .is_some_and(|p| p.kind() != SyntaxKind::HTML_STRING_INNER)
+                });
+
+        if has_dynamic_attributes
+            || HREF_ATTRIBUTES.iter().any(|href| has_attribute(href))
+            // named anchors are link targets
+            || has_attribute("name")
+            // focusable fallback with an explicit role
+            || (has_attribute("role") && has_attribute("tabindex"))
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Anchor without href")
+            .primary_note(
+                tag_name.text_range(),
+                "this is not keyboard focusable, add a href or use a <button> for actions",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "anchor-needs-href",
+            r#"<a class="link" onclick="open()">Open</a><a role="button">Open</a>"#,
+            expect![[r#"
+                warning[anchor-needs-href]: Anchor without href
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <a class="link" onclick="open()">Open</a><a role="button">Open</a>
+                  │  ^ this is not keyboard focusable, add a href or use a <button> for actions
+
+                warning[anchor-needs-href]: Anchor without href
+                  ┌─ ./debug-rule.html.twig:1:43
+                  │
+                1 │ <a class="link" onclick="open()">Open</a><a role="button">Open</a>
+                  │                                           ^ this is not keyboard focusable, add a href or use a <button> for actions
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "anchor-needs-href",
+            r#"<a href="/home">Home</a><a {% if url %}href="{{ url }}"{% endif %}>x</a><a :href="url">x</a><a name="top"></a><a role="button" tabindex="0">x</a><a {{ attributes }}>x</a>"#,
+            expect![""],
+        );
+    }
+}
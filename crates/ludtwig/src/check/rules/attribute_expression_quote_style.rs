@@ -0,0 +1,137 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlString, TwigLiteralString};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::twig_string_quotation::make_changed_quotes_suggestion_if_possible;
+use crate::config::Quotation;
+
+pub struct RuleAttributeExpressionQuoteStyle;
+
+impl Rule for RuleAttributeExpressionQuoteStyle {
+    fn name(&self) -> &'static str {
+        "attribute-expression-quote-style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig strings inside of html attribute values use the configured twig quotation"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_string = TwigLiteralString::cast(node)?;
+        let html_string = attribute_value_of(twig_string.syntax())?;
+
+        let twig_quotation = &ctx.config().format.twig_quotation;
+        if *twig_quotation == Quotation::Single
+            && twig_string
+                .get_inner()
+                .is_some_and(|inner| inner.get_interpolations().next().is_some())
+        {
+            return None; // interpolated strings can't change quotes
+        }
+
+        let correct_quote = twig_quotation.corresponding_char();
+        let is_fine = [
+            twig_string.get_opening_quote(),
+            twig_string.get_closing_quote(),
+        ]
+        .iter()
+        .all(|quote| {
+            quote
+                .as_ref()
+                .is_some_and(|t| t.text().starts_with(correct_quote))
+        });
+        if is_fine {
+            return None;
+        }
+
+        let mut result = self
+            .create_result(
+                Severity::Help,
+                "Inconsistent quotation of twig string inside attribute value",
+            )
+            .primary_note(
+                twig_string.syntax().text_range_trimmed_trivia(),
+                format!("help: change the quotation to {twig_quotation}"),
+            );
+        if let Some(quote) = html_string.get_opening_quote() {
+            result = result.secondary_note(
+                quote.text_range(),
+                "the attribute value is quoted independently of the twig strings inside of it",
+            );
+        }
+        let result =
+            make_changed_quotes_suggestion_if_possible(&twig_string, correct_quote, result);
+
+        Some(vec![result])
+    }
+}
+
+/// The html attribute value which contains the twig syntax of the node
+pub fn attribute_value_of(node: &SyntaxNode) -> Option<HtmlString> {
+    node.ancestors().find_map(HtmlString::cast)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_fix_with_config};
+    use crate::config::Quotation;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "attribute-expression-quote-style",
+            r#"<img src="{{ asset("x") }}">"#,
+            expect![[r#"
+                help[attribute-expression-quote-style]: Inconsistent quotation of twig string inside attribute value
+                  ┌─ ./debug-rule.html.twig:1:20
+                  │
+                1 │ <img src="{{ asset("x") }}">
+                  │          -         ^^^
+                  │          │         │ │
+                  │          │         │ Try this quote instead: '
+                  │          │         help: change the quotation to single quotes (')
+                  │          │         Try this quote instead: '
+                  │          the attribute value is quoted independently of the twig strings inside of it
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "attribute-expression-quote-style",
+            r##"<img src="{{ asset('x') }}" alt="{{ "#{name} logo" }}">{{ "outside" }}"##,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "attribute-expression-quote-style",
+            r#"<img src="{{ asset("x") }}" class="{% if a == "b" %}active{% endif %}">"#,
+            expect![[r#"<img src="{{ asset('x') }}" class="{% if a == 'b' %}active{% endif %}">"#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_configured() {
+        test_rule_fix_with_config(
+            "attribute-expression-quote-style",
+            r"<img src='{{ asset('x') }}'>",
+            |config| config.format.twig_quotation = Quotation::Double,
+            expect![[r#"<img src='{{ asset("x") }}'>"#]],
+        );
+    }
+}
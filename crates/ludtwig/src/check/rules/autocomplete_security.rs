@@ -0,0 +1,192 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleAutocompleteSecurity;
+
+impl Rule for RuleAutocompleteSecurity {
+    fn name(&self) -> &'static str {
+        "autocomplete-security"
+    }
+
+    fn description(&self) -> &'static str {
+        "Password and payment card inputs have a fitting autocomplete value"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let input = HtmlTag::cast(node)?;
+        let input_name = input.name()?;
+        if !input_name.text().eq_ignore_ascii_case("input") {
+            return None;
+        }
+
+        let rules_config = &ctx.config().rules;
+        let autocomplete = find_attribute(&input, "autocomplete");
+        let is_password = find_attribute(&input, "type")
+            .and_then(|attribute| static_value(&attribute))
+            .is_some_and(|value| value == "password");
+
+        let result = if is_password {
+            let allowed_values = &rules_config.autocomplete_password_values;
+            let expected = allowed_values
+                .iter()
+                .map(|value| format!("'{value}'"))
+                .collect::<Vec<_>>()
+                .join(" or ");
+
+            let Some(autocomplete) = autocomplete else {
+                let result = self
+                    .create_result(Severity::Warning, "Password input without autocomplete")
+                    .primary_note(
+                        input_name.text_range(),
+                        format!(
+                            "add an autocomplete attribute with {expected}, so password managers can fill or generate the password"
+                        ),
+                    );
+                return Some(vec![result]);
+            };
+
+            // the value may also contain section or shipping / billing tokens
+            let value = static_value(&autocomplete)?;
+            if value.split_ascii_whitespace().any(|token| {
+                allowed_values
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(token))
+            }) {
+                return None;
+            }
+
+            self.create_result(
+                Severity::Warning,
+                "Wrong autocomplete value on password input",
+            )
+            .primary_note(
+                autocomplete.syntax().text_range_trimmed_trivia(),
+                format!("use {expected} instead"),
+            )
+        } else {
+            let autocomplete = autocomplete?;
+            if static_value(&autocomplete)? != "on" || !is_card_field(&input, ctx) {
+                return None;
+            }
+
+            self.create_result(Severity::Warning, "Unspecific autocomplete on card field")
+                .primary_note(
+                    autocomplete.syntax().text_range_trimmed_trivia(),
+                    "use the specific value of the field like 'cc-number', 'cc-exp' or 'cc-csc' instead",
+                )
+        };
+
+        Some(vec![result])
+    }
+}
+
+fn find_attribute(tag: &HtmlTag, name: &str) -> Option<HtmlAttribute> {
+    tag.attributes().find(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|n| n.text().eq_ignore_ascii_case(name))
+    })
+}
+
+/// Trimmed and lowercase value of the attribute, None for values with twig syntax
+fn static_value(attribute: &HtmlAttribute) -> Option<String> {
+    let Some(value) = attribute.value() else {
+        return Some(String::new());
+    };
+    let inner = value.get_inner()?;
+    if inner.syntax().children().next().is_some() {
+        return None;
+    }
+
+    Some(
+        inner
+            .syntax()
+            .text()
+            .to_string()
+            .trim()
+            .to_ascii_lowercase(),
+    )
+}
+
+/// The name or id of the input contains one of the configured card field names
+fn is_card_field(input: &HtmlTag, ctx: &RuleRunContext) -> bool {
+    let card_field_names = &ctx.config().rules.autocomplete_card_field_names;
+    ["name", "id"]
+        .iter()
+        .filter_map(|name| find_attribute(input, name))
+        .filter_map(|attribute| static_value(&attribute))
+        .any(|value| {
+            card_field_names
+                .iter()
+                .any(|card_name| value.contains(&card_name.to_ascii_lowercase()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "autocomplete-security",
+            r#"<input type="password" name="password">
+<input type="password" autocomplete="off">
+<input type="text" name="cardNumber" autocomplete="on">"#,
+            expect![[r#"
+                warning[autocomplete-security]: Password input without autocomplete
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <input type="password" name="password">
+                  │  ^^^^^ add an autocomplete attribute with 'current-password' or 'new-password', so password managers can fill or generate the password
+
+                warning[autocomplete-security]: Wrong autocomplete value on password input
+                  ┌─ ./debug-rule.html.twig:2:24
+                  │
+                2 │ <input type="password" autocomplete="off">
+                  │                        ^^^^^^^^^^^^^^^^^^ use 'current-password' or 'new-password' instead
+
+                warning[autocomplete-security]: Unspecific autocomplete on card field
+                  ┌─ ./debug-rule.html.twig:3:38
+                  │
+                3 │ <input type="text" name="cardNumber" autocomplete="on">
+                  │                                      ^^^^^^^^^^^^^^^^^ use the specific value of the field like 'cc-number', 'cc-exp' or 'cc-csc' instead
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "autocomplete-security",
+            r#"<input type="password" autocomplete="current-password">
+<input type="password" autocomplete="section-signup new-password">
+<input type="password" autocomplete="{{ autocomplete }}">
+<input type="text" name="cardNumber" autocomplete="cc-number">
+<input type="text" name="search" autocomplete="on">"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_card_fields() {
+        test_rule_with_config(
+            "autocomplete-security",
+            r#"<input type="text" id="payment-pan" autocomplete="on">"#,
+            |config| config.rules.autocomplete_card_field_names = vec!["pan".to_owned()],
+            expect![[r#"
+                warning[autocomplete-security]: Unspecific autocomplete on card field
+                  ┌─ ./debug-rule.html.twig:1:37
+                  │
+                1 │ <input type="text" id="payment-pan" autocomplete="on">
+                  │                                     ^^^^^^^^^^^^^^^^^ use the specific value of the field like 'cc-number', 'cc-exp' or 'cc-csc' instead
+
+            "#]],
+        );
+    }
+}
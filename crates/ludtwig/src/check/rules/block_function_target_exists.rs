@@ -0,0 +1,120 @@
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, TwigArguments, TwigBlock, TwigFunctionCall, TwigLiteralName,
+    TwigLiteralString, TwigOperand,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockFunctionTargetExists;
+
+impl Rule for RuleBlockFunctionTargetExists {
+    fn name(&self) -> &'static str {
+        "block-function-target-exists"
+    }
+
+    fn description(&self) -> &'static str {
+        "The block() function only references blocks which are defined in the template"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let call = TwigFunctionCall::cast(node)?;
+        let function_name: TwigLiteralName =
+            support::child(support::child::<TwigOperand>(call.syntax())?.syntax())?;
+        if support::token(function_name.syntax(), T![word])?.text() != "block" {
+            return None;
+        }
+
+        // only block('name') with a single plain string is checked,
+        // block('name', 'template.html.twig') references another template
+        let arguments: TwigArguments = support::child(call.syntax())?;
+        let mut argument_iter = arguments.syntax().children();
+        let argument = argument_iter.next()?;
+        if argument_iter.next().is_some() {
+            return None;
+        }
+        let string: TwigLiteralString = support::child(&argument)?;
+        let inner = string.get_inner()?;
+        if inner.syntax().children().next().is_some() {
+            return None; // contains string interpolation
+        }
+        let block_name = inner.syntax().text().to_string();
+
+        // blocks of parent templates can't be resolved (yet), so skip these files
+        let root = call.syntax().ancestors().last()?;
+        let has_parents = root.descendants().any(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::TWIG_EXTENDS
+                    | SyntaxKind::TWIG_USE
+                    | SyntaxKind::TWIG_EMBED
+                    | SyntaxKind::SHOPWARE_TWIG_SW_EXTENDS
+            )
+        });
+        if has_parents {
+            return None;
+        }
+
+        let is_defined = root
+            .descendants()
+            .filter_map(TwigBlock::cast)
+            .any(|block| block.name().is_some_and(|n| n.text() == block_name));
+        if is_defined {
+            return None;
+        }
+
+        let result = self
+            .create_result(
+                Severity::Warning,
+                format!("Block '{block_name}' is not defined in this template"),
+            )
+            .primary_note(
+                inner.syntax().text_range(),
+                "define a block with this name or fix the name",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "block-function-target-exists",
+            "{% block title %}Hello{% endblock %}<h1>{{ block('titel') }}</h1>",
+            expect![[r"
+                warning[block-function-target-exists]: Block 'titel' is not defined in this template
+                  ┌─ ./debug-rule.html.twig:1:51
+                  │
+                1 │ {% block title %}Hello{% endblock %}<h1>{{ block('titel') }}</h1>
+                  │                                                   ^^^^^ define a block with this name or fix the name
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_defined_block() {
+        test_rule(
+            "block-function-target-exists",
+            "<h1>{{ block('title') }}</h1>{% block title %}Hello{% endblock %}{{ block('other', 'other.html.twig') }}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_with_parent_template() {
+        test_rule(
+            "block-function-target-exists",
+            "{% extends 'base.html.twig' %}{% block content %}{{ block('title') }}{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigStartingBlock};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange, TextSize};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockHeaderClean;
+
+impl Rule for RuleBlockHeaderClean {
+    fn name(&self) -> &'static str {
+        "block-header-clean"
+    }
+
+    fn description(&self) -> &'static str {
+        "Block tags don't contain anything after the block name"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let starting_block = TwigStartingBlock::cast(node)?;
+        let name = starting_block.name()?;
+
+        // everything between the name and '%}' (like the shortcut expression or syntax errors)
+        let stray: Vec<_> = starting_block
+            .syntax()
+            .children_with_tokens()
+            .skip_while(|element| element.as_token() != Some(&name))
+            .skip(1)
+            .take_while(|element| element.kind() != T!["%}"])
+            .filter(|element| !element.kind().is_trivia())
+            .collect();
+        let first = stray.first()?;
+        let last = stray.last()?;
+        let range = TextRange::new(first.text_range().start(), last.text_range().end());
+        // the node ranges include their leading trivia
+        let text = starting_block
+            .syntax()
+            .text()
+            .slice(range - starting_block.syntax().text_range().start())
+            .to_string();
+        let range = TextRange::new(range.end() - TextSize::of(text.trim_start()), range.end());
+
+        let result = self
+            .create_result(Severity::Warning, "Stray tokens in block header")
+            .primary_note(
+                range,
+                format!("block '{}' should only be followed by '%}}'", name.text()),
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "block-header-clean",
+            "{% block name foo %}{% endblock %}",
+            expect![[r"
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:1:24
+                  │
+                1 │ {% block name foo %}{% endblock %}
+                  │                        ^^^^^^^^ expected twig tag but found endblock
+
+                warning[block-header-clean]: Stray tokens in block header
+                  ┌─ ./debug-rule.html.twig:1:15
+                  │
+                1 │ {% block name foo %}{% endblock %}
+                  │               ^^^ block 'name' should only be followed by '%}'
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "block-header-clean",
+            "{% block name %}foo{% endblock %}{% block other   %}{% endblock other %}",
+            expect![""],
+        );
+    }
+}
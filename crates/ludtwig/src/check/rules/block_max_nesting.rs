@@ -0,0 +1,106 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigBlock};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockMaxNesting;
+
+impl Rule for RuleBlockMaxNesting {
+    fn name(&self) -> &'static str {
+        "block-max-nesting"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig blocks are not nested deeper than the configured limit"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let max_nesting = ctx.config().rules.max_block_nesting?;
+        let block = TwigBlock::cast(node)?;
+
+        // only twig blocks count, html elements or other twig tags in between don't
+        let parent_blocks: Vec<TwigBlock> = block
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(TwigBlock::cast)
+            .collect();
+        let depth = parent_blocks.len() + 1;
+        if depth <= max_nesting {
+            return None;
+        }
+
+        let starting_block = block.starting_block()?;
+        let mut result = self
+            .create_result(Severity::Warning, "Twig block is nested too deeply")
+            .primary_note(
+                starting_block.syntax().text_range_trimmed_trivia(),
+                format!(
+                    "this block is at nesting depth {depth}, but at most {max_nesting} is allowed"
+                ),
+            );
+        if let Some(name) = parent_blocks.last().and_then(TwigBlock::name) {
+            result = result.secondary_note(
+                name.text_range(),
+                "help: consider splitting up this block or moving parts into an included template",
+            );
+        }
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "block-max-nesting",
+            r"{% block a %}
+    {% block b %}
+        {% block c %}{% endblock %}
+    {% endblock %}
+{% endblock %}",
+            |config| config.rules.max_block_nesting = Some(2),
+            expect![[r"
+                warning[block-max-nesting]: Twig block is nested too deeply
+                  ┌─ ./debug-rule.html.twig:3:9
+                  │
+                1 │ {% block a %}
+                  │          - help: consider splitting up this block or moving parts into an included template
+                2 │     {% block b %}
+                3 │         {% block c %}{% endblock %}
+                  │         ^^^^^^^^^^^^^ this block is at nesting depth 3, but at most 2 is allowed
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule_with_config(
+            "block-max-nesting",
+            r"{% block a %}
+    <div><div><div>
+        {% block b %}{% endblock %}
+    </div></div></div>
+{% endblock %}
+{% block c %}{% endblock %}",
+            |config| config.rules.max_block_nesting = Some(2),
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_by_default() {
+        test_rule(
+            "block-max-nesting",
+            "{% block a %}{% block b %}{% block c %}{% endblock %}{% endblock %}{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag, TwigBlock};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+use regex::Regex;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockNameContentContract;
+
+impl Rule for RuleBlockNameContentContract {
+    fn name(&self) -> &'static str {
+        "block-name-content-contract"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig blocks with matching names contain the configured required elements"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let block = TwigBlock::cast(node)?;
+        let block_name = block.name()?;
+        let body = block.body()?;
+
+        let contained_elements: Vec<String> = body
+            .syntax()
+            .descendants()
+            .filter_map(HtmlTag::cast)
+            .filter_map(|tag| tag.name())
+            .map(|name| name.text().to_ascii_lowercase())
+            .collect();
+
+        // invalid patterns never match
+        let results: Vec<_> = ctx
+            .config()
+            .rules
+            .block_name_content_contracts
+            .iter()
+            .filter(|contract| {
+                Regex::new(&contract.block_name_pattern)
+                    .is_ok_and(|regex| regex.is_match(block_name.text()))
+            })
+            .flat_map(|contract| contract.required_elements.iter())
+            .filter(|required| !contained_elements.contains(&required.to_ascii_lowercase()))
+            .map(|required| {
+                self.create_result(
+                    Severity::Warning,
+                    "Twig block is missing a required element",
+                )
+                .primary_note(
+                    block_name.text_range(),
+                    format!("blocks with this name must contain a <{required}> element"),
+                )
+            })
+            .collect();
+
+        if results.is_empty() {
+            None
+        } else {
+            Some(results)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+    use crate::config::BlockContentContract;
+
+    fn inner_contract() -> Vec<BlockContentContract> {
+        vec![BlockContentContract {
+            block_name_pattern: "_inner$".to_owned(),
+            required_elements: vec!["div".to_owned(), "h2".to_owned()],
+        }]
+    }
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "block-name-content-contract",
+            r"{% block card_inner %}
+    <div><span>{{ title }}</span></div>
+{% endblock %}",
+            |config| config.rules.block_name_content_contracts = inner_contract(),
+            expect![[r"
+                warning[block-name-content-contract]: Twig block is missing a required element
+                  ┌─ ./debug-rule.html.twig:1:10
+                  │
+                1 │ {% block card_inner %}
+                  │          ^^^^^^^^^^ blocks with this name must contain a <h2> element
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule_with_config(
+            "block-name-content-contract",
+            r"{% block card_inner %}
+    <div><H2>{{ title }}</H2></div>
+{% endblock %}
+{% block card_footer %}
+    <span>{{ footer }}</span>
+{% endblock %}",
+            |config| config.rules.block_name_content_contracts = inner_contract(),
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_without_contracts() {
+        test_rule(
+            "block-name-content-contract",
+            "{% block card_inner %}{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigBlock};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockNameDenylist;
+
+impl Rule for RuleBlockNameDenylist {
+    fn name(&self) -> &'static str {
+        "block-name-denylist"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig block names don't match any pattern of the configured denylist"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let block = TwigBlock::cast(node)?;
+        let block_name = block.name()?;
+
+        if !ctx
+            .config()
+            .rules
+            .block_name_denylist
+            .iter()
+            .any(|denied| denied == block_name.text())
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Twig block name is not allowed")
+            .primary_note(
+                block_name.text_range(),
+                "this name is on the block-name-denylist, choose another one",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "block-name-denylist",
+            "{% block content %}{% block body %}{% endblock %}{% endblock %}",
+            |config| {
+                config.rules.block_name_denylist = vec!["body".to_owned(), "head".to_owned()];
+            },
+            expect![[r"
+                warning[block-name-denylist]: Twig block name is not allowed
+                  ┌─ ./debug-rule.html.twig:1:29
+                  │
+                1 │ {% block content %}{% block body %}{% endblock %}{% endblock %}
+                  │                             ^^^^ this name is on the block-name-denylist, choose another one
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_without_denylist() {
+        test_rule(
+            "block-name-denylist",
+            "{% block body %}{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigBlock};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBlockNameManifest;
+
+impl Rule for RuleBlockNameManifest {
+    fn name(&self) -> &'static str {
+        "block-name-manifest"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig block names are part of the configured block name manifest"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let block = TwigBlock::cast(node)?;
+        let block_name = block.name()?;
+
+        let rules_config = &ctx.config().rules;
+        if rules_config.block_name_manifest.is_empty()
+            || rules_config
+                .block_name_manifest
+                .iter()
+                .any(|allowed| allowed == block_name.text())
+        {
+            return None;
+        }
+
+        if rules_config.block_name_manifest_only_overrides && !is_override(&block) {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Twig block name is not in the manifest")
+            .primary_note(
+                block_name.text_range(),
+                "check this name for typos or add it to the block-name-manifest",
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// Blocks inside embeds or templates which extend another template override a parent block
+fn is_override(block: &TwigBlock) -> bool {
+    if block
+        .syntax()
+        .ancestors()
+        .any(|a| a.kind() == SyntaxKind::TWIG_EMBED)
+    {
+        return true;
+    }
+
+    block.syntax().ancestors().last().is_some_and(|root| {
+        root.descendants().any(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::TWIG_EXTENDS | SyntaxKind::SHOPWARE_TWIG_SW_EXTENDS
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "block-name-manifest",
+            "{% block page_content %}{% block page_contnet_inner %}{% endblock %}{% endblock %}",
+            |config| {
+                config.rules.block_name_manifest =
+                    vec!["page_content".to_owned(), "page_content_inner".to_owned()];
+            },
+            expect![[r"
+                warning[block-name-manifest]: Twig block name is not in the manifest
+                  ┌─ ./debug-rule.html.twig:1:34
+                  │
+                1 │ {% block page_content %}{% block page_contnet_inner %}{% endblock %}{% endblock %}
+                  │                                  ^^^^^^^^^^^^^^^^^^ check this name for typos or add it to the block-name-manifest
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_without_manifest() {
+        test_rule(
+            "block-name-manifest",
+            "{% block anything %}{% endblock %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_only_overrides() {
+        test_rule_with_config(
+            "block-name-manifest",
+            "{% block own %}{% embed 'a.html.twig' %}{% block other %}{% endblock %}{% endembed %}{% endblock %}",
+            |config| {
+                config.rules.block_name_manifest = vec!["page".to_owned()];
+                config.rules.block_name_manifest_only_overrides = true;
+            },
+            expect![[r"
+                warning[block-name-manifest]: Twig block name is not in the manifest
+                  ┌─ ./debug-rule.html.twig:1:50
+                  │
+                1 │ {% block own %}{% embed 'a.html.twig' %}{% block other %}{% endblock %}{% endembed %}{% endblock %}
+                  │                                                  ^^^^^ check this name for typos or add it to the block-name-manifest
+
+            "]],
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxToken};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleBooleanLiteralCasing;
+
+impl Rule for RuleBooleanLiteralCasing {
+    fn name(&self) -> &'static str {
+        "boolean-literal-casing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig boolean and null literals use the configured casing"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_token(&self, token: SyntaxToken, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        // the lexer matches these keywords case-insensitive everywhere (also in strings and html text),
+        // so only look at the ones which are parsed as literals inside twig expressions
+        let parent_kind = token.parent()?.kind();
+        if !matches!(
+            parent_kind,
+            SyntaxKind::TWIG_LITERAL_BOOLEAN | SyntaxKind::TWIG_LITERAL_NULL
+        ) {
+            return None;
+        }
+
+        let casing = &ctx.config().rules.boolean_literal_casing;
+        let expected = casing.apply(token.text());
+        if expected == token.text() {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Inconsistent casing of twig literal")
+            .primary_note(token.text_range(), format!("help: use {expected} instead"))
+            .suggestion(token.text_range(), expected, "Try this literal instead");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::LiteralCasing;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "boolean-literal-casing",
+            "{{ FALSE }}",
+            expect![[r"
+                help[boolean-literal-casing]: Inconsistent casing of twig literal
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ FALSE }}
+                  │    ^^^^^
+                  │    │
+                  │    help: use false instead
+                  │    Try this literal instead: false
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_strings_or_names() {
+        test_rule(
+            "boolean-literal-casing",
+            "{{ 'TRUE' ~ a.Null ~ NULLABLE }}<p>True</p>{% if true or null %}{% endif %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "boolean-literal-casing",
+            "{{ FALSE }}{% set a = True ?? NULL %}",
+            expect!["{{ false }}{% set a = true ?? null %}"],
+        );
+    }
+
+    #[test]
+    fn rule_reports_uppercase() {
+        test_rule_with_config(
+            "boolean-literal-casing",
+            "{{ TRUE }}{{ null }}",
+            |config| config.rules.boolean_literal_casing = LiteralCasing::Uppercase,
+            expect![[r"
+                help[boolean-literal-casing]: Inconsistent casing of twig literal
+                  ┌─ ./debug-rule.html.twig:1:14
+                  │
+                1 │ {{ TRUE }}{{ null }}
+                  │              ^^^^
+                  │              │
+                  │              help: use NULL instead
+                  │              Try this literal instead: NULL
+
+            "]],
+        );
+    }
+}
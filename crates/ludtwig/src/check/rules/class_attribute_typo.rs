@@ -0,0 +1,113 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlStartingTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Known wrong spellings of the class attribute (compared case-insensitive)
+static CLASS_TYPOS: &[&str] = &["classname", "class-name", "class_name"];
+
+pub struct RuleClassAttributeTypo;
+
+impl Rule for RuleClassAttributeTypo {
+    fn name(&self) -> &'static str {
+        "class-attribute-typo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Common misspellings of the class attribute (like className)"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        let lowercase_name = attribute_name.text().to_ascii_lowercase();
+        if !CLASS_TYPOS.contains(&lowercase_name.as_str()) {
+            return None;
+        }
+
+        let mut result = self
+            .create_result(Severity::Warning, "Likely wrong class attribute name")
+            .primary_note(
+                attribute_name.text_range(),
+                format!(
+                    "'{}' is not the html class attribute",
+                    attribute_name.text()
+                ),
+            );
+
+        // renaming would produce a duplicate attribute if there already is a class
+        let has_class = attribute
+            .syntax()
+            .ancestors()
+            .find_map(HtmlStartingTag::cast)
+            .is_some_and(|tag| {
+                tag.attributes().any(|a| {
+                    a.name()
+                        .is_some_and(|n| n.text().eq_ignore_ascii_case("class"))
+                })
+            });
+        if !has_class {
+            result = result.suggestion(
+                attribute_name.text_range(),
+                "class",
+                "Try this name instead",
+            );
+        }
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "class-attribute-typo",
+            r#"<div className="x"></div><div class="a" class-name="b"></div>"#,
+            expect![[r#"
+                warning[class-attribute-typo]: Likely wrong class attribute name
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div className="x"></div><div class="a" class-name="b"></div>
+                  │      ^^^^^^^^^
+                  │      │
+                  │      'className' is not the html class attribute
+                  │      Try this name instead: class
+
+                warning[class-attribute-typo]: Likely wrong class attribute name
+                  ┌─ ./debug-rule.html.twig:1:41
+                  │
+                1 │ <div className="x"></div><div class="a" class-name="b"></div>
+                  │                                         ^^^^^^^^^^ 'class-name' is not the html class attribute
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "class-attribute-typo",
+            r#"<div class="classname" data-classname="x"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "class-attribute-typo",
+            r#"<div className="x"></div>"#,
+            expect![[r#"<div class="x"></div>"#]],
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, HtmlAttribute, TwigExpression, TwigIf, TwigVar,
+};
+use ludtwig_parser::syntax::untyped::{
+    SyntaxElement, SyntaxKind, SyntaxNode, SyntaxNodeExt, TextRange,
+};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleClassStaticBeforeConditional;
+
+impl Rule for RuleClassStaticBeforeConditional {
+    fn name(&self) -> &'static str {
+        "class-static-before-conditional"
+    }
+
+    fn description(&self) -> &'static str {
+        "Static classes come before the conditional classes in class attributes"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        if !attribute
+            .name()
+            .is_some_and(|name| name.text().eq_ignore_ascii_case("class"))
+        {
+            return None;
+        }
+
+        let inner = attribute.value()?.get_inner()?;
+        let fragments = split_fragments(inner.syntax())?;
+
+        // the first conditional which is followed by a static class
+        let last_static = fragments
+            .iter()
+            .rposition(|f| matches!(f, Fragment::Static(_)))?;
+        let Some(Fragment::Conditional { range, .. }) = fragments[..last_static]
+            .iter()
+            .find(|f| matches!(f, Fragment::Conditional { .. }))
+        else {
+            return None;
+        };
+
+        let result = self
+            .create_result(
+                Severity::Help,
+                "Static classes should come before conditional classes",
+            )
+            .primary_note(
+                *range,
+                "this conditional class is in front of static classes",
+            )
+            .suggestion(
+                inner.syntax().text_range(),
+                reorder(fragments),
+                "Move the static classes to the front",
+            );
+
+        Some(vec![result])
+    }
+}
+
+enum Fragment {
+    /// plain class name
+    Static(String),
+    /// twig if or ternary expression which outputs classes
+    Conditional {
+        text: String,
+        range: TextRange,
+        /// every branch starts with whitespace, so no space is needed in front of it
+        self_separating: bool,
+    },
+}
+
+/// Split the class value into static class names and conditional fragments
+/// (None if the value contains other twig syntax or fragments are glued to class names)
+fn split_fragments(inner: &SyntaxNode) -> Option<Vec<Fragment>> {
+    let mut fragments = vec![];
+    let mut word = String::new();
+    let mut after_conditional = false;
+
+    for element in inner.children_with_tokens() {
+        match element {
+            SyntaxElement::Token(token) if token.kind().is_trivia() => {
+                if !word.is_empty() {
+                    fragments.push(Fragment::Static(std::mem::take(&mut word)));
+                }
+                after_conditional = false;
+            }
+            SyntaxElement::Token(token) => {
+                if after_conditional {
+                    return None;
+                }
+                word.push_str(token.text());
+            }
+            SyntaxElement::Node(node) => {
+                if after_conditional {
+                    return None;
+                }
+                let self_separating = is_self_separating(&node)?;
+                let separated = self_separating
+                    || node
+                        .first_token()
+                        .is_some_and(|token| token.kind().is_trivia());
+                if !word.is_empty() {
+                    if !separated {
+                        return None;
+                    }
+                    fragments.push(Fragment::Static(std::mem::take(&mut word)));
+                }
+
+                let text = node.text().to_string();
+                fragments.push(Fragment::Conditional {
+                    text: text.trim_start().to_owned(),
+                    range: node.text_range_trimmed_trivia(),
+                    self_separating,
+                });
+                after_conditional = true;
+            }
+        }
+    }
+    if !word.is_empty() {
+        fragments.push(Fragment::Static(word));
+    }
+
+    Some(fragments)
+}
+
+/// Whether the conditional starts every output with whitespace (None if it isn't a conditional)
+fn is_self_separating(node: &SyntaxNode) -> Option<bool> {
+    if let Some(twig_if) = TwigIf::cast(node.clone()) {
+        return Some(
+            twig_if
+                .syntax()
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::BODY)
+                .all(|body| {
+                    let text = body.text().to_string();
+                    text.is_empty() || text.starts_with(|c: char| c.is_ascii_whitespace())
+                }),
+        );
+    }
+
+    let expression: TwigExpression = support::child(TwigVar::cast(node.clone())?.syntax())?;
+    if expression.syntax().first_child()?.kind() == SyntaxKind::TWIG_CONDITIONAL_EXPRESSION {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn reorder(fragments: Vec<Fragment>) -> String {
+    let (statics, conditionals): (Vec<_>, Vec<_>) = fragments
+        .into_iter()
+        .partition(|f| matches!(f, Fragment::Static(_)));
+
+    let mut value = statics
+        .into_iter()
+        .filter_map(|f| match f {
+            Fragment::Static(word) => Some(word),
+            Fragment::Conditional { .. } => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    for fragment in conditionals {
+        if let Fragment::Conditional {
+            text,
+            self_separating,
+            ..
+        } = fragment
+        {
+            if !value.is_empty() && !self_separating {
+                value.push(' ');
+            }
+            value.push_str(&text);
+        }
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "class-static-before-conditional",
+            r#"<div class="{% if active %}is-active{% endif %} btn {{ large ? 'btn-lg' : 'btn-sm' }} shadow"></div>"#,
+            expect![[r#"
+                help[class-static-before-conditional]: Static classes should come before conditional classes
+                  ┌─ ./debug-rule.html.twig:1:13
+                  │
+                1 │ <div class="{% if active %}is-active{% endif %} btn {{ large ? 'btn-lg' : 'btn-sm' }} shadow"></div>
+                  │             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^---------------------------------------------
+                  │             │
+                  │             Move the static classes to the front: btn shadow {% if active %}is-active{% endif %} {{ large ? 'btn-lg' : 'btn-sm' }}
+                  │             this conditional class is in front of static classes
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "class-static-before-conditional",
+            r#"<div class="btn shadow{% if active %} is-active{% endif %} {{ large ? 'btn-lg' : 'btn-sm' }}"></div>
+<div class="{{ large ? 'btn-lg' : 'btn-sm' }}-outline btn"></div>
+<div class="{{ classes }} btn"></div>
+<div class="{% if a %}active {% endif %}btn"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "class-static-before-conditional",
+            r#"<div class="{% if active %} is-active{% endif %}
+    btn {{ large ? 'btn-lg' : 'btn-sm' }}  shadow"></div>"#,
+            expect![[
+                r#"<div class="btn shadow{% if active %} is-active{% endif %} {{ large ? 'btn-lg' : 'btn-sm' }}"></div>"#
+            ]],
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, HtmlStartingTag};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxNode, TextRange, TextSize};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::line_indentation;
+use crate::config::ClosingBracketPlacement;
+
+pub struct RuleClosingBracketPlacement;
+
+impl Rule for RuleClosingBracketPlacement {
+    fn name(&self) -> &'static str {
+        "closing-bracket-placement"
+    }
+
+    fn description(&self) -> &'static str {
+        "The closing bracket of tags with wrapped attributes is placed as configured"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if ctx.traversal_ctx().inside_trivia_sensitive_node {
+            return None; // no trivia modification allowed here
+        }
+
+        let starting_tag = HtmlStartingTag::cast(node)?;
+        let opening = support::token(starting_tag.syntax(), T!["<"])?;
+        let closing = support::token(starting_tag.syntax(), T![">"])
+            .or_else(|| support::token(starting_tag.syntax(), T!["/>"]))?;
+
+        // the whitespace in front of the closing bracket
+        let mut actual = String::new();
+        let mut previous = closing.prev_token();
+        while let Some(t) = previous.filter(|t| t.kind().is_trivia()) {
+            actual.insert_str(0, t.text());
+            previous = t.prev_token();
+        }
+        let before_range = TextRange::new(
+            closing.text_range().start() - TextSize::of(actual.as_str()),
+            closing.text_range().start(),
+        );
+
+        // only tags with attributes wrapped over multiple lines (not counting the line break in front
+        // of the closing bracket, which may be left over after other fixes joined the attributes)
+        let is_wrapped = starting_tag
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .skip_while(|t| t != &opening)
+            .take_while(|t| t.text_range().start() < before_range.start())
+            .any(|t| t.kind() == T![lb]);
+
+        let placement = if is_wrapped {
+            &ctx.config().rules.closing_bracket_placement
+        } else if actual.contains('\n') {
+            // the attributes are on a single line again, so the closing bracket belongs behind them
+            &ClosingBracketPlacement::SameLine
+        } else {
+            return None;
+        };
+        let expected = match placement {
+            ClosingBracketPlacement::SameLine if closing.kind() == T!["/>"] => " ".to_owned(),
+            ClosingBracketPlacement::SameLine => String::new(),
+            ClosingBracketPlacement::OwnLine => format!(
+                "{}{}",
+                ctx.config().format.line_ending.corresponding_string(),
+                line_indentation(&opening)?
+            ),
+        };
+        if actual == expected {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Inconsistent placement of closing bracket")
+            .primary_note(
+                closing.text_range(),
+                match placement {
+                    ClosingBracketPlacement::SameLine => "put this behind the last attribute",
+                    ClosingBracketPlacement::OwnLine => "put this on its own line",
+                },
+            )
+            .suggestion(before_range, expected, "Try this instead");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::ClosingBracketPlacement;
+
+    #[test]
+    fn rule_reports_own_line() {
+        test_rule(
+            "closing-bracket-placement",
+            "<div class=\"a\"\n     id=\"b\">\n</div>",
+            expect![[r#"
+                help[closing-bracket-placement]: Inconsistent placement of closing bracket
+                  ┌─ ./debug-rule.html.twig:2:12
+                  │
+                2 │      id="b">
+                  │            ^
+                  │            │
+                  │            put this on its own line
+                  │            Try this instead: 
+
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_own_line() {
+        test_rule(
+            "closing-bracket-placement",
+            "<div class=\"a\" id=\"b\"></div>\n<div class=\"a\"\n     id=\"b\"\n>\n    <img src=\"a\"\n         alt=\"b\"\n    />\n</div>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_own_line() {
+        test_rule_fix(
+            "closing-bracket-placement",
+            "<div>\n    <img src=\"a\"\n         alt=\"b\"/>\n    <p class=\"a\"\n       id=\"b\">\n    </p>\n</div>",
+            expect![[r#"
+                <div>
+                    <img src="a"
+                         alt="b"
+                    />
+                    <p class="a"
+                       id="b"
+                    >
+                    </p>
+                </div>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_same_line() {
+        test_rule_with_config(
+            "closing-bracket-placement",
+            "<div class=\"a\"\n     id=\"b\"\n>\n    <img src=\"a\"\n         alt=\"b\" />\n</div>",
+            |config| config.rules.closing_bracket_placement = ClosingBracketPlacement::SameLine,
+            expect![[r#"
+                help[closing-bracket-placement]: Inconsistent placement of closing bracket
+                  ┌─ ./debug-rule.html.twig:3:1
+                  │  
+                2 │        id="b"
+                  │ ╭───────────'
+                3 │ │ >
+                  │ │ ^ put this behind the last attribute
+                  │ ╰' Try this instead: 
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_same_line() {
+        test_rule_fix(
+            "closing-bracket-placement",
+            "{# ludtwig-config closing-bracket-placement=same-line #}\n<div class=\"a\"\n     id=\"b\"\n>\n    <img src=\"a\"\n         alt=\"b\"\n    />\n</div>",
+            expect![[r#"
+                {# ludtwig-config closing-bracket-placement=same-line #}
+                <div class="a"
+                     id="b">
+                    <img src="a"
+                         alt="b" />
+                </div>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_own_line_of_joined_attributes() {
+        test_rule_fix(
+            "closing-bracket-placement",
+            "<div class=\"a{% if x %} b{% endif %}\"\n>\n    <img src=\"a\"\n    />\n</div>",
+            expect![[r#"
+                <div class="a{% if x %} b{% endif %}">
+                    <img src="a" />
+                </div>"#]],
+        );
+    }
+}
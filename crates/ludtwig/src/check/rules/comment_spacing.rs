@@ -0,0 +1,121 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigComment};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleCommentSpacing;
+
+impl Rule for RuleCommentSpacing {
+    fn name(&self) -> &'static str {
+        "comment-spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig comments have exactly one space inside of the delimiters"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let comment = TwigComment::cast(node)?;
+        let open = support::token(comment.syntax(), T!["{#"])?;
+        let close = support::token(comment.syntax(), T!["#}"])?;
+        let range = TextRange::new(open.text_range().start(), close.text_range().end());
+
+        // the lexer doesn't know about comment content, so work on the plain text
+        let text: String = comment
+            .syntax()
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|t| range.contains_range(t.text_range()))
+            .map(|t| t.text().to_owned())
+            .collect();
+        let inner = text.strip_prefix("{#")?.strip_suffix("#}")?;
+
+        // whitespace control modifiers belong to the delimiters
+        let (open_modifier, inner) = split_modifier(inner, str::strip_prefix);
+        let (close_modifier, inner) = split_modifier(inner, str::strip_suffix);
+
+        // multi line comments and empty ones keep their format
+        let content = inner.trim();
+        if content.is_empty() || inner.contains('\n') {
+            return None;
+        }
+
+        let expected = format!("{{#{open_modifier} {content} {close_modifier}#}}");
+        if expected == text {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Inconsistent spacing inside twig comment")
+            .primary_note(range, "use exactly one space inside the comment delimiters")
+            .suggestion(range, expected, "Try this instead");
+
+        Some(vec![result])
+    }
+}
+
+fn split_modifier<'a>(
+    text: &'a str,
+    strip: fn(&'a str, &'static str) -> Option<&'a str>,
+) -> (&'static str, &'a str) {
+    for modifier in ["-", "~"] {
+        if let Some(rest) = strip(text, modifier) {
+            return (modifier, rest);
+        }
+    }
+
+    ("", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "comment-spacing",
+            "{#x#}",
+            expect![[r"
+            help[comment-spacing]: Inconsistent spacing inside twig comment
+              ┌─ ./debug-rule.html.twig:1:1
+              │
+            1 │ {#x#}
+              │ ^^^^^
+              │ │
+              │ use exactly one space inside the comment delimiters
+              │ Try this instead: {# x #}
+
+        "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "comment-spacing",
+            "{# x #}{#- trimmed -#}{#~ y ~#}{#\n    multi\n    line\n#}{##}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "comment-spacing",
+            "{#x#}<div>{#   some  comment#}</div>{#-trimmed   -#}",
+            expect!["{# x #}<div>{# some  comment #}</div>{#- trimmed -#}"],
+        );
+    }
+}
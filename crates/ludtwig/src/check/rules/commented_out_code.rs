@@ -0,0 +1,124 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigComment};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange};
+use ludtwig_parser::{parse_with_options, ParseOptions, T};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleCommentedOutCode;
+
+impl Rule for RuleCommentedOutCode {
+    fn name(&self) -> &'static str {
+        "commented-out-code"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig comments which mostly contain html or twig code that was commented out"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let comment = TwigComment::cast(node)?;
+        let open = support::token(comment.syntax(), T!["{#"])?;
+        let close = support::token(comment.syntax(), T!["#}"])?;
+        let range = TextRange::new(open.text_range().start(), close.text_range().end());
+
+        // the content of comments is not parsed, so parse it on its own here
+        let inner: String = comment
+            .syntax()
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|t| t.text_range().start() >= open.text_range().end())
+            .filter(|t| t.text_range().end() <= close.text_range().start())
+            .map(|t| t.text().to_owned())
+            .collect();
+        let inner = inner
+            .trim_start_matches(['-', '~'])
+            .trim_end_matches(['-', '~']);
+
+        let options = ParseOptions {
+            additional_void_elements: ctx.config().general.additional_void_elements.clone(),
+        };
+        let parse = parse_with_options(inner, &options);
+        let element_count = SyntaxNode::new_root(parse.green_node)
+            .descendants()
+            .filter(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::HTML_TAG
+                        | SyntaxKind::TWIG_BLOCK
+                        | SyntaxKind::TWIG_IF
+                        | SyntaxKind::TWIG_FOR
+                        | SyntaxKind::TWIG_VAR
+                )
+            })
+            .count();
+
+        let min_elements = ctx.config().rules.commented_out_code_min_elements;
+        if element_count < min_elements.max(1) {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Info, "Comment looks like commented out code")
+            .primary_note(
+                range,
+                format!("this comment contains {element_count} html or twig elements"),
+            )
+            .secondary_note(
+                range,
+                "help: remove the code or uncomment it, version control keeps the history",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "commented-out-code",
+            r#"{# <div class="teaser"><a href="{{ url }}">{{ label }}</a></div> #}"#,
+            expect![[r#"
+                note[commented-out-code]: Comment looks like commented out code
+                  ┌─ ./debug-rule.html.twig:1:1
+                  │
+                1 │ {# <div class="teaser"><a href="{{ url }}">{{ label }}</a></div> #}
+                  │ ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                  │ │
+                  │ this comment contains 4 html or twig elements
+                  │ help: remove the code or uncomment it, version control keeps the history
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "commented-out-code",
+            r"{# This block renders the teaser, use <br> for line breaks in the label #}
+{# {{ product.name }} is already escaped #}
+{# ludtwig-ignore-file #}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_configured() {
+        test_rule_with_config(
+            "commented-out-code",
+            r"{# <span>{{ a }}</span><span>{{ b }}</span> #}",
+            |config| config.rules.commented_out_code_min_elements = 5,
+            expect![""],
+        );
+    }
+}
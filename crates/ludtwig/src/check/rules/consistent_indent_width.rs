@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleConsistentIndentWidth;
+
+impl Rule for RuleConsistentIndentWidth {
+    fn name(&self) -> &'static str {
+        "consistent-indent-width"
+    }
+
+    fn description(&self) -> &'static str {
+        "Indentation steps have the same width as in the rest of the file"
+    }
+
+    fn check_root(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        // indentation increases between consecutive lines which start an element
+        let mut steps: Vec<(SyntaxToken, usize)> = vec![];
+        let mut previous_width = 0;
+        let mut line_start = true;
+        let mut indentation: Option<SyntaxToken> = None;
+        for token in node
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+        {
+            match token.kind() {
+                T![lb] => {
+                    line_start = true;
+                    indentation = None;
+                }
+                T![ws] if line_start => indentation = Some(token),
+                _ if line_start => {
+                    line_start = false;
+                    if !starts_element(&token) {
+                        continue;
+                    }
+
+                    let width = indentation.as_ref().map_or(0, |ws| ws.text().len());
+                    if indentation
+                        .as_ref()
+                        .is_some_and(|ws| ws.text().contains('\t'))
+                    {
+                        continue; // only spaces are comparable
+                    }
+                    if width > previous_width {
+                        if let Some(ws) = indentation.take() {
+                            steps.push((ws, width - previous_width));
+                        }
+                    }
+                    previous_width = width;
+                }
+                _ => {}
+            }
+        }
+
+        // the most common step wins (the smaller one on a tie)
+        let mut step_counts: HashMap<usize, usize> = HashMap::new();
+        for (_, step) in &steps {
+            *step_counts.entry(*step).or_default() += 1;
+        }
+        let (dominant_step, _) =
+            step_counts
+                .iter()
+                .max_by(|(step_a, count_a), (step_b, count_b)| {
+                    count_a.cmp(count_b).then(step_b.cmp(step_a))
+                })?;
+
+        let results = steps
+            .iter()
+            .filter(|(_, step)| step != dominant_step)
+            .map(|(ws, step)| {
+                self.create_result(Severity::Warning, "Inconsistent indentation width")
+                    .primary_note(
+                        ws.text_range(),
+                        format!(
+                            "indented by {step} more spaces here, but this file mostly uses steps of {dominant_step}"
+                        ),
+                    )
+            })
+            .collect();
+
+        Some(results)
+    }
+}
+
+/// The token is the start of an element inside a body (and not of an attribute, ending tag or similar)
+fn starts_element(token: &SyntaxToken) -> bool {
+    let mut node = token.parent();
+    while let Some(n) = node {
+        let starts_with_token = n
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| !t.kind().is_trivia())
+            .is_some_and(|t| &t == token);
+        if !starts_with_token {
+            return false;
+        }
+
+        match n.parent() {
+            Some(p) if p.kind() == SyntaxKind::ERROR => return false,
+            Some(p) if matches!(p.kind(), SyntaxKind::BODY | SyntaxKind::ROOT) => {
+                // content of trivia sensitive elements is not indented by structure
+                return !p.ancestors().filter_map(HtmlTag::cast).any(|tag| {
+                    tag.name()
+                        .is_some_and(|name| matches!(name.text(), "pre" | "textarea"))
+                });
+            }
+            parent => node = parent,
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "consistent-indent-width",
+            "<div>\n  <p>\n    text\n  </p>\n  <ul>\n      <li>a</li>\n  </ul>\n</div>",
+            expect![[r"
+                warning[consistent-indent-width]: Inconsistent indentation width
+                  ┌─ ./debug-rule.html.twig:6:1
+                  │
+                6 │       <li>a</li>
+                  │ ^^^^^^ indented by 4 more spaces here, but this file mostly uses steps of 2
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_ignored_node() {
+        test_rule(
+            "consistent-indent-width",
+            "<div>\n  <p>\n    text\n  </p>\n  <ul>{# ludtwig-ignore consistent-indent-width #}\n      <li>a</li>\n  </ul>\n</div>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "consistent-indent-width",
+            "{% block a %}\n    <div class=\"a\"\n         id=\"b\">\n        <p>\n            text\n        </p>\n    </div>\n    <pre>\n  code\n      more\n    </pre>\n{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigExpression};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleConstantCondition;
+
+impl Rule for RuleConstantCondition {
+    fn name(&self) -> &'static str {
+        "constant-condition"
+    }
+
+    fn description(&self) -> &'static str {
+        "Conditions which only consist of literals and are always the same"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if !matches!(
+            node.kind(),
+            SyntaxKind::TWIG_IF_BLOCK | SyntaxKind::TWIG_ELSE_IF_BLOCK
+        ) {
+            return None;
+        }
+
+        let condition: TwigExpression = support::child(&node)?;
+        if !is_literal_only(condition.syntax()) {
+            return None;
+        }
+
+        let start = condition
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| !t.kind().is_trivia())?
+            .text_range()
+            .start();
+        let result = self
+            .create_result(Severity::Warning, "Condition is always the same")
+            .primary_note(
+                TextRange::new(start, condition.syntax().text_range().end()),
+                "this condition only consists of literals",
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// Conservative check that the expression only contains literals and operators on them
+fn is_literal_only(expression: &SyntaxNode) -> bool {
+    expression.descendants().all(|n| {
+        matches!(
+            n.kind(),
+            SyntaxKind::TWIG_EXPRESSION
+                | SyntaxKind::TWIG_BINARY_EXPRESSION
+                | SyntaxKind::TWIG_UNARY_EXPRESSION
+                | SyntaxKind::TWIG_PARENTHESES_EXPRESSION
+                | SyntaxKind::TWIG_LITERAL_BOOLEAN
+                | SyntaxKind::TWIG_LITERAL_NULL
+                | SyntaxKind::TWIG_LITERAL_NUMBER
+                | SyntaxKind::TWIG_LITERAL_STRING
+                | SyntaxKind::TWIG_LITERAL_STRING_INNER
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "constant-condition",
+            "{% if true %}a{% elseif 1 == 1 %}b{% endif %}{% if not (false or null) %}c{% endif %}",
+            expect![[r"
+                warning[constant-condition]: Condition is always the same
+                  ┌─ ./debug-rule.html.twig:1:7
+                  │
+                1 │ {% if true %}a{% elseif 1 == 1 %}b{% endif %}{% if not (false or null) %}c{% endif %}
+                  │       ^^^^ this condition only consists of literals
+
+                warning[constant-condition]: Condition is always the same
+                  ┌─ ./debug-rule.html.twig:1:25
+                  │
+                1 │ {% if true %}a{% elseif 1 == 1 %}b{% endif %}{% if not (false or null) %}c{% endif %}
+                  │                         ^^^^^^ this condition only consists of literals
+
+                warning[constant-condition]: Condition is always the same
+                  ┌─ ./debug-rule.html.twig:1:52
+                  │
+                1 │ {% if true %}a{% elseif 1 == 1 %}b{% endif %}{% if not (false or null) %}c{% endif %}
+                  │                                                    ^^^^^^^^^^^^^^^^^^^ this condition only consists of literals
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "constant-condition",
+            "{% if x %}a{% elseif x == 1 %}b{% endif %}{% if 'a' ~ b %}{% endif %}{% if \"#{a}\" %}{% endif %}{% if 1 is odd %}{% endif %}{% if max(1, 2) > 1 %}{% endif %}",
+            expect![""],
+        );
+    }
+}
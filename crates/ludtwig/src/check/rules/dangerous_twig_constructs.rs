@@ -0,0 +1,162 @@
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, TwigArguments, TwigExpression, TwigFunctionCall, TwigLiteralName, TwigOperand,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxNodeExt};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleDangerousTwigConstructs;
+
+impl Rule for RuleDangerousTwigConstructs {
+    fn name(&self) -> &'static str {
+        "dangerous-twig-constructs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig functions on the configured dangerous list and dynamic template includes"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let rules_config = &ctx.config().rules;
+
+        // {% include ... %} and {% sw_include ... %} tags
+        if matches!(
+            node.kind(),
+            SyntaxKind::TWIG_INCLUDE | SyntaxKind::SHOPWARE_TWIG_SW_INCLUDE
+        ) {
+            let template: TwigExpression = support::child(&node)?;
+            if !rules_config.dangerous_twig_dynamic_includes
+                || !is_built_dynamically(template.syntax())
+            {
+                return None;
+            }
+
+            return Some(vec![self.dynamic_include_result(template.syntax())]);
+        }
+
+        let call = TwigFunctionCall::cast(node)?;
+        let function_name: TwigLiteralName =
+            support::child(support::child::<TwigOperand>(call.syntax())?.syntax())?;
+        let function_name = support::token(function_name.syntax(), T![word])?;
+
+        if rules_config
+            .dangerous_twig_functions
+            .iter()
+            .any(|dangerous| dangerous == function_name.text())
+        {
+            let result = self
+                .create_result(Severity::Warning, "Dangerous twig construct")
+                .primary_note(
+                    function_name.text_range(),
+                    format!(
+                        "'{}' is on the dangerous-twig-functions list, it may allow template injection",
+                        function_name.text()
+                    ),
+                );
+            return Some(vec![result]);
+        }
+
+        if function_name.text() != "include" || !rules_config.dangerous_twig_dynamic_includes {
+            return None;
+        }
+        let arguments: TwigArguments = support::child(call.syntax())?;
+        let template = arguments.syntax().first_child()?;
+        if template.kind() != SyntaxKind::TWIG_EXPRESSION || !is_built_dynamically(&template) {
+            return None;
+        }
+
+        Some(vec![self.dynamic_include_result(&template)])
+    }
+}
+
+impl RuleDangerousTwigConstructs {
+    fn dynamic_include_result(&self, template: &SyntaxNode) -> CheckResult {
+        self.create_result(Severity::Warning, "Dangerous twig construct")
+            .primary_note(
+                template.text_range_trimmed_trivia(),
+                "the included template name is built dynamically, which may allow including arbitrary templates",
+            )
+    }
+}
+
+/// Template names built with `~` concatenation or string interpolation
+fn is_built_dynamically(expression: &SyntaxNode) -> bool {
+    match expression.first_child() {
+        Some(binary) if binary.kind() == SyntaxKind::TWIG_BINARY_EXPRESSION => binary
+            .children_with_tokens()
+            .any(|child| child.kind() == T!["~"]),
+        Some(string) if string.kind() == SyntaxKind::TWIG_LITERAL_STRING => string
+            .descendants()
+            .any(|d| d.kind() == SyntaxKind::TWIG_LITERAL_STRING_INTERPOLATION),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "dangerous-twig-constructs",
+            r#"{{ include(template_from_string(userInput)) }}
+{% include 'partials/' ~ name ~ '.html.twig' %}
+{{ include("partials/#{name}.html.twig") }}"#,
+            expect![[r#"
+                warning[dangerous-twig-constructs]: Dangerous twig construct
+                  ┌─ ./debug-rule.html.twig:1:12
+                  │
+                1 │ {{ include(template_from_string(userInput)) }}
+                  │            ^^^^^^^^^^^^^^^^^^^^ 'template_from_string' is on the dangerous-twig-functions list, it may allow template injection
+
+                warning[dangerous-twig-constructs]: Dangerous twig construct
+                  ┌─ ./debug-rule.html.twig:2:11
+                  │
+                2 │ {% include 'partials/' ~ name ~ '.html.twig' %}
+                  │           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the included template name is built dynamically, which may allow including arbitrary templates
+
+                warning[dangerous-twig-constructs]: Dangerous twig construct
+                  ┌─ ./debug-rule.html.twig:3:12
+                  │
+                3 │ {{ include("partials/#{name}.html.twig") }}
+                  │            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the included template name is built dynamically, which may allow including arbitrary templates
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "dangerous-twig-constructs",
+            r"{{ include('partials/card.html.twig') }}
+{% include template with { a: 'b' ~ c } %}
+{{ 'a' ~ b }}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_functions() {
+        test_rule_with_config(
+            "dangerous-twig-constructs",
+            r"{{ source('a' ~ b) }}{% include 'a' ~ b %}",
+            |config| {
+                config.rules.dangerous_twig_functions = vec!["source".to_owned()];
+                config.rules.dangerous_twig_dynamic_includes = false;
+            },
+            expect![[r"
+                warning[dangerous-twig-constructs]: Dangerous twig construct
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ source('a' ~ b) }}{% include 'a' ~ b %}
+                  │    ^^^^^^ 'source' is on the dangerous-twig-functions list, it may allow template injection
+
+            "]],
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt, TextRange};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::config::DataBooleanFormat;
+
+pub struct RuleDataBooleanConvention;
+
+impl Rule for RuleDataBooleanConvention {
+    fn name(&self) -> &'static str {
+        "data-boolean-convention"
+    }
+
+    fn description(&self) -> &'static str {
+        "Boolean data attributes use the configured value format"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let name = attribute.name()?;
+        let config = ctx.config();
+        if !config
+            .rules
+            .data_boolean_attributes
+            .iter()
+            .any(|boolean_attribute| name.text().eq_ignore_ascii_case(boolean_attribute))
+        {
+            return None;
+        }
+
+        // only plain values which represent a boolean are checked
+        let default_quote = config
+            .format
+            .html_quotation
+            .corresponding_char()
+            .to_string();
+        let (value, quote) = match attribute.value() {
+            None => (true, default_quote),
+            Some(string) => {
+                let text = match string.get_inner() {
+                    Some(inner) if inner.syntax().first_child().is_some() => return None,
+                    Some(inner) => inner.syntax().text().to_string(),
+                    None => String::new(),
+                };
+                let value = match text.trim() {
+                    "true" | "1" | "" => true,
+                    "false" | "0" => false,
+                    _ => return None,
+                };
+                let quote = string
+                    .get_opening_quote()
+                    .map_or(default_quote, |q| q.text().to_owned());
+                (value, quote)
+            }
+        };
+
+        let expected = match (&config.rules.data_boolean_format, value) {
+            (DataBooleanFormat::TrueFalse, true) => format!("={quote}true{quote}"),
+            (DataBooleanFormat::TrueFalse, false) => format!("={quote}false{quote}"),
+            (DataBooleanFormat::OneZero, true) => format!("={quote}1{quote}"),
+            (DataBooleanFormat::OneZero, false) => format!("={quote}0{quote}"),
+            (DataBooleanFormat::Presence, true) => String::new(),
+            (DataBooleanFormat::Presence, false) => {
+                // false is expressed by leaving out the attribute
+                let result = self
+                    .create_result(Severity::Help, "Inconsistent boolean data attribute value")
+                    .primary_note(
+                        attribute.syntax().text_range_trimmed_trivia(),
+                        "false is expressed by leaving out this attribute",
+                    )
+                    .suggestion(attribute.syntax().text_range(), "", "Remove the attribute");
+                return Some(vec![result]);
+            }
+        };
+
+        // everything behind the name (equal sign and value)
+        let value_range = TextRange::new(
+            name.text_range().end(),
+            attribute.syntax().text_range().end(),
+        );
+        let current = attribute
+            .syntax()
+            .text()
+            .slice(value_range - attribute.syntax().text_range().start())
+            .to_string();
+        if current == expected {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Inconsistent boolean data attribute value")
+            .primary_note(
+                attribute.syntax().text_range_trimmed_trivia(),
+                format!("write this as {}{expected}", name.text()),
+            )
+            .suggestion(value_range, expected, "Use the configured boolean format");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix_with_config, test_rule_with_config};
+    use crate::config::DataBooleanFormat;
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "data-boolean-convention",
+            r#"<div data-open="1" data-active data-hidden='0' data-other="1"></div>"#,
+            |config| {
+                config.rules.data_boolean_attributes = vec![
+                    "data-open".to_owned(),
+                    "data-active".to_owned(),
+                    "data-hidden".to_owned(),
+                ];
+            },
+            expect![[r#"
+                help[data-boolean-convention]: Inconsistent boolean data attribute value
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div data-open="1" data-active data-hidden='0' data-other="1"></div>
+                  │      ^^^^^^^^^^^^^
+                  │      │        │
+                  │      │        Use the configured boolean format: ="true"
+                  │      write this as data-open="true"
+
+                help[data-boolean-convention]: Inconsistent boolean data attribute value
+                  ┌─ ./debug-rule.html.twig:1:20
+                  │
+                1 │ <div data-open="1" data-active data-hidden='0' data-other="1"></div>
+                  │                    ^^^^^^^^^^^- Use the configured boolean format: ="true"
+                  │                    │           
+                  │                    write this as data-active="true"
+
+                help[data-boolean-convention]: Inconsistent boolean data attribute value
+                  ┌─ ./debug-rule.html.twig:1:32
+                  │
+                1 │ <div data-open="1" data-active data-hidden='0' data-other="1"></div>
+                  │                                ^^^^^^^^^^^^^^^
+                  │                                │          │
+                  │                                │          Use the configured boolean format: ='false'
+                  │                                write this as data-hidden='false'
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_presence() {
+        test_rule_with_config(
+            "data-boolean-convention",
+            r#"<div data-open="true" data-hidden="false" data-active></div>"#,
+            |config| {
+                config.rules.data_boolean_attributes = vec![
+                    "data-open".to_owned(),
+                    "data-active".to_owned(),
+                    "data-hidden".to_owned(),
+                ];
+                config.rules.data_boolean_format = DataBooleanFormat::Presence;
+            },
+            expect![[r#"
+                help[data-boolean-convention]: Inconsistent boolean data attribute value
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div data-open="true" data-hidden="false" data-active></div>
+                  │      ^^^^^^^^^^^^^^^^
+                  │      │        │
+                  │      │        Use the configured boolean format: 
+                  │      write this as data-open
+
+                help[data-boolean-convention]: Inconsistent boolean data attribute value
+                  ┌─ ./debug-rule.html.twig:1:23
+                  │
+                1 │ <div data-open="true" data-hidden="false" data-active></div>
+                  │                      -^^^^^^^^^^^^^^^^^^^
+                  │                      ││
+                  │                      │false is expressed by leaving out this attribute
+                  │                      Remove the attribute: 
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule_with_config(
+            "data-boolean-convention",
+            r#"<div data-open="true" data-active="false" data-hidden="{{ hidden ? 1 : 0 }}" data-mode="auto"></div>"#,
+            |config| {
+                config.rules.data_boolean_attributes = vec![
+                    "data-open".to_owned(),
+                    "data-active".to_owned(),
+                    "data-hidden".to_owned(),
+                    "data-mode".to_owned(),
+                ];
+            },
+            expect![""],
+        );
+        test_rule(
+            "data-boolean-convention",
+            r#"<div data-open="1"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix_with_config(
+            "data-boolean-convention",
+            r#"<div data-open="true" data-active data-hidden='false'></div>"#,
+            |config| {
+                config.rules.data_boolean_attributes = vec![
+                    "data-open".to_owned(),
+                    "data-active".to_owned(),
+                    "data-hidden".to_owned(),
+                ];
+                config.rules.data_boolean_format = DataBooleanFormat::OneZero;
+            },
+            expect![[r#"<div data-open="1" data-active="1" data-hidden='0'></div>"#]],
+        );
+    }
+}
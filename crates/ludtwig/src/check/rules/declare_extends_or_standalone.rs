@@ -0,0 +1,105 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigComment};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleDeclareExtendsOrStandalone;
+
+impl Rule for RuleDeclareExtendsOrStandalone {
+    fn name(&self) -> &'static str {
+        "declare-extends-or-standalone"
+    }
+
+    fn description(&self) -> &'static str {
+        "Templates either extend another template or are marked as standalone"
+    }
+
+    fn check_root(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if node.descendants().any(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::TWIG_EXTENDS | SyntaxKind::SHOPWARE_TWIG_SW_EXTENDS
+            )
+        }) {
+            return None;
+        }
+
+        let marker = &ctx.config().rules.declare_extends_or_standalone_marker;
+        if node
+            .children()
+            .filter_map(TwigComment::cast)
+            .any(|comment| &comment_content(&comment) == marker)
+        {
+            return None;
+        }
+
+        // empty files have nothing to declare
+        let first_token = node
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| !t.kind().is_trivia())?;
+
+        let result = self
+            .create_result(
+                Severity::Warning,
+                "Template neither extends another one nor is marked as standalone",
+            )
+            .primary_note(
+                first_token.text_range(),
+                format!("add an extends tag or a '{{# {marker} #}}' comment to this file"),
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// Text between the comment delimiters without surrounding whitespace
+fn comment_content(comment: &TwigComment) -> String {
+    let text = comment.syntax().text().to_string();
+    let text = text.trim();
+    let text = text.strip_prefix("{#").unwrap_or(text);
+    let text = text.strip_suffix("#}").unwrap_or(text);
+    text.trim().to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "declare-extends-or-standalone",
+            "{# some comment #}\n{% block content %}{% endblock %}",
+            expect![[r"
+                warning[declare-extends-or-standalone]: Template neither extends another one nor is marked as standalone
+                  ┌─ ./debug-rule.html.twig:1:1
+                  │
+                1 │ {# some comment #}
+                  │ ^^ add an extends tag or a '{# standalone #}' comment to this file
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "declare-extends-or-standalone",
+            "{% sw_extends '@Storefront/base.html.twig' %}\n{% block content %}{% endblock %}",
+            expect![""],
+        );
+        test_rule(
+            "declare-extends-or-standalone",
+            "{% extends 'base.html.twig' %}",
+            expect![""],
+        );
+        test_rule(
+            "declare-extends-or-standalone",
+            "{# standalone #}\n<div></div>",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleDivSoup;
+
+impl Rule for RuleDivSoup {
+    fn name(&self) -> &'static str {
+        "div-soup"
+    }
+
+    fn description(&self) -> &'static str {
+        "Long runs of div and span siblings without semantic meaning"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if !matches!(node.kind(), SyntaxKind::BODY | SyntaxKind::ROOT) {
+            return None;
+        }
+
+        let max_siblings = ctx.config().rules.div_soup_max_siblings;
+        let mut results = vec![];
+        let mut run: Vec<SyntaxToken> = vec![];
+        for child in node.children() {
+            match HtmlTag::cast(child.clone()) {
+                Some(tag) if is_non_semantic(&tag) => {
+                    if let Some(name) = tag.name() {
+                        run.push(name);
+                    }
+                    continue;
+                }
+                // comments don't interrupt the sequence
+                None if matches!(
+                    child.kind(),
+                    SyntaxKind::HTML_COMMENT | SyntaxKind::TWIG_COMMENT
+                ) =>
+                {
+                    continue;
+                }
+                _ => {}
+            }
+
+            results.extend(self.check_run(&run, max_siblings));
+            run.clear();
+        }
+        results.extend(self.check_run(&run, max_siblings));
+
+        Some(results)
+    }
+}
+
+impl RuleDivSoup {
+    fn check_run(&self, run: &[SyntaxToken], max_siblings: usize) -> Option<CheckResult> {
+        if run.len() <= max_siblings {
+            return None;
+        }
+
+        let result = self
+            .create_result(
+                Severity::Warning,
+                "Too many consecutive div or span elements",
+            )
+            .primary_note(
+                run.first()?.text_range(),
+                format!(
+                    "this is the first of {} div / span siblings (more than {max_siblings})",
+                    run.len()
+                ),
+            )
+            .secondary_note(
+                run.last()?.text_range(),
+                "help: consider semantic elements like section, nav, ul or article",
+            );
+
+        Some(result)
+    }
+}
+
+/// A div or span which doesn't get a meaning through a role attribute
+fn is_non_semantic(tag: &HtmlTag) -> bool {
+    tag.name().is_some_and(|name| {
+        name.text().eq_ignore_ascii_case("div") || name.text().eq_ignore_ascii_case("span")
+    }) && !tag.attributes().any(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|name| name.text().eq_ignore_ascii_case("role"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule_with_config;
+
+    #[test]
+    fn rule_reports() {
+        test_rule_with_config(
+            "div-soup",
+            r"<div>
+    <div>a</div>
+    <span>b</span>
+    <!-- comment -->
+    <div>c</div>
+</div>",
+            |config| config.rules.div_soup_max_siblings = 2,
+            expect![[r"
+                warning[div-soup]: Too many consecutive div or span elements
+                  ┌─ ./debug-rule.html.twig:2:6
+                  │
+                2 │     <div>a</div>
+                  │      ^^^ this is the first of 3 div / span siblings (more than 2)
+                  ·
+                5 │     <div>c</div>
+                  │      --- help: consider semantic elements like section, nav, ul or article
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule_with_config(
+            "div-soup",
+            r#"<div>
+    <div>a</div>
+    <span>b</span>
+</div>
+<div>
+    <div>a</div>
+    <p>b</p>
+    <div>c</div>
+    <div role="group">d</div>
+    <div>e</div>
+</div>"#,
+            |config| config.rules.div_soup_max_siblings = 2,
+            expect![""],
+        );
+    }
+}
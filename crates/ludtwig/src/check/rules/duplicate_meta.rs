@@ -0,0 +1,128 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleDuplicateMeta;
+
+impl Rule for RuleDuplicateMeta {
+    fn name(&self) -> &'static str {
+        "duplicate-meta"
+    }
+
+    fn description(&self) -> &'static str {
+        "Meta tags (like charset or viewport) are only defined once"
+    }
+
+    fn check_root(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        // first attribute for every key ('name' or 'property' together with its value)
+        let mut seen: Vec<((String, String), HtmlAttribute)> = vec![];
+        let mut results = vec![];
+        for meta in node.descendants().filter_map(HtmlTag::cast).filter(|tag| {
+            tag.name()
+                .is_some_and(|name| name.text().eq_ignore_ascii_case("meta"))
+        }) {
+            // metas inside of conditionals may exclude each other
+            if meta
+                .syntax()
+                .ancestors()
+                .any(|ancestor| ancestor.kind() == SyntaxKind::TWIG_IF)
+            {
+                continue;
+            }
+
+            let Some((key, attribute)) = meta_key(&meta) else {
+                continue;
+            };
+            match seen.iter().find(|(other, _)| *other == key) {
+                Some((_, first)) => results.push(
+                    self.create_result(Severity::Warning, "Duplicate meta tag")
+                        .primary_note(
+                            attribute.syntax().text_range_trimmed_trivia(),
+                            format!("a meta tag with {}=\"{}\" already exists", key.0, key.1),
+                        )
+                        .secondary_note(
+                            first.syntax().text_range_trimmed_trivia(),
+                            "first defined here",
+                        ),
+                ),
+                None => seen.push((key, attribute)),
+            }
+        }
+
+        Some(results)
+    }
+}
+
+/// The `name` or `property` attribute of the meta tag with its (lowercase) key.
+/// Values containing twig syntax are not comparable.
+fn meta_key(meta: &HtmlTag) -> Option<((String, String), HtmlAttribute)> {
+    meta.attributes().find_map(|attribute| {
+        let name = attribute.name()?.text().to_ascii_lowercase();
+        if name != "name" && name != "property" {
+            return None;
+        }
+
+        let inner = attribute.value()?.get_inner()?;
+        if inner.syntax().children().next().is_some() {
+            return None;
+        }
+
+        let value = inner
+            .syntax()
+            .text()
+            .to_string()
+            .trim()
+            .to_ascii_lowercase();
+        Some(((name, value), attribute))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "duplicate-meta",
+            r#"<head>
+    <meta name="description" content="a">
+    <meta property="og:title" content="b">
+    <meta NAME="Description" content="c">
+</head>"#,
+            expect![[r#"
+                warning[duplicate-meta]: Duplicate meta tag
+                  ┌─ ./debug-rule.html.twig:4:11
+                  │
+                2 │     <meta name="description" content="a">
+                  │           ------------------ first defined here
+                3 │     <meta property="og:title" content="b">
+                4 │     <meta NAME="Description" content="c">
+                  │           ^^^^^^^^^^^^^^^^^^ a meta tag with name="description" already exists
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "duplicate-meta",
+            r#"<head>
+    <meta name="description" content="a">
+    <meta property="description" content="b">
+    <meta name="{{ metaName }}" content="c">
+    <meta name="{{ metaName }}" content="d">
+    {% if isProduct %}
+        <meta name="robots" content="index">
+    {% else %}
+        <meta name="robots" content="noindex">
+    {% endif %}
+</head>"#,
+            expect![""],
+        );
+    }
+}
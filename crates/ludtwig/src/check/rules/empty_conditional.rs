@@ -0,0 +1,115 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigIf};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleEmptyConditional;
+
+impl Rule for RuleEmptyConditional {
+    fn name(&self) -> &'static str {
+        "empty-conditional"
+    }
+
+    fn description(&self) -> &'static str {
+        "Conditionals whose branches are all empty"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_if = TwigIf::cast(node)?;
+        let children: Vec<SyntaxNode> = twig_if.syntax().children().collect();
+        if children.last()?.kind() != SyntaxKind::TWIG_ENDIF_BLOCK {
+            return None;
+        }
+
+        let all_branches_empty = children
+            .iter()
+            .filter(|child| child.kind() == SyntaxKind::BODY)
+            .all(|body| body.text().to_string().trim().is_empty());
+        if !all_branches_empty {
+            return None;
+        }
+
+        let if_keyword = support::token(&children[0], T!["if"])?;
+        let construct_start = support::token(&children[0], T!["{%"])?.text_range().start();
+        let construct_range = TextRange::new(construct_start, twig_if.syntax().text_range().end());
+
+        // remove the whole line when the construct is the only thing on it
+        let on_own_line = twig_if
+            .syntax()
+            .first_token()
+            .is_some_and(|t| t.kind() == SyntaxKind::TK_LINE_BREAK)
+            && twig_if
+                .syntax()
+                .last_token()
+                .and_then(|t| t.next_token())
+                .is_none_or(|t| t.kind() == SyntaxKind::TK_LINE_BREAK);
+        let removal_range = if on_own_line {
+            twig_if.syntax().text_range()
+        } else {
+            construct_range
+        };
+
+        let result = self
+            .create_result(Severity::Warning, "Empty conditional")
+            .primary_note(
+                if_keyword.text_range(),
+                "all branches of this if are empty, so it has no effect",
+            )
+            .suggestion(removal_range, "", "Remove the conditional");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "empty-conditional",
+            "<div>\n    {% if a %}\n    {% else %} {% endif %}\n</div>",
+            expect![[r"
+                warning[empty-conditional]: Empty conditional
+                  ┌─ ./debug-rule.html.twig:2:8
+                  │  
+                1 │   <div>
+                  │ ╭─────'
+                2 │ │     {% if a %}
+                  │ │        ^^ all branches of this if are empty, so it has no effect
+                3 │ │     {% else %} {% endif %}
+                  │ ╰──────────────────────────' Remove the conditional: 
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "empty-conditional",
+            "{% if a %}\n{% else %}b{% endif %}{% if c %}{# todo #}{% endif %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "empty-conditional",
+            "<div>\n    {% if a %}\n    {% endif %}\n    <p class=\"a {% if b %} {% endif %}\">c</p>\n</div>",
+            expect![[r#"
+                <div>
+                    <p class="a ">c</p>
+                </div>"#]],
+        );
+    }
+}
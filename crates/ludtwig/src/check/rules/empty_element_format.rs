@@ -0,0 +1,168 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::line_indentation;
+use crate::config::EmptyElementFormat;
+
+pub struct RuleEmptyElementFormat;
+
+impl Rule for RuleEmptyElementFormat {
+    fn name(&self) -> &'static str {
+        "empty-element-format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Empty html elements use the configured format"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if ctx.traversal_ctx().inside_trivia_sensitive_node {
+            return None; // no trivia modification allowed here
+        }
+
+        // void and self closing elements have no ending tag
+        let tag = HtmlTag::cast(node)?;
+        let starting_tag = tag.starting_tag()?;
+        let ending_tag_start = support::token(tag.ending_tag()?.syntax(), T!["</"])?;
+        if !tag
+            .body()?
+            .syntax()
+            .children_with_tokens()
+            .all(|e| e.kind().is_trivia())
+        {
+            return None;
+        }
+
+        // the whitespace between the starting and ending tag
+        let mut actual = String::new();
+        let mut previous = ending_tag_start.prev_token();
+        while let Some(t) = previous.filter(|t| t.kind().is_trivia()) {
+            actual.insert_str(0, t.text());
+            previous = t.prev_token();
+        }
+
+        let expected = match ctx.config().rules.empty_element_format {
+            EmptyElementFormat::SameLine => String::new(),
+            EmptyElementFormat::Expanded => {
+                let indentation =
+                    line_indentation(&support::token(starting_tag.syntax(), T!["<"])?)?;
+                format!(
+                    "{}{indentation}",
+                    ctx.config().format.line_ending.corresponding_string()
+                )
+            }
+        };
+        if actual == expected {
+            return None;
+        }
+
+        let between_range = TextRange::new(
+            starting_tag.syntax().text_range().end(),
+            ending_tag_start.text_range().start(),
+        );
+        let result = self
+            .create_result(Severity::Help, "Inconsistent format of empty element")
+            .primary_note(
+                tag.name()?.text_range(),
+                match ctx.config().rules.empty_element_format {
+                    EmptyElementFormat::SameLine => "put the ending tag on the same line",
+                    EmptyElementFormat::Expanded => "put the ending tag on the next line",
+                },
+            )
+            .suggestion(between_range, expected, "Try this instead");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::EmptyElementFormat;
+
+    #[test]
+    fn rule_reports_same_line() {
+        test_rule(
+            "empty-element-format",
+            "<div>\n</div>",
+            expect![[r"
+                help[empty-element-format]: Inconsistent format of empty element
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │  
+                1 │   <div>
+                  │    ^^^ put the ending tag on the same line
+                  │ ╭─────'
+                2 │ │ </div>
+                  │ ╰' Try this instead: 
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_same_line() {
+        test_rule(
+            "empty-element-format",
+            "<div></div><br><img src=\"a.png\"/><p>\n    text\n</p><pre>\n</pre>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_same_line() {
+        test_rule_fix(
+            "empty-element-format",
+            "<section>\n    <div>\n\n    </div>\n</section>",
+            expect![[r"
+                <section>
+                    <div></div>
+                </section>"]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_expanded() {
+        test_rule_with_config(
+            "empty-element-format",
+            "<div></div>\n<p>\n</p>\ntext <span></span>",
+            |config| config.rules.empty_element_format = EmptyElementFormat::Expanded,
+            expect![[r"
+                help[empty-element-format]: Inconsistent format of empty element
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <div></div>
+                  │  ^^^ - Try this instead: 
+
+                  │  │    
+                  │  put the ending tag on the next line
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_expanded() {
+        test_rule_fix(
+            "empty-element-format",
+            "{# ludtwig-config empty-element-format=expanded #}\n<section>\n    <div></div>\n</section>",
+            expect![[r"
+                {# ludtwig-config empty-element-format=expanded #}
+                <section>
+                    <div>
+                    </div>
+                </section>"]],
+        );
+    }
+}
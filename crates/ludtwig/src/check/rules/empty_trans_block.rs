@@ -0,0 +1,68 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigTrans};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleEmptyTransBlock;
+
+impl Rule for RuleEmptyTransBlock {
+    fn name(&self) -> &'static str {
+        "empty-trans-block"
+    }
+
+    fn description(&self) -> &'static str {
+        "Trans blocks contain a translation"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let trans = TwigTrans::cast(node)?;
+        let body = trans.body()?;
+
+        if !body.syntax().text().to_string().trim().is_empty() {
+            return None;
+        }
+
+        let trans_keyword = support::token(trans.starting_block()?.syntax(), T!["trans"])?;
+        let result = self
+            .create_result(Severity::Warning, "Empty trans block")
+            .primary_note(
+                trans_keyword.text_range(),
+                "add a translation to this block or remove it",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "empty-trans-block",
+            "<div>\n    {% trans %}\n    {% endtrans %}\n</div>",
+            expect![[r"
+                warning[empty-trans-block]: Empty trans block
+                  ┌─ ./debug-rule.html.twig:2:8
+                  │
+                2 │     {% trans %}
+                  │        ^^^^^ add a translation to this block or remove it
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_filled_block() {
+        test_rule(
+            "empty-trans-block",
+            "{% trans with {'%name%': name} %}Hello %name%{% endtrans %}",
+            expect![""],
+        );
+    }
+}
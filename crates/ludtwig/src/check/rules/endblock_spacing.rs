@@ -0,0 +1,128 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigEndingBlock};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxNode, SyntaxToken, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleEndblockSpacing;
+
+impl Rule for RuleEndblockSpacing {
+    fn name(&self) -> &'static str {
+        "endblock-spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Endblock tags have exactly one space between their parts"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let ending_block = TwigEndingBlock::cast(node)?;
+
+        // only look at well-formed endblock tags (syntax errors are reported by the parser)
+        let mut tokens = vec![];
+        for element in ending_block.syntax().children_with_tokens() {
+            match element {
+                SyntaxElement::Token(t) if t.kind().is_trivia() => {}
+                SyntaxElement::Token(t) => tokens.push(t),
+                SyntaxElement::Node(_) => return None,
+            }
+        }
+        let kinds: Vec<_> = tokens.iter().map(SyntaxToken::kind).collect();
+        if kinds != [T!["{%"], T!["endblock"], T!["%}"]]
+            && kinds != [T!["{%"], T!["endblock"], T![word], T!["%}"]]
+        {
+            return None;
+        }
+
+        let range = TextRange::new(
+            tokens.first()?.text_range().start(),
+            tokens.last()?.text_range().end(),
+        );
+        let actual: String = ending_block
+            .syntax()
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .skip_while(|t| t.kind().is_trivia())
+            .map(|t| t.text().to_owned())
+            .collect();
+        let expected = match ending_block.name() {
+            Some(name) => format!("{{% endblock {} %}}", name.text()),
+            None => "{% endblock %}".to_owned(),
+        };
+        if actual == expected {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Irregular spacing inside endblock")
+            .primary_note(range, "use exactly one space between the parts")
+            .suggestion(range, expected, "Try this instead");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "endblock-spacing",
+            "{% block a %}{%endblock%}",
+            expect![[r"
+                help[endblock-spacing]: Irregular spacing inside endblock
+                  ┌─ ./debug-rule.html.twig:1:14
+                  │
+                1 │ {% block a %}{%endblock%}
+                  │              ^^^^^^^^^^^^
+                  │              │
+                  │              use exactly one space between the parts
+                  │              Try this instead: {% endblock %}
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "endblock-spacing",
+            "{% block a %}{% block b %}{% endblock b %}{% endblock %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_without_name() {
+        test_rule_fix(
+            "endblock-spacing",
+            "{% block a %}\n{%endblock%}",
+            expect![[r"
+                {% block a %}
+                {% endblock %}"]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_with_name() {
+        test_rule_fix(
+            "endblock-spacing",
+            "{% block a %}\n{%   endblock  a\n%}",
+            expect![[r"
+                {% block a %}
+                {% endblock a %}"]],
+        );
+    }
+}
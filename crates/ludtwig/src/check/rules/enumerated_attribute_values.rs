@@ -0,0 +1,162 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::edit_distance;
+
+/// Enumerated html attributes and their valid (lowercase) keywords
+static ENUMERATED_ATTRIBUTES: &[(&str, &[&str])] = &[
+    (
+        "autocapitalize",
+        &["off", "none", "on", "sentences", "words", "characters"],
+    ),
+    ("contenteditable", &["", "true", "false", "plaintext-only"]),
+    ("crossorigin", &["", "anonymous", "use-credentials"]),
+    ("decoding", &["sync", "async", "auto"]),
+    ("dir", &["ltr", "rtl", "auto"]),
+    ("draggable", &["true", "false"]),
+    (
+        "enterkeyhint",
+        &["enter", "done", "go", "next", "previous", "search", "send"],
+    ),
+    ("fetchpriority", &["high", "low", "auto"]),
+    (
+        "inputmode",
+        &[
+            "none", "text", "decimal", "numeric", "tel", "search", "email", "url",
+        ],
+    ),
+    ("loading", &["lazy", "eager"]),
+    ("spellcheck", &["", "true", "false"]),
+    ("translate", &["", "yes", "no"]),
+];
+
+/// Values which are commonly used in place of a keyword with the same meaning
+static SYNONYMS: &[(&str, &str)] = &[
+    ("yes", "true"),
+    ("on", "true"),
+    ("1", "true"),
+    ("no", "false"),
+    ("off", "false"),
+    ("0", "false"),
+    ("true", "yes"),
+    ("false", "no"),
+];
+
+pub struct RuleEnumeratedAttributeValues;
+
+impl Rule for RuleEnumeratedAttributeValues {
+    fn name(&self) -> &'static str {
+        "enumerated-attribute-values"
+    }
+
+    fn description(&self) -> &'static str {
+        "Enumerated html attributes only have one of their allowed values"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        let (_, valid_values) = ENUMERATED_ATTRIBUTES
+            .iter()
+            .find(|(name, _)| attribute_name.text().eq_ignore_ascii_case(name))?;
+
+        // dynamic values (containing twig syntax) are skipped
+        let inner = attribute.value()?.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None;
+        }
+        let value = inner.syntax().text().to_string();
+        let lowercase_value = value.trim().to_ascii_lowercase();
+        if valid_values.contains(&lowercase_value.as_str()) {
+            return None;
+        }
+
+        let nearest = nearest_value(&lowercase_value, valid_values)?;
+        let result = self
+            .create_result(Severity::Warning, "Invalid value for enumerated attribute")
+            .primary_note(
+                inner.syntax().text_range(),
+                format!(
+                    "'{value}' is not valid for {}, expected one of: {}",
+                    attribute_name.text(),
+                    valid_values
+                        .iter()
+                        .filter(|v| !v.is_empty())
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .suggestion(
+                inner.syntax().text_range(),
+                nearest,
+                "Did you mean this value?",
+            );
+
+        Some(vec![result])
+    }
+}
+
+fn nearest_value(value: &str, valid_values: &[&'static str]) -> Option<&'static str> {
+    if let Some((_, synonym)) = SYNONYMS
+        .iter()
+        .find(|(from, to)| *from == value && valid_values.contains(to))
+    {
+        return Some(synonym);
+    }
+
+    valid_values
+        .iter()
+        .filter(|v| !v.is_empty())
+        .min_by_key(|v| edit_distance(value, v))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "enumerated-attribute-values",
+            r#"<div draggable="yes"></div>"#,
+            expect![[r#"
+                warning[enumerated-attribute-values]: Invalid value for enumerated attribute
+                  ┌─ ./debug-rule.html.twig:1:17
+                  │
+                1 │ <div draggable="yes"></div>
+                  │                 ^^^
+                  │                 │
+                  │                 'yes' is not valid for draggable, expected one of: true, false
+                  │                 Did you mean this value?: true
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "enumerated-attribute-values",
+            r#"<div draggable="true" translate="no" spellcheck="" dir="{{ dir }}" contenteditable></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "enumerated-attribute-values",
+            r#"<img loading="lazzy" translate="false" draggable="yes">"#,
+            expect![[r#"<img loading="lazy" translate="no" draggable="true">"#]],
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange, TextSize};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleFinalNewline;
+
+impl Rule for RuleFinalNewline {
+    fn name(&self) -> &'static str {
+        "final-newline"
+    }
+
+    fn description(&self) -> &'static str {
+        "Files end with exactly one line break"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_root(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let source = node.text().to_string();
+        if source.is_empty() {
+            return None;
+        }
+
+        let file_end = TextSize::of(source.as_str());
+        let content = source.trim_end_matches([' ', '\t', '\r', '\n']);
+        let tail = &source[content.len()..];
+        let result = match tail.find('\n') {
+            None => {
+                let line_ending = ctx.config().format.line_ending.corresponding_string();
+                self.create_result(Severity::Help, "Missing final newline")
+                    .primary_note(
+                        TextRange::empty(file_end),
+                        "help: end the file with a line break",
+                    )
+                    .suggestion(TextRange::empty(file_end), line_ending, "Add a line break")
+            }
+            Some(first_line_break) => {
+                // keep only the first line break at the end of the file
+                let keep_end = content.len() + first_line_break + 1;
+                if keep_end == source.len() {
+                    return None;
+                }
+
+                let extra_range = TextRange::new(TextSize::try_from(keep_end).unwrap(), file_end);
+                self.create_result(Severity::Help, "Multiple trailing line breaks")
+                    .primary_note(extra_range, "help: end the file with a single line break")
+                    .suggestion(extra_range, "", "Remove these line breaks")
+            }
+        };
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "final-newline",
+            "<div></div>\n\n\n",
+            expect![[r"
+            help[final-newline]: Multiple trailing line breaks
+              ┌─ ./debug-rule.html.twig:2:1
+              │    
+            2 │ ╭ ╭ 
+            3 │ │ │ 
+            4 │ │ │ 
+              │ ╰─│^ help: end the file with a single line break
+              │   ╰' Remove these line breaks: 
+
+        "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule("final-newline", "<div></div>\n", expect![""]);
+        test_rule("final-newline", "<div></div>\r\n", expect![""]);
+        test_rule("final-newline", "", expect![""]);
+    }
+
+    #[test]
+    fn rule_fixes_missing_newline() {
+        test_rule_fix("final-newline", "<div></div>", expect!["<div></div>\n"]);
+    }
+
+    #[test]
+    fn rule_fixes_multiple_newlines() {
+        test_rule_fix(
+            "final-newline",
+            "<div></div>\r\n  \r\n\r\n",
+            expect!["<div></div>\r\n"],
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt, TextRange};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+static MULTIPART: &str = "multipart/form-data";
+
+pub struct RuleFormFileEnctype;
+
+impl Rule for RuleFormFileEnctype {
+    fn name(&self) -> &'static str {
+        "form-file-enctype"
+    }
+
+    fn description(&self) -> &'static str {
+        "Forms with file inputs use the post method and the multipart/form-data enctype"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let form = HtmlTag::cast(node)?;
+        let form_name = form.name()?;
+        if !has_name(&form, "form") {
+            return None;
+        }
+
+        let file_input = form
+            .syntax()
+            .descendants()
+            .skip(1)
+            .filter_map(HtmlTag::cast)
+            .find(|tag| has_name(tag, "input") && is_file_input(tag))?;
+        let file_input_name = file_input.name()?;
+
+        let quote = ctx.config().format.html_quotation.corresponding_char();
+        let expected_attribute = format!("enctype={quote}{MULTIPART}{quote}");
+
+        // browsers ignore the enctype for the get method (which is also the default),
+        // switching to post changes how the server receives the form, so there is no fix for it
+        let method = find_attribute(&form, "method");
+        let method_value = method
+            .as_ref()
+            .and_then(HtmlAttribute::value)
+            .and_then(|value| value.get_inner());
+        let is_post = method_value.as_ref().is_some_and(|inner| {
+            // values with twig syntax can't be checked
+            inner.syntax().children().next().is_some()
+                || inner
+                    .syntax()
+                    .text()
+                    .to_string()
+                    .trim()
+                    .eq_ignore_ascii_case("post")
+        });
+        if !is_post {
+            let range = match (&method, &method_value) {
+                (_, Some(inner)) => inner.syntax().text_range(),
+                (Some(method), None) => method.syntax().text_range_trimmed_trivia(),
+                (None, None) => form_name.text_range(),
+            };
+            let result = self
+                .create_result(Severity::Error, "Form with file input doesn't use the post method")
+                .primary_note(
+                    range,
+                    format!("this form needs method={quote}post{quote} and {expected_attribute} to upload files"),
+                )
+                .secondary_note(file_input_name.text_range(), "file input");
+
+            return Some(vec![result]);
+        }
+
+        let enctype = find_attribute(&form, "enctype");
+
+        let result = match enctype {
+            None => {
+                // add the attribute behind the last attribute (or the tag name)
+                let insert_position = form
+                    .attributes()
+                    .last()
+                    .map_or(form_name.text_range().end(), |attribute| {
+                        attribute.syntax().text_range().end()
+                    });
+
+                self.create_result(Severity::Error, "Missing enctype on form with file input")
+                    .primary_note(
+                        form_name.text_range(),
+                        format!("this form needs {expected_attribute} to upload files"),
+                    )
+                    .suggestion(
+                        TextRange::empty(insert_position),
+                        format!(" {expected_attribute}"),
+                        "Add the enctype",
+                    )
+            }
+            Some(enctype) => {
+                let (range, replacement) = match enctype.value() {
+                    Some(value) => {
+                        let inner = value.get_inner()?;
+                        // values with twig syntax can't be checked
+                        if inner.syntax().children().next().is_some() {
+                            return None;
+                        }
+                        if inner
+                            .syntax()
+                            .text()
+                            .to_string()
+                            .trim()
+                            .eq_ignore_ascii_case(MULTIPART)
+                        {
+                            return None;
+                        }
+
+                        (inner.syntax().text_range(), MULTIPART.to_owned())
+                    }
+                    None => (
+                        enctype.syntax().text_range_trimmed_trivia(),
+                        expected_attribute.clone(),
+                    ),
+                };
+
+                self.create_result(Severity::Error, "Wrong enctype on form with file input")
+                    .primary_note(
+                        range,
+                        format!("this form needs {expected_attribute} to upload files"),
+                    )
+                    .suggestion(range, replacement, "Use this enctype instead")
+            }
+        }
+        .secondary_note(file_input_name.text_range(), "file input");
+
+        Some(vec![result])
+    }
+}
+
+fn find_attribute(tag: &HtmlTag, name: &str) -> Option<HtmlAttribute> {
+    tag.attributes().find(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|attribute_name| attribute_name.text().eq_ignore_ascii_case(name))
+    })
+}
+
+fn has_name(tag: &HtmlTag, name: &str) -> bool {
+    tag.name()
+        .is_some_and(|tag_name| tag_name.text().eq_ignore_ascii_case(name))
+}
+
+fn is_file_input(input: &HtmlTag) -> bool {
+    input.attributes().any(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|name| name.text().eq_ignore_ascii_case("type"))
+            && attribute
+                .value()
+                .and_then(|value| value.get_inner())
+                .is_some_and(|inner| {
+                    inner
+                        .syntax()
+                        .text()
+                        .to_string()
+                        .trim()
+                        .eq_ignore_ascii_case("file")
+                })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_does_not_fix, test_rule_fix};
+
+    #[test]
+    fn rule_reports_missing() {
+        test_rule(
+            "form-file-enctype",
+            r#"<form method="post">
+    <input type="file" name="avatar">
+</form>"#,
+            expect![[r#"
+                error[form-file-enctype]: Missing enctype on form with file input
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <form method="post">
+                  │  ^^^^              - Add the enctype:  enctype="multipart/form-data"
+                  │  │                  
+                  │  this form needs enctype="multipart/form-data" to upload files
+                2 │     <input type="file" name="avatar">
+                  │      ----- file input
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_wrong() {
+        test_rule(
+            "form-file-enctype",
+            r#"<form method="post" enctype="text/plain"><input type="file"></form>"#,
+            expect![[r#"
+                error[form-file-enctype]: Wrong enctype on form with file input
+                  ┌─ ./debug-rule.html.twig:1:30
+                  │
+                1 │ <form method="post" enctype="text/plain"><input type="file"></form>
+                  │                              ^^^^^^^^^^   ----- file input
+                  │                              │             
+                  │                              this form needs enctype="multipart/form-data" to upload files
+                  │                              Use this enctype instead: multipart/form-data
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_get_method() {
+        test_rule(
+            "form-file-enctype",
+            r#"<form method="get"><input type="file"></form>
+<form enctype="multipart/form-data"><input type="file"></form>"#,
+            expect![[r#"
+                error[form-file-enctype]: Form with file input doesn't use the post method
+                  ┌─ ./debug-rule.html.twig:1:15
+                  │
+                1 │ <form method="get"><input type="file"></form>
+                  │               ^^^   ----- file input
+                  │               │      
+                  │               this form needs method="post" and enctype="multipart/form-data" to upload files
+
+                error[form-file-enctype]: Form with file input doesn't use the post method
+                  ┌─ ./debug-rule.html.twig:2:2
+                  │
+                2 │ <form enctype="multipart/form-data"><input type="file"></form>
+                  │  ^^^^                                ----- file input
+                  │  │                                    
+                  │  this form needs method="post" and enctype="multipart/form-data" to upload files
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_fix_get_method() {
+        test_rule_does_not_fix(
+            "form-file-enctype",
+            r#"<form method="get"><input type="file"></form>"#,
+            expect![[r#"<form method="get"><input type="file"></form>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "form-file-enctype",
+            r#"<form method="post" enctype="multipart/form-data"><div><input type="file"></div></form>
+<form method="{{ method }}" enctype="{{ enctype }}"><input type="file"></form>
+<form method="post"><input type="text"></form>
+<form><input type="text"></form>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "form-file-enctype",
+            r#"<form method="post">
+    <input type="file" name="avatar">
+</form>
+<form method="POST" enctype="text/plain"><input type="file"></form>
+<form method="post" enctype><input type="file"></form>"#,
+            expect![[r#"
+                <form method="post" enctype="multipart/form-data">
+                    <input type="file" name="avatar">
+                </form>
+                <form method="POST" enctype="multipart/form-data"><input type="file"></form>
+                <form method="post" enctype="multipart/form-data"><input type="file"></form>"#]],
+        );
+    }
+}
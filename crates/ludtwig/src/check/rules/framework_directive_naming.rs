@@ -0,0 +1,116 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+use regex::Regex;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleFrameworkDirectiveNaming;
+
+impl Rule for RuleFrameworkDirectiveNaming {
+    fn name(&self) -> &'static str {
+        "framework-directive-naming"
+    }
+
+    fn description(&self) -> &'static str {
+        "Framework directives (like v-on or x-data) match the configured naming patterns"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute_name = HtmlAttribute::cast(node)?.name()?;
+        let name = attribute_name.text();
+
+        // standard html attributes are not framework directives
+        let rules_config = &ctx.config().rules;
+        if !rules_config
+            .framework_directive_prefixes
+            .iter()
+            .any(|prefix| name.starts_with(prefix.as_str()))
+        {
+            return None;
+        }
+
+        // invalid patterns never match
+        let matches_convention = rules_config
+            .framework_directive_patterns
+            .iter()
+            .filter_map(|pattern| Regex::new(pattern).ok())
+            .any(|regex| regex.is_match(name));
+        if matches_convention {
+            return None;
+        }
+
+        let result = self
+            .create_result(
+                Severity::Help,
+                "Framework directive does not follow the naming conventions",
+            )
+            .primary_note(
+                attribute_name.text_range(),
+                "rename this directive to match one of the framework-directive-patterns",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "framework-directive-naming",
+            r#"<div v-on:click.prevent="a" @click="b" x-on:Click="c" :myProp="d"></div>"#,
+            expect![[r#"
+                help[framework-directive-naming]: Framework directive does not follow the naming conventions
+                  ┌─ ./debug-rule.html.twig:1:40
+                  │
+                1 │ <div v-on:click.prevent="a" @click="b" x-on:Click="c" :myProp="d"></div>
+                  │                                        ^^^^^^^^^^ rename this directive to match one of the framework-directive-patterns
+
+                help[framework-directive-naming]: Framework directive does not follow the naming conventions
+                  ┌─ ./debug-rule.html.twig:1:55
+                  │
+                1 │ <div v-on:click.prevent="a" @click="b" x-on:Click="c" :myProp="d"></div>
+                  │                                                       ^^^^^^^ rename this directive to match one of the framework-directive-patterns
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_standard_attributes() {
+        test_rule(
+            "framework-directive-naming",
+            r#"<div class="a" data-fooBar="b" aria-label="c"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_with_custom_conventions() {
+        test_rule_with_config(
+            "framework-directive-naming",
+            r#"<div x-data="{}" v-if="a"></div>"#,
+            |config| {
+                config.rules.framework_directive_prefixes = vec!["x-".to_owned(), "v-".to_owned()];
+                config.rules.framework_directive_patterns = vec!["^x-[a-z]+$".to_owned()];
+            },
+            expect![[r#"
+                help[framework-directive-naming]: Framework directive does not follow the naming conventions
+                  ┌─ ./debug-rule.html.twig:1:18
+                  │
+                1 │ <div x-data="{}" v-if="a"></div>
+                  │                  ^^^^ rename this directive to match one of the framework-directive-patterns
+
+            "#]],
+        );
+    }
+}
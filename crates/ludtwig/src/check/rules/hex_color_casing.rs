@@ -0,0 +1,150 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange, TextSize};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Hex colors with 3, 4, 6 or 8 digits
+static HEX_COLOR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"#(?:[0-9a-fA-F]{8}|[0-9a-fA-F]{6}|[0-9a-fA-F]{3,4})\b").unwrap());
+
+pub struct RuleHexColorCasing;
+
+impl Rule for RuleHexColorCasing {
+    fn name(&self) -> &'static str {
+        "hex-color-casing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Hex colors use the configured casing"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        if !attribute.name()?.text().eq_ignore_ascii_case("style") {
+            return None;
+        }
+        let inner = attribute.value()?.get_inner()?;
+
+        // split the value into runs of plain text (twig syntax in between is skipped)
+        let mut runs: Vec<(TextSize, String)> = vec![];
+        let mut previous_was_token = false;
+        for element in inner.syntax().children_with_tokens() {
+            let Some(t) = element.into_token() else {
+                previous_was_token = false;
+                continue;
+            };
+            match runs.last_mut() {
+                Some((_, text)) if previous_was_token => text.push_str(t.text()),
+                _ => runs.push((t.text_range().start(), t.text().to_owned())),
+            }
+            previous_was_token = true;
+        }
+
+        let casing = &ctx.config().rules.hex_color_casing;
+        let results: Vec<CheckResult> = runs
+            .iter()
+            .flat_map(|(run_start, text)| {
+                HEX_COLOR_REGEX
+                    .find_iter(text)
+                    .map(move |m| (*run_start, m.start(), m.as_str()))
+            })
+            .filter_map(|(run_start, offset, color)| {
+                let expected = casing.apply(color);
+                if expected == color {
+                    return None;
+                }
+
+                let start = run_start + TextSize::try_from(offset).unwrap();
+                let range = TextRange::at(start, TextSize::of(color));
+                Some(
+                    self.create_result(Severity::Help, "Inconsistent casing of hex color")
+                        .primary_note(range, format!("help: use {expected} instead"))
+                        .suggestion(range, expected, "Try this color instead"),
+                )
+            })
+            .collect();
+
+        Some(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::LiteralCasing;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "hex-color-casing",
+            r#"<div style="color: #ABC; background: #fff"></div>"#,
+            expect![[r#"
+                help[hex-color-casing]: Inconsistent casing of hex color
+                  ┌─ ./debug-rule.html.twig:1:20
+                  │
+                1 │ <div style="color: #ABC; background: #fff"></div>
+                  │                    ^^^^
+                  │                    │
+                  │                    help: use #abc instead
+                  │                    Try this color instead: #abc
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "hex-color-casing",
+            r##"<div style="color: #abc; border-color: #{{ color }}" class="#ABC"></div>
+<a href="#TOP">top</a>"##,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_uppercase() {
+        test_rule_with_config(
+            "hex-color-casing",
+            r#"<div style="color: #ABCDEF; background: #ff00aa80"></div>"#,
+            |config| {
+                config.rules.hex_color_casing = LiteralCasing::Uppercase;
+            },
+            expect![[r#"
+                help[hex-color-casing]: Inconsistent casing of hex color
+                  ┌─ ./debug-rule.html.twig:1:41
+                  │
+                1 │ <div style="color: #ABCDEF; background: #ff00aa80"></div>
+                  │                                         ^^^^^^^^^
+                  │                                         │
+                  │                                         help: use #FF00AA80 instead
+                  │                                         Try this color instead: #FF00AA80
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "hex-color-casing",
+            r#"<div style="color: #ABC; background: #FfF; border: 1px solid #00AAff {{ extra }}"></div>"#,
+            expect![[
+                r#"<div style="color: #abc; background: #fff; border: 1px solid #00aaff {{ extra }}"></div>"#
+            ]],
+        );
+    }
+}
@@ -10,9 +10,28 @@ impl Rule for RuleHtmlAttributeNameKebabCase {
         "html-attribute-name-kebab-case"
     }
 
-    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+    fn description(&self) -> &'static str {
+        "Html attribute names are written in kebab-case"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if ctx.traversal_ctx().inside_foreign_element {
+            return None; // svg attributes like viewBox are case-sensitive
+        }
+
         let attribute_name = HtmlAttribute::cast(node)?.name()?;
-        if !is_valid_alphanumeric_kebab_case(attribute_name.text()) {
+        if !name_segments(attribute_name.text())
+            .iter()
+            .all(|segment| is_valid_alphanumeric_kebab_case(segment))
+        {
             // name is not valid
             let mut result = self
                 .create_result(
@@ -25,7 +44,14 @@ impl Rule for RuleHtmlAttributeNameKebabCase {
                 );
 
             // try make a suggestion
-            if let Some(suggested_name) = try_make_kebab_case(attribute_name.text()) {
+            let suggested_name: Option<Vec<String>> = name_segments(attribute_name.text())
+                .iter()
+                .map(|segment| match segment.strip_prefix('.') {
+                    Some(modifier) => try_make_kebab_case(modifier).map(|m| format!(".{m}")),
+                    None => try_make_kebab_case(segment),
+                })
+                .collect();
+            if let Some(suggested_name) = suggested_name.map(|segments| segments.concat()) {
                 result = result.suggestion(
                     attribute_name.text_range(),
                     suggested_name,
@@ -39,7 +65,25 @@ impl Rule for RuleHtmlAttributeNameKebabCase {
     }
 }
 
+/// Splits framework directive names like `v-on:click.prevent` into `v-on`, `:click` and `.prevent`
+/// (the dot of modifiers is kept so they can be validated without it)
+fn name_segments(name: &str) -> Vec<&str> {
+    let mut segments = vec![];
+    let mut start = 0;
+    for (idx, c) in name.char_indices() {
+        if idx > 0 && (c == ':' || c == '.') {
+            segments.push(&name[start..idx]);
+            start = idx;
+        }
+    }
+    segments.push(&name[start..]);
+
+    segments
+}
+
 fn is_valid_alphanumeric_kebab_case(s: &str) -> bool {
+    let s = s.strip_prefix('.').unwrap_or(s);
+
     let mut iter = s.chars().enumerate().peekable();
     while let Some((idx, c)) = iter.next() {
         let next = iter.peek();
@@ -147,6 +191,16 @@ mod tests {
         assert!(!is_valid_alphanumeric_kebab_case("myA"));
     }
 
+    #[test]
+    fn test_name_segments() {
+        assert_eq!(name_segments("my-attribute"), vec!["my-attribute"]);
+        assert_eq!(name_segments(":vue-bound"), vec![":vue-bound"]);
+        assert_eq!(
+            name_segments("v-on:click.prevent"),
+            vec!["v-on", ":click", ".prevent"]
+        );
+    }
+
     #[test]
     fn test_try_make_kebab_case() {
         assert_eq!(
@@ -190,7 +244,7 @@ mod tests {
         test_rule(
             "html-attribute-name-kebab-case",
             "<custom aBc/>",
-            expect![[r#"
+            expect![[r"
                 help[html-attribute-name-kebab-case]: Attribute name is not written in kebab-case
                   ┌─ ./debug-rule.html.twig:1:9
                   │
@@ -200,7 +254,7 @@ mod tests {
                   │         help: rename this attribute in kebab-case
                   │         Try this name instead: a-bc
 
-            "#]],
+            "]],
         );
     }
 
@@ -212,4 +266,31 @@ mod tests {
             expect!["<custom a-bc/>"],
         );
     }
+
+    #[test]
+    fn rule_does_not_report_framework_directives() {
+        test_rule(
+            "html-attribute-name-kebab-case",
+            r#"<div v-on:click.prevent="a" @click.self="b" :my-prop="c"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_framework_directives() {
+        test_rule_fix(
+            "html-attribute-name-kebab-case",
+            r#"<div v-on:clickOutside.preventDefault="a"></div>"#,
+            expect![[r#"<div v-on:click-outside.prevent-default="a"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_svg_attributes() {
+        test_rule(
+            "html-attribute-name-kebab-case",
+            r#"<svg viewBox="0 0 10 10" preserveAspectRatio="none"><path pathLength="10"/></svg>"#,
+            expect![""],
+        );
+    }
 }
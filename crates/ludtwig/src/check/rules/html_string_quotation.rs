@@ -1,5 +1,7 @@
 use ludtwig_parser::syntax::typed::{AstNode, HtmlString};
-use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt, TextRange, TextSize};
+use ludtwig_parser::syntax::untyped::{
+    SyntaxElement, SyntaxNode, SyntaxNodeExt, SyntaxToken, TextRange, TextSize,
+};
 
 use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
 
@@ -10,16 +12,28 @@ impl Rule for RuleHtmlStringQuotation {
         "html-string-quotation"
     }
 
+    fn description(&self) -> &'static str {
+        "Html attribute values use the configured quotation"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let html_string = HtmlString::cast(node)?;
 
         let correct_quote = ctx.config().format.html_quotation.corresponding_char();
         let opening_is_fine = html_string
             .get_opening_quote()
-            .map_or(false, |t| t.text().starts_with(correct_quote));
+            .is_some_and(|t| t.text().starts_with(correct_quote));
         let closing_is_fine = html_string
             .get_closing_quote()
-            .map_or(false, |t| t.text().starts_with(correct_quote));
+            .is_some_and(|t| t.text().starts_with(correct_quote));
 
         if !opening_is_fine || !closing_is_fine {
             // invalid quotation
@@ -47,14 +61,34 @@ pub fn make_changed_quotes_suggestion_if_possible(
     correct_quote: char,
     mut result: CheckResult,
 ) -> CheckResult {
-    let inner = match twig_string.get_inner() {
-        Some(inner) => inner,
-        None => return result,
+    let Some(inner) = twig_string.get_inner() else {
+        return result;
     };
 
-    let inner_text = inner.syntax().text();
-    if inner_text.contains_char(correct_quote) {
-        return result; // TODO: could still try to transform the string with more effort...
+    // the correct quote inside of the value must be escaped as a html character reference,
+    // which is only possible outside of twig syntax
+    let quotes_in_value: Vec<SyntaxToken> = inner
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|t| t.text().contains(correct_quote))
+        .collect();
+    if quotes_in_value
+        .iter()
+        .any(|t| t.parent().as_ref() != Some(inner.syntax()))
+    {
+        return result;
+    }
+    let escaped_quote = match correct_quote {
+        '"' => "&quot;",
+        _ => "&#39;",
+    };
+    for token in quotes_in_value {
+        result = result.suggestion(
+            token.text_range(),
+            token.text().replace(correct_quote, escaped_quote),
+            "Escape this quote",
+        );
     }
 
     // opening quote
@@ -91,7 +125,7 @@ mod tests {
     fn rule_reports() {
         test_rule(
             "html-string-quotation",
-            r#"<div class='a'></div>"#,
+            r"<div class='a'></div>",
             expect![[r#"
                 help[html-string-quotation]: wrong quotation
                   ┌─ ./debug-rule.html.twig:1:12
@@ -117,7 +151,13 @@ mod tests {
                   ┌─ ./debug-rule.html.twig:1:12
                   │
                 1 │ <div style='value: "a"'></div>
-                  │            ^^^^^^^^^^^^ help: change the quotation to double quotes (")
+                  │            ^^^^^^^^^^^^
+                  │            │       │ ││
+                  │            │       │ │Try this quote instead: "
+                  │            │       │ Escape this quote: &quot;
+                  │            │       Escape this quote: &quot;
+                  │            help: change the quotation to double quotes (")
+                  │            Try this quote instead: "
 
             "#]],
         );
@@ -127,7 +167,7 @@ mod tests {
     fn rule_does_report_strings_without_quotes() {
         test_rule(
             "html-string-quotation",
-            r#"<div class=a></div>"#,
+            r"<div class=a></div>",
             expect![[r#"
                 help[html-string-quotation]: wrong quotation
                   ┌─ ./debug-rule.html.twig:1:12
@@ -146,7 +186,7 @@ mod tests {
     fn rule_fixes() {
         test_rule_fix(
             "html-string-quotation",
-            r#"<div class='a'></div>"#,
+            r"<div class='a'></div>",
             expect![r#"<div class="a"></div>"#],
         );
     }
@@ -155,17 +195,26 @@ mod tests {
     fn rule_fixes_no_quotation() {
         test_rule_fix(
             "html-string-quotation",
-            r#"<div class=a required></div>"#,
+            r"<div class=a required></div>",
             expect![r#"<div class="a" required></div>"#],
         );
     }
 
     #[test]
-    fn rule_doesnt_fix_strings_containing_same_quotation() {
+    fn rule_fixes_strings_containing_same_quotation() {
+        test_rule_fix(
+            "html-string-quotation",
+            r#"<div style='value: "a"' title='{{ "b" }} "c"'></div>"#,
+            expect![[r#"<div style="value: &quot;a&quot;" title='{{ "b" }} "c"'></div>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_doesnt_fix_strings_containing_same_quotation_in_twig() {
         test_rule_does_not_fix(
             "html-string-quotation",
-            r#"<div style='value: "a"'></div>"#,
-            expect![r#"<div style='value: "a"'></div>"#],
+            r#"<div title='{{ "a" }}'></div>"#,
+            expect![r#"<div title='{{ "a" }}'></div>"#],
         );
     }
 }
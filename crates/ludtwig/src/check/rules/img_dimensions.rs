@@ -0,0 +1,156 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlAttributeList, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleImgDimensions;
+
+impl Rule for RuleImgDimensions {
+    fn name(&self) -> &'static str {
+        "img-dimensions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Images have explicit width and height attributes"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        if !tag_name.text().eq_ignore_ascii_case("img") {
+            return None;
+        }
+
+        // also look into nested twig syntax (like conditionals) inside the attribute list
+        let attribute_list = tag
+            .starting_tag()?
+            .syntax()
+            .children()
+            .find_map(HtmlAttributeList::cast)?;
+
+        let mut has_width = false;
+        let mut has_height = false;
+        for attribute in attribute_list
+            .syntax()
+            .descendants()
+            .filter_map(HtmlAttribute::cast)
+        {
+            let Some(name) = attribute.name() else {
+                // dynamic attribute names could contain the dimensions
+                return None;
+            };
+
+            if name.text().eq_ignore_ascii_case("width") {
+                has_width = true;
+            } else if name.text().eq_ignore_ascii_case("height") {
+                has_height = true;
+            }
+        }
+
+        let missing = if ctx.config().rules.img_dimensions_require_both {
+            !(has_width && has_height)
+        } else {
+            !(has_width || has_height)
+        };
+        if !missing {
+            return None;
+        }
+
+        let result = self
+            .create_result(
+                Severity::Warning,
+                "Image without explicit width and height attributes",
+            )
+            .primary_note(
+                tag_name.text_range(),
+                "add width and height attributes to avoid layout shifts",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "img-dimensions",
+            r#"<img src="a.png" alt="a">"#,
+            expect![[r#"
+                warning[img-dimensions]: Image without explicit width and height attributes
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <img src="a.png" alt="a">
+                  │  ^^^ add width and height attributes to avoid layout shifts
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_with_dimensions() {
+        test_rule(
+            "img-dimensions",
+            r#"<img src="a.png" width="10" height="20"><IMG src="b.png" height="20" />"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_conditional_dimensions() {
+        test_rule(
+            "img-dimensions",
+            r#"<img src="a.png" {% if big %}width="100"{% endif %}>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_missing_one_when_both_required() {
+        test_rule_with_config(
+            "img-dimensions",
+            r#"<img src="a.png" width="10"><img src="b.png" width="10" height="20">"#,
+            |config| config.rules.img_dimensions_require_both = true,
+            expect![[r#"
+                warning[img-dimensions]: Image without explicit width and height attributes
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <img src="a.png" width="10"><img src="b.png" width="10" height="20">
+                  │  ^^^ add width and height attributes to avoid layout shifts
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_missing_one_with_inline_config() {
+        test_rule(
+            "img-dimensions",
+            r#"{# ludtwig-config img-dimensions-require-both=true #}
+<img src="a.png" width="10">"#,
+            expect![[r#"
+                warning[img-dimensions]: Image without explicit width and height attributes
+                  ┌─ ./debug-rule.html.twig:2:2
+                  │
+                2 │ <img src="a.png" width="10">
+                  │  ^^^ add width and height attributes to avoid layout shifts
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_ignored() {
+        test_rule(
+            "img-dimensions",
+            r#"{# ludtwig-ignore img-dimensions #}
+<img src="a.png">"#,
+            expect![""],
+        );
+    }
+}
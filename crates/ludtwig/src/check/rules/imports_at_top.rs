@@ -0,0 +1,211 @@
+use ludtwig_parser::syntax::typed::{AstNode, LudtwigDirectiveIgnore};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange, TextSize};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleImportsAtTop;
+
+impl Rule for RuleImportsAtTop {
+    fn name(&self) -> &'static str {
+        "imports-at-top"
+    }
+
+    fn description(&self) -> &'static str {
+        "Macro imports are at the top of the file"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_root(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let children: Vec<SyntaxNode> = node.children().collect();
+        let header_len = children
+            .iter()
+            .take_while(|child| is_header(child.kind()))
+            .count();
+        // imports are moved behind the last node of the header (or to the start of the file)
+        let insert_at = header_len
+            .checked_sub(1)
+            .map_or(TextSize::from(0), |last| children[last].text_range().end());
+
+        let misplaced: Vec<(&SyntaxNode, TextRange)> = children[header_len..]
+            .iter()
+            .filter(|child| is_import(child.kind()) && !is_ignored(child, self.name()))
+            .filter_map(|import| Some((import, non_trivia_range(import)?)))
+            .collect();
+
+        // all imports are moved up by a single insertion (multiple insertions at the same
+        // position would overlap)
+        let moved_imports: String = misplaced
+            .iter()
+            .map(|(import, range)| {
+                let import_text = import.text().to_string();
+                let import_text =
+                    &import_text[usize::from(range.start() - import.text_range().start())..];
+                if header_len == 0 {
+                    format!("{import_text}{}", line_break(import))
+                } else {
+                    format!("{}{import_text}", line_break(import))
+                }
+            })
+            .collect();
+
+        let mut results = vec![];
+        for (import, range) in misplaced {
+            let mut result = self
+                .create_result(
+                    Severity::Help,
+                    "Macro imports should be at the top of the file",
+                )
+                .primary_note(range, "move this import above the other content");
+
+            if results.is_empty() {
+                result = result.suggestion(
+                    TextRange::empty(insert_at),
+                    moved_imports.clone(),
+                    "Move the imports up",
+                );
+            }
+
+            results.push(result.suggestion(import.text_range(), "", "Remove it here"));
+        }
+
+        Some(results)
+    }
+}
+
+/// Nodes which are allowed before (and between) the macro imports
+fn is_header(kind: SyntaxKind) -> bool {
+    is_import(kind)
+        || matches!(
+            kind,
+            SyntaxKind::TWIG_EXTENDS
+                | SyntaxKind::SHOPWARE_TWIG_SW_EXTENDS
+                | SyntaxKind::TWIG_USE
+                | SyntaxKind::TWIG_COMMENT
+                | SyntaxKind::LUDTWIG_DIRECTIVE_FILE_IGNORE
+                | SyntaxKind::LUDTWIG_DIRECTIVE_IGNORE
+        )
+}
+
+fn is_import(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::TWIG_IMPORT | SyntaxKind::TWIG_FROM)
+}
+
+/// Imports behind an ignore directive for this rule must not be moved together with the others
+fn is_ignored(node: &SyntaxNode, rule_name: &str) -> bool {
+    node.prev_sibling()
+        .and_then(LudtwigDirectiveIgnore::cast)
+        .is_some_and(|directive| {
+            let rules = directive.get_rules();
+            rules.is_empty() || rules.iter().any(|r| r == rule_name)
+        })
+}
+
+/// Range of the node without its leading trivia
+fn non_trivia_range(node: &SyntaxNode) -> Option<TextRange> {
+    let first = node
+        .children_with_tokens()
+        .find(|element| !element.kind().is_trivia())?;
+
+    Some(TextRange::new(
+        first.text_range().start(),
+        node.text_range().end(),
+    ))
+}
+
+fn line_break(node: &SyntaxNode) -> String {
+    node.children_with_tokens()
+        .find(|element| element.kind() == SyntaxKind::TK_LINE_BREAK)
+        .and_then(SyntaxElement::into_token)
+        .map_or_else(|| "\n".to_string(), |token| token.text().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "imports-at-top",
+            r"{% extends 'base.html.twig' %}
+{% import 'forms.html.twig' as forms %}
+<div></div>
+    {% import 'macros.html.twig' as macros %}",
+            expect![[r"
+                help[imports-at-top]: Macro imports should be at the top of the file
+                  ┌─ ./debug-rule.html.twig:4:5
+                  │  
+                2 │   {% import 'forms.html.twig' as forms %}
+                  │                                          - Move the imports up: 
+                {% import 'macros.html.twig' as macros %}
+                3 │   <div></div>
+                  │ ╭───────────'
+                4 │ │     {% import 'macros.html.twig' as macros %}
+                  │ │     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ move this import above the other content
+                  │ ╰─────────────────────────────────────────────' Remove it here: 
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "imports-at-top",
+            r"{% extends 'base.html.twig' %}
+{# macros #}
+{% import 'forms.html.twig' as forms %}
+{% from 'macros.html.twig' import input as field %}
+<div>{{ forms.input('name') }}</div>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_ignored_import() {
+        test_rule(
+            "imports-at-top",
+            r"{% block content %}{% endblock %}
+{# ludtwig-ignore imports-at-top #}
+{% import 'forms.html.twig' as forms %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "imports-at-top",
+            r"{% extends 'base.html.twig' %}
+{% block content %}{% endblock %}
+{% import 'forms.html.twig' as forms %}
+{% from 'macros.html.twig' import input as field %}",
+            expect![[r"
+                {% extends 'base.html.twig' %}
+                {% import 'forms.html.twig' as forms %}
+                {% from 'macros.html.twig' import input as field %}
+                {% block content %}{% endblock %}"]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_without_header() {
+        test_rule_fix(
+            "imports-at-top",
+            r"<div></div>
+  {% import 'forms.html.twig' as forms %}",
+            expect![[r"
+                {% import 'forms.html.twig' as forms %}
+                <div></div>"]],
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigExpression, TwigInclude};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Order of the include modifiers which twig expects
+static CANONICAL_ORDER: &[SyntaxKind] = &[
+    T!["ignore missing"],
+    SyntaxKind::TWIG_INCLUDE_WITH,
+    T!["only"],
+];
+
+pub struct RuleIncludeModifierOrder;
+
+impl Rule for RuleIncludeModifierOrder {
+    fn name(&self) -> &'static str {
+        "include-modifier-order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Include modifiers are in the order twig expects"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let include = TwigInclude::cast(node)?;
+        let modifiers: Vec<SyntaxElement> = include
+            .syntax()
+            .children_with_tokens()
+            .filter(|element| CANONICAL_ORDER.contains(&element.kind()))
+            .collect();
+
+        let is_ordered = modifiers
+            .windows(2)
+            .all(|pair| order_index(pair[0].kind()) < order_index(pair[1].kind()));
+        if is_ordered {
+            return None;
+        }
+
+        // replace everything between the template name and the last modifier
+        let template = include
+            .syntax()
+            .children()
+            .find(|n| TwigExpression::can_cast(n.kind()))?;
+        let range = TextRange::new(
+            template.text_range().end(),
+            modifiers.last()?.text_range().end(),
+        );
+
+        let mut sorted_modifiers = modifiers;
+        sorted_modifiers.sort_by_key(|element| order_index(element.kind()));
+        let suggested: String = sorted_modifiers
+            .iter()
+            .map(|element| match element {
+                SyntaxElement::Node(n) => format!(" {}", n.text().to_string().trim()),
+                SyntaxElement::Token(t) => format!(" {}", t.text()),
+            })
+            .collect();
+
+        let result = self
+            .create_result(
+                Severity::Error,
+                "Include modifiers are not in the order twig expects",
+            )
+            .primary_note(range, "twig expects 'ignore missing', 'with', 'only'")
+            .suggestion(range, suggested, "Try this order instead");
+
+        Some(vec![result])
+    }
+}
+
+fn order_index(kind: SyntaxKind) -> usize {
+    CANONICAL_ORDER
+        .iter()
+        .position(|k| *k == kind)
+        .unwrap_or(CANONICAL_ORDER.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "include-modifier-order",
+            "{% include 'header.html' only with {'foo': 'bar'} ignore missing %}",
+            expect![[r"
+                error[include-modifier-order]: Include modifiers are not in the order twig expects
+                  ┌─ ./debug-rule.html.twig:1:25
+                  │
+                1 │ {% include 'header.html' only with {'foo': 'bar'} ignore missing %}
+                  │                         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                  │                         │
+                  │                         twig expects 'ignore missing', 'with', 'only'
+                  │                         Try this order instead:  ignore missing with {'foo': 'bar'} only
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_ordered_modifiers() {
+        test_rule(
+            "include-modifier-order",
+            "{% include 'header.html' ignore missing with {'foo': 'bar'} only %}{% include 'footer.html' only %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "include-modifier-order",
+            "{% include 'header.html' only with {'foo': 'bar'} ignore missing %}",
+            expect!["{% include 'header.html' ignore missing with {'foo': 'bar'} only %}"],
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::cmp::Ordering;
+
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, TwigExpression, TwigIncludeWith, TwigLiteralHash, TwigLiteralHashItems,
+    TwigLiteralHashKey, TwigLiteralHashPair, TwigLiteralString,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleIncludeWithKeyOrder;
+
+impl Rule for RuleIncludeWithKeyOrder {
+    fn name(&self) -> &'static str {
+        "include-with-key-order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keys of the include with hash are sorted"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let with = TwigIncludeWith::cast(node)?;
+        let expression: TwigExpression = support::child(with.syntax())?;
+        let hash: TwigLiteralHash = support::child(expression.syntax())?;
+        let items: TwigLiteralHashItems = support::child(hash.syntax())?;
+
+        // dynamic keys like '(name)' can't be ordered
+        let pairs = support::children::<TwigLiteralHashPair>(items.syntax())
+            .map(|pair| Some((hash_key(&pair)?, pair)))
+            .collect::<Option<Vec<_>>>()?;
+
+        let leading_keys = &ctx.config().rules.include_with_key_order;
+        let compare = |a: &String, b: &String| {
+            let position = |key: &String| leading_keys.iter().position(|k| k == key);
+            match (position(a), position(b)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a.to_lowercase().cmp(&b.to_lowercase()).then(a.cmp(b)),
+            }
+        };
+
+        let is_ordered = pairs
+            .windows(2)
+            .all(|pair| compare(&pair[0].0, &pair[1].0) != Ordering::Greater);
+        if is_ordered {
+            return None;
+        }
+
+        let mut sorted_pairs: Vec<&(String, TwigLiteralHashPair)> = pairs.iter().collect();
+        sorted_pairs.sort_by(|a, b| compare(&a.0, &b.0));
+
+        // keep the separators (and line breaks) between the pairs in place
+        let items_start = items.syntax().text_range().start();
+        let items_text = items.syntax().text().to_string();
+        let text_of = |range: TextRange| items_text[range - items_start].to_owned();
+        let ranges: Vec<TextRange> = pairs
+            .iter()
+            .map(|(_, pair)| trimmed_range(pair.syntax()))
+            .collect();
+        let mut suggested = String::new();
+        for (idx, (_, pair)) in sorted_pairs.iter().enumerate() {
+            if idx > 0 {
+                suggested.push_str(&text_of(TextRange::new(
+                    ranges[idx - 1].end(),
+                    ranges[idx].start(),
+                )));
+            }
+            suggested.push_str(&text_of(trimmed_range(pair.syntax())));
+        }
+
+        let range = TextRange::new(ranges.first()?.start(), ranges.last()?.end());
+        let result = self
+            .create_result(Severity::Help, "With keys are not in order")
+            .primary_note(
+                range,
+                if leading_keys.is_empty() {
+                    "sort these keys alphabetically".to_owned()
+                } else {
+                    format!(
+                        "sort these keys with {} first and the others alphabetically",
+                        leading_keys.join(", ")
+                    )
+                },
+            )
+            .suggestion(range, suggested, "Try this order instead");
+
+        Some(vec![result])
+    }
+}
+
+/// Name of the key without quotes, None for dynamic keys
+fn hash_key(pair: &TwigLiteralHashPair) -> Option<String> {
+    let key: TwigLiteralHashKey = support::child(pair.syntax())?;
+    if support::token(key.syntax(), T!["("]).is_some() {
+        return None;
+    }
+
+    match support::child::<TwigLiteralString>(key.syntax()) {
+        Some(string) => Some(string.get_inner()?.syntax().text().to_string()),
+        None => Some(key.syntax().text().to_string().trim().to_owned()),
+    }
+}
+
+/// Range of the node without leading trivia (which may be nested inside of its children)
+fn trimmed_range(node: &SyntaxNode) -> TextRange {
+    let start = node
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .find(|t| !t.kind().is_trivia())
+        .map_or(node.text_range().start(), |t| t.text_range().start());
+
+    TextRange::new(start, node.text_range().end())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{
+        test_rule, test_rule_fix, test_rule_fix_with_config, test_rule_with_config,
+    };
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "include-with-key-order",
+            "{% include 'a.html' with { c: 3, 'a': 1, b: 2 } %}",
+            expect![[r"
+                help[include-with-key-order]: With keys are not in order
+                  ┌─ ./debug-rule.html.twig:1:28
+                  │
+                1 │ {% include 'a.html' with { c: 3, 'a': 1, b: 2 } %}
+                  │                            ^^^^^^^^^^^^^^^^^^
+                  │                            │
+                  │                            sort these keys alphabetically
+                  │                            Try this order instead: 'a': 1, b: 2, c: 3
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "include-with-key-order",
+            "{% include 'a.html' with { a: 1, B: 2, c: 3 } only %}{% include 'b.html' with { b: 1, (a): 2 } %}{% include 'c.html' with vars %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_configured() {
+        test_rule_with_config(
+            "include-with-key-order",
+            "{% include 'a.html' with { id: 1, a: 2, b: 3 } %}",
+            |config| config.rules.include_with_key_order = vec!["id".to_owned()],
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "include-with-key-order",
+            r"{% embed 'a.html' with {
+    'title': 'Hello',
+    active: true,
+    'id': 1,
+} %}{% endembed %}",
+            expect![[r"
+                {% embed 'a.html' with {
+                    active: true,
+                    'id': 1,
+                    'title': 'Hello',
+                } %}{% endembed %}"]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_configured() {
+        test_rule_fix_with_config(
+            "include-with-key-order",
+            "{% sw_include '@Storefront/a.html.twig' with { b: 1, a: 2, id: 3 } %}",
+            |config| config.rules.include_with_key_order = vec!["id".to_owned()],
+            expect!["{% sw_include '@Storefront/a.html.twig' with { id: 3, a: 2, b: 1 } %}"],
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use ludtwig_parser::syntax::typed::{AstNode, HtmlStartingTag, HtmlTag, LudtwigDirectiveIgnore};
+use ludtwig_parser::syntax::typed::{
+    AstNode, HtmlAttributeList, HtmlStartingTag, HtmlTag, LudtwigDirectiveIgnore,
+};
 use ludtwig_parser::syntax::untyped::{
     PreorderWithTokens, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
     WalkEvent,
@@ -13,6 +15,18 @@ impl Rule for RuleIndentation {
         "indentation"
     }
 
+    fn description(&self) -> &'static str {
+        "Indentation follows the nesting of the syntax tree"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_root(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         // keep track of some state during tree traversal
         let mut line_break_encountered = true;
@@ -32,6 +46,10 @@ impl Rule for RuleIndentation {
                         SyntaxElement::Node(n) if n.kind() == SyntaxKind::ERROR => {
                             tree_iter.skip_subtree(); // Skip everything under error nodes!
                         }
+                        SyntaxElement::Node(n) if n.kind() == SyntaxKind::HTML_STRING => {
+                            // attribute values are checked by the multiline-attribute-value-indent rule
+                            tree_iter.skip_subtree();
+                        }
                         SyntaxElement::Token(t) if t.kind() == SyntaxKind::TK_LINE_BREAK => {
                             line_break_encountered = true;
                         }
@@ -128,13 +146,13 @@ impl RuleIndentation {
     ) -> Vec<CheckResult> {
         let indent_char = ctx.config().format.indentation_mode.corresponding_char();
         let indent_char_count = ctx.config().format.indentation_count;
-        let expected_str = std::iter::repeat(indent_char)
-            .take(indentation_level * indent_char_count as usize)
-            .chain(" ".repeat(indentation_substeps).chars())
-            .collect::<String>();
+        let expected_str =
+            std::iter::repeat_n(indent_char, indentation_level * indent_char_count as usize)
+                .chain(" ".repeat(indentation_substeps).chars())
+                .collect::<String>();
 
         let substeps_expectation_notice = if indentation_substeps > 0 {
-            format!(" (+{} spaces)", indentation_substeps)
+            format!(" (+{indentation_substeps} spaces)")
         } else {
             String::new()
         };
@@ -146,8 +164,18 @@ impl RuleIndentation {
                     let (found_spaces, found_tabs) = get_spaces_and_tabs_count(token.text());
 
                     // report wrong indentation
+                    let message = if found_spaces > 0
+                        && found_tabs > 0
+                        && !(expected_str.contains(' ') && expected_str.contains('\t'))
+                    {
+                        "Mixed tabs and spaces in indentation"
+                    } else if starts_child_of_wrapped_tag(token) {
+                        "Child indentation is not based on the line of the wrapped tag"
+                    } else {
+                        "Wrong indentation"
+                    };
                     let result = self
-                        .create_result( Severity::Help, "Wrong indentation")
+                        .create_result(Severity::Help, message)
                         .primary_note(
                             token.text_range(),
                             format!(
@@ -274,9 +302,8 @@ impl RuleIndentation {
                 | SyntaxKind::TWIG_LITERAL_ARRAY_INNER
                 | SyntaxKind::TWIG_LITERAL_HASH_ITEMS
         ) && (indent_block_children
-            || !n
-                .parent()
-                .map_or(false, |p| p.kind() == SyntaxKind::TWIG_BLOCK))
+            || n.parent()
+                .is_none_or(|p| p.kind() != SyntaxKind::TWIG_BLOCK))
         {
             match walk_mode {
                 WalkMode::Enter => {
@@ -313,6 +340,39 @@ impl RuleIndentation {
     }
 }
 
+/// Is the token the indentation of the first line of a direct child of a tag
+/// with attributes wrapped over multiple lines?
+fn starts_child_of_wrapped_tag(token: &SyntaxToken) -> bool {
+    // the line break and indentation are the leading trivia of the child
+    let Some(child) = token
+        .parent_ancestors()
+        .find(|n| n.parent().is_some_and(|p| p.kind() == SyntaxKind::BODY))
+    else {
+        return false;
+    };
+    if child.first_token() != token.prev_token() {
+        return false;
+    }
+
+    child
+        .parent()
+        .and_then(|body| body.parent())
+        .and_then(HtmlTag::cast)
+        .and_then(|tag| tag.starting_tag())
+        .and_then(|starting_tag| {
+            starting_tag
+                .syntax()
+                .children()
+                .find_map(HtmlAttributeList::cast)
+        })
+        .is_some_and(|attribute_list| {
+            attribute_list
+                .syntax()
+                .descendants_with_tokens()
+                .any(|element| element.kind() == SyntaxKind::TK_LINE_BREAK)
+        })
+}
+
 fn get_spaces_and_tabs_count(input: &str) -> (i32, i32) {
     input.chars().fold((0, 0), |(mut spaces, mut tabs), c| {
         match c {
@@ -335,12 +395,12 @@ mod tests {
     fn rule_reports() {
         test_rule(
             "indentation",
-            r#"{% block outer %}
+            r"{% block outer %}
                 <div>
         inner
                       </div>
-                  {% endblock %}"#,
-            expect![[r#"
+                  {% endblock %}",
+            expect![[r"
                 help[indentation]: Wrong indentation
                   ┌─ ./debug-rule.html.twig:2:1
                   │
@@ -368,7 +428,7 @@ mod tests {
                   │ Found 18 spaces and 0 tabs but expected indentation of 0 spaces here
                   │ Change indentation to 0 spaces: 
 
-            "#]],
+            "]],
         );
     }
 
@@ -376,7 +436,7 @@ mod tests {
     fn rule_does_not_report_trivia_sensitive() {
         test_rule(
             "indentation",
-            r#"<pre>
+            r"<pre>
         hello
         
         
@@ -406,8 +466,8 @@ mod tests {
             </textarea>
     <div>
         wrong
-</div>"#,
-            expect![[r#"
+</div>",
+            expect![[r"
                 help[indentation]: Wrong indentation
                    ┌─ ./debug-rule.html.twig:29:1
                    │
@@ -426,7 +486,7 @@ mod tests {
                    │ Found 8 spaces and 0 tabs but expected indentation of 4 spaces here
                    │ Change indentation to 4 spaces:     
 
-            "#]],
+            "]],
         );
     }
 
@@ -434,17 +494,17 @@ mod tests {
     fn rule_fixes() {
         test_rule_fix(
             "indentation",
-            r#"{% block outer %}
+            r"{% block outer %}
                 <div>
         inner
                       </div>
-                  {% endblock %}"#,
-            expect![[r#"
+                  {% endblock %}",
+            expect![[r"
                 {% block outer %}
                     <div>
                         inner
                     </div>
-                {% endblock %}"#]],
+                {% endblock %}"]],
         );
     }
 
@@ -468,17 +528,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rule_reports_mixed_tabs_and_spaces() {
+        test_rule(
+            "indentation",
+            "<div>\n  \t<p>a</p>\n</div>",
+            expect![[r"
+                help[indentation]: Mixed tabs and spaces in indentation
+                  ┌─ ./debug-rule.html.twig:2:1
+                  │
+                2 │     <p>a</p>
+                  │ ^^^^
+                  │ │
+                  │ Found 2 spaces and 1 tabs but expected indentation of 4 spaces here
+                  │ Change indentation to 4 spaces:     
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_mixed_tabs_and_spaces() {
+        test_rule_fix(
+            "indentation",
+            "<div>\n\t<p>a</p>\n  \t<p>b</p>\n</div>",
+            expect![[r"
+                <div>
+                    <p>a</p>
+                    <p>b</p>
+                </div>"]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_child_of_wrapped_tag() {
+        test_rule(
+            "indentation",
+            r#"<div class="a"
+     id="b">
+     <span>x</span>
+</div>"#,
+            expect![[r"
+                help[indentation]: Child indentation is not based on the line of the wrapped tag
+                  ┌─ ./debug-rule.html.twig:3:1
+                  │
+                3 │      <span>x</span>
+                  │ ^^^^^
+                  │ │
+                  │ Found 5 spaces and 0 tabs but expected indentation of 4 spaces here
+                  │ Change indentation to 4 spaces:     
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_child_of_wrapped_tag() {
+        test_rule_fix(
+            "indentation",
+            r#"<div>
+    <div class="a"
+         id="b">
+             <span>x</span>
+             text
+    </div>
+</div>"#,
+            expect![[r#"
+                <div>
+                    <div class="a"
+                         id="b">
+                        <span>x</span>
+                        text
+                    </div>
+                </div>"#]],
+        );
+    }
+
     #[test]
     fn rule_ignores() {
         test_rule(
             "indentation",
-            r#"{% block outer %}
+            r"{% block outer %}
     {# ludtwig-ignore indentation #}
     <div>
     inner
     </div>
-{% endblock %}"#,
-            expect![[r#""#]],
+{% endblock %}",
+            expect![[r""]],
         );
     }
 }
@@ -0,0 +1,151 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Labelable form control elements
+static FORM_CONTROLS: &[&str] = &[
+    "button", "input", "meter", "output", "progress", "select", "textarea",
+];
+
+pub struct RuleLabelSingleControl;
+
+impl Rule for RuleLabelSingleControl {
+    fn name(&self) -> &'static str {
+        "label-single-control"
+    }
+
+    fn description(&self) -> &'static str {
+        "Labels only wrap a single form control"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let label = HtmlTag::cast(node)?;
+        let label_name = label.name()?;
+        if !label_name.text().eq_ignore_ascii_case("label") {
+            return None;
+        }
+
+        let controls: Vec<HtmlTag> = label
+            .syntax()
+            .descendants()
+            .skip(1)
+            .filter_map(HtmlTag::cast)
+            .filter(is_form_control)
+            .collect();
+        if controls.len() < 2 {
+            return None;
+        }
+
+        // controls inside of twig conditionals may never be rendered together
+        let is_conditional = |control: &HtmlTag| {
+            control
+                .syntax()
+                .ancestors()
+                .take_while(|ancestor| ancestor != label.syntax())
+                .any(|ancestor| ancestor.kind() == SyntaxKind::TWIG_IF)
+        };
+        if controls.iter().any(is_conditional) {
+            return None;
+        }
+
+        let mut result = self
+            .create_result(Severity::Warning, "Label wraps multiple form controls")
+            .primary_note(
+                label_name.text_range(),
+                format!(
+                    "this label contains {} form controls but can only label one",
+                    controls.len()
+                ),
+            );
+        for control in &controls[1..] {
+            if let Some(name) = control.name() {
+                result = result.secondary_note(
+                    name.text_range(),
+                    "move this control out of the label or give it its own label",
+                );
+            }
+        }
+
+        Some(vec![result])
+    }
+}
+
+fn is_form_control(tag: &HtmlTag) -> bool {
+    let Some(name) = tag.name() else {
+        return false;
+    };
+    let name = name.text().to_ascii_lowercase();
+    if !FORM_CONTROLS.contains(&name.as_str()) {
+        return false;
+    }
+
+    // hidden inputs are not labelable
+    name != "input"
+        || !tag.attributes().any(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("type"))
+                && attribute
+                    .value()
+                    .and_then(|v| v.get_inner())
+                    .is_some_and(|inner| {
+                        inner
+                            .syntax()
+                            .text()
+                            .to_string()
+                            .trim()
+                            .eq_ignore_ascii_case("hidden")
+                    })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "label-single-control",
+            r#"<label>
+    Name
+    <input type="text" name="first">
+    <input type="text" name="last">
+</label>"#,
+            expect![[r#"
+                warning[label-single-control]: Label wraps multiple form controls
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <label>
+                  │  ^^^^^ this label contains 2 form controls but can only label one
+                  ·
+                4 │     <input type="text" name="last">
+                  │      ----- move this control out of the label or give it its own label
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "label-single-control",
+            r#"<label>
+    Name
+    <input type="hidden" name="token">
+    <input type="text" name="name">
+</label>
+<label>
+    {% if multiline %}
+        <textarea name="comment"></textarea>
+    {% else %}
+        <input type="text" name="comment">
+    {% endif %}
+</label>"#,
+            expect![""],
+        );
+    }
+}
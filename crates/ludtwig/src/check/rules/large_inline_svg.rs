@@ -0,0 +1,116 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleLargeInlineSvg;
+
+impl Rule for RuleLargeInlineSvg {
+    fn name(&self) -> &'static str {
+        "large-inline-svg"
+    }
+
+    fn description(&self) -> &'static str {
+        "Inline svg elements are not larger than the configured limits"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        if tag_name.text() != "svg" {
+            return None;
+        }
+
+        let opening = support::token(tag.starting_tag()?.syntax(), T!["<"])?;
+        let range = TextRange::new(
+            opening.text_range().start(),
+            tag.syntax().text_range().end(),
+        );
+        let element_count = tag.syntax().descendants().filter_map(HtmlTag::cast).count() - 1;
+        let line_count = tag
+            .syntax()
+            .text()
+            .slice(range - tag.syntax().text_range().start())
+            .to_string()
+            .lines()
+            .count();
+
+        let rules_config = &ctx.config().rules;
+        let max_elements = rules_config.large_inline_svg_max_elements;
+        let max_lines = rules_config.large_inline_svg_max_lines;
+        let message = if element_count > max_elements {
+            format!("this svg contains {element_count} elements (more than {max_elements})")
+        } else if line_count > max_lines {
+            format!("this svg spans {line_count} lines (more than {max_lines})")
+        } else {
+            return None;
+        };
+
+        let result = self
+            .create_result(Severity::Warning, "Large inline svg")
+            .primary_note(tag_name.text_range(), message)
+            .secondary_note(
+                range,
+                "consider moving it into an external file or a sprite",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports_elements() {
+        test_rule_with_config(
+            "large-inline-svg",
+            r#"<svg viewBox="0 0 10 10"><g><path d="M0 0L10 10"/><circle cx="5" cy="5" r="2"></circle></g></svg>"#,
+            |config| config.rules.large_inline_svg_max_elements = 2,
+            expect![[r#"
+                warning[large-inline-svg]: Large inline svg
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <svg viewBox="0 0 10 10"><g><path d="M0 0L10 10"/><circle cx="5" cy="5" r="2"></circle></g></svg>
+                  │ -^^^---------------------------------------------------------------------------------------------
+                  │ ││
+                  │ │this svg contains 3 elements (more than 2)
+                  │ consider moving it into an external file or a sprite
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_lines() {
+        test_rule_with_config(
+            "large-inline-svg",
+            "<div>\n    <svg viewBox=\"0 0 10 10\">\n        <path d=\"M0 0L10 10\"/>\n    </svg>\n</div>",
+            |config| config.rules.large_inline_svg_max_lines = 2,
+            expect![[r#"
+                warning[large-inline-svg]: Large inline svg
+                  ┌─ ./debug-rule.html.twig:2:6
+                  │  
+                2 │ ╭     <svg viewBox="0 0 10 10">
+                  │        ^^^ this svg spans 3 lines (more than 2)
+                3 │ │         <path d="M0 0L10 10"/>
+                4 │ │     </svg>
+                  │ ╰──────────' consider moving it into an external file or a sprite
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "large-inline-svg",
+            "<svg viewBox=\"0 0 10 10\">\n    <path d=\"M0 0L10 10\"/>\n</svg>",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, Body, HtmlTag, TwigFor};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleLoopItemKey;
+
+impl Rule for RuleLoopItemKey {
+    fn name(&self) -> &'static str {
+        "loop-item-key"
+    }
+
+    fn description(&self) -> &'static str {
+        "Elements rendered in a loop have one of the configured key attributes"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_for = TwigFor::cast(node)?;
+        // the first body is the loop body (a second one may follow the else block)
+        let body: Body = support::child(twig_for.syntax())?;
+
+        let key_attributes = &ctx.config().rules.loop_item_key_attributes;
+        if key_attributes.is_empty() {
+            return None;
+        }
+
+        let results: Vec<CheckResult> = support::children::<HtmlTag>(body.syntax())
+            .filter(|tag| {
+                !tag.attributes().any(|attribute| {
+                    attribute.name().is_some_and(|name| {
+                        key_attributes
+                            .iter()
+                            .any(|key| name.text().eq_ignore_ascii_case(key))
+                    })
+                })
+            })
+            .filter_map(|tag| {
+                let name = tag.name()?;
+                Some(
+                    self.create_result(Severity::Warning, "Loop item without key")
+                        .primary_note(
+                            name.text_range(),
+                            format!(
+                                "add one of these attributes to identify the item: {}",
+                                key_attributes.join(", ")
+                            ),
+                        ),
+                )
+            })
+            .collect();
+
+        Some(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "loop-item-key",
+            r"<ul>
+    {% for item in items %}
+        <li>{{ item.label }}</li>
+    {% else %}
+        <li>No items</li>
+    {% endfor %}
+</ul>",
+            expect![[r"
+                warning[loop-item-key]: Loop item without key
+                  ┌─ ./debug-rule.html.twig:3:10
+                  │
+                3 │         <li>{{ item.label }}</li>
+                  │          ^^ add one of these attributes to identify the item: :key, data-key
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "loop-item-key",
+            r#"<ul>
+    {% for item in items %}
+        <li :key="item.id">{{ item.label }}</li>
+        <li data-key="{{ item.id }}-copy">{{ item.label }}</li>
+    {% endfor %}
+</ul>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_with_config() {
+        test_rule_with_config(
+            "loop-item-key",
+            r#"{% for item in items %}<li :key="item.id">{{ item }}</li>{% endfor %}"#,
+            |config| {
+                config.rules.loop_item_key_attributes = vec!["wire:key".to_string()];
+            },
+            expect![[r#"
+                warning[loop-item-key]: Loop item without key
+                  ┌─ ./debug-rule.html.twig:1:25
+                  │
+                1 │ {% for item in items %}<li :key="item.id">{{ item }}</li>{% endfor %}
+                  │                         ^^ add one of these attributes to identify the item: wire:key
+
+            "#]],
+        );
+    }
+}
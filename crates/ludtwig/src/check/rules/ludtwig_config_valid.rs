@@ -0,0 +1,123 @@
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext};
+use crate::Severity;
+use ludtwig_parser::syntax::typed::{AstNode, LudtwigDirectiveConfig};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, TextRange};
+
+pub struct RuleLudtwigConfigValid;
+
+impl Rule for RuleLudtwigConfigValid {
+    fn name(&self) -> &'static str {
+        "ludtwig-config-valid"
+    }
+
+    fn description(&self) -> &'static str {
+        "ludtwig-config directives are on the top level and only contain valid options"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let directive = LudtwigDirectiveConfig::cast(node)?;
+        let parent = directive.syntax().parent()?;
+
+        if parent.kind() != SyntaxKind::ROOT {
+            let result = self.create_result(Severity::Error, "ludtwig-config directive must be on the top level in a file otherwise it is discarded!")
+                .primary_note(directive.syntax().text_range(), "move this to the top level of the file (ideally the first line)");
+
+            return Some(vec![result]);
+        }
+
+        let results: Vec<CheckResult> = directive
+            .get_options()
+            .filter_map(|option| {
+                // missing keys or values are already reported by the parser
+                let key = option.get_key()?;
+                let value = option.get_value()?;
+                let error = ctx
+                    .config()
+                    .with_inline_option(key.text(), value.text())
+                    .err()?;
+
+                Some(
+                    self.create_result(Severity::Error, "Invalid ludtwig-config option")
+                        .primary_note(
+                            TextRange::new(key.text_range().start(), value.text_range().end()),
+                            format!("{error}, it is discarded"),
+                        ),
+                )
+            })
+            .collect();
+
+        if results.is_empty() {
+            None
+        } else {
+            Some(results)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports_unknown_key() {
+        test_rule(
+            "ludtwig-config-valid",
+            "{# ludtwig-config max-line-length=100 #}",
+            expect![[r"
+                error[ludtwig-config-valid]: Invalid ludtwig-config option
+                  ┌─ ./debug-rule.html.twig:1:19
+                  │
+                1 │ {# ludtwig-config max-line-length=100 #}
+                  │                   ^^^^^^^^^^^^^^^^^^^ Unknown config option max-line-length, it is discarded
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_invalid_value() {
+        test_rule(
+            "ludtwig-config-valid",
+            "{# ludtwig-config indentation-count=2, twig-quotation=backtick #}",
+            expect![[r"
+                error[ludtwig-config-valid]: Invalid ludtwig-config option
+                  ┌─ ./debug-rule.html.twig:1:40
+                  │
+                1 │ {# ludtwig-config indentation-count=2, twig-quotation=backtick #}
+                  │                                        ^^^^^^^^^^^^^^^^^^^^^^^ Invalid value backtick for config option twig-quotation, it is discarded
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_valid_options() {
+        test_rule(
+            "ludtwig-config-valid",
+            "{# ludtwig-config indentation-count=2, twig-quotation=single, img-dimensions-require-both=true #}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_not_on_top() {
+        test_rule(
+            "ludtwig-config-valid",
+            "<div>{# ludtwig-config indentation-count=2 #}</div>",
+            expect![[r"
+                error[ludtwig-config-valid]: ludtwig-config directive must be on the top level in a file otherwise it is discarded!
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div>{# ludtwig-config indentation-count=2 #}</div>
+                  │      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ move this to the top level of the file (ideally the first line)
+
+            "]],
+        );
+    }
+}
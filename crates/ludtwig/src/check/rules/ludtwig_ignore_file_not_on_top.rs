@@ -10,6 +10,14 @@ impl Rule for RuleLudtwigIgnoreFileNotOnTop {
         "ludtwig-ignore-file-not-on-top"
     }
 
+    fn description(&self) -> &'static str {
+        "ludtwig-ignore-file directives are on the top level of the file"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
     fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let directive = LudtwigDirectiveFileIgnore::cast(node)?;
         let parent = directive.syntax().parent()?;
@@ -0,0 +1,107 @@
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleMaxTemplateSize;
+
+impl Rule for RuleMaxTemplateSize {
+    fn name(&self) -> &'static str {
+        "max-template-size"
+    }
+
+    fn description(&self) -> &'static str {
+        "Templates are not larger than the configured number of lines and nodes"
+    }
+
+    fn check_root(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let line_count = node.text().to_string().lines().count();
+        let node_count = node.descendants().count() - 1;
+
+        let rules_config = &ctx.config().rules;
+        let max_lines = rules_config.max_template_size_lines;
+        let max_nodes = rules_config.max_template_size_nodes;
+        let message = if line_count > max_lines {
+            format!("this template has {line_count} lines (more than {max_lines})")
+        } else if node_count > max_nodes {
+            format!("this template contains {node_count} syntax nodes (more than {max_nodes})")
+        } else {
+            return None;
+        };
+
+        // the file level warning is reported at the start of the template
+        let first_token = node
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| !t.kind().is_trivia())?;
+
+        let result = self
+            .create_result(Severity::Warning, "Template is too large")
+            .primary_note(first_token.text_range(), message)
+            .secondary_note(
+                first_token.text_range(),
+                "consider extracting parts of it into includes or separate templates",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule_with_config;
+
+    #[test]
+    fn rule_reports_lines() {
+        test_rule_with_config(
+            "max-template-size",
+            "<div>\n    <span>a</span>\n    <span>b</span>\n</div>",
+            |config| config.rules.max_template_size_lines = 3,
+            expect![[r"
+                warning[max-template-size]: Template is too large
+                  ┌─ ./debug-rule.html.twig:1:1
+                  │
+                1 │ <div>
+                  │ ^
+                  │ │
+                  │ this template has 4 lines (more than 3)
+                  │ consider extracting parts of it into includes or separate templates
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_nodes() {
+        test_rule_with_config(
+            "max-template-size",
+            "<ul><li>a</li><li>b</li></ul>",
+            |config| config.rules.max_template_size_nodes = 5,
+            expect![[r"
+                warning[max-template-size]: Template is too large
+                  ┌─ ./debug-rule.html.twig:1:1
+                  │
+                1 │ <ul><li>a</li><li>b</li></ul>
+                  │ ^
+                  │ │
+                  │ this template contains 17 syntax nodes (more than 5)
+                  │ consider extracting parts of it into includes or separate templates
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule_with_config(
+            "max-template-size",
+            "<div>\n    <span>a</span>\n    <span>b</span>\n</div>",
+            |config| {
+                config.rules.max_template_size_lines = 4;
+                config.rules.max_template_size_nodes = 100;
+            },
+            expect![""],
+        );
+    }
+}
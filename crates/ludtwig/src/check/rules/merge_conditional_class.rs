@@ -0,0 +1,187 @@
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, Body, HtmlAttribute, HtmlAttributeList, TwigExpression, TwigIf, TwigIfBlock,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleMergeConditionalClass;
+
+impl Rule for RuleMergeConditionalClass {
+    fn name(&self) -> &'static str {
+        "merge-conditional-class"
+    }
+
+    fn description(&self) -> &'static str {
+        "Static and conditional class attributes on the same element are merged"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute_list = HtmlAttributeList::cast(node)?;
+        let static_classes: Vec<HtmlAttribute> = support::children(attribute_list.syntax())
+            .filter(is_class_attribute)
+            .collect();
+        if static_classes.is_empty() {
+            return None;
+        }
+
+        let results = support::children::<TwigIf>(attribute_list.syntax())
+            .filter_map(|twig_if| {
+                let conditional_classes: Vec<HtmlAttribute> = twig_if
+                    .syntax()
+                    .descendants()
+                    .filter_map(HtmlAttribute::cast)
+                    .filter(is_class_attribute)
+                    .collect();
+                let first_conditional_class = conditional_classes.first()?;
+
+                let mut result = self
+                    .create_result(
+                        Severity::Warning,
+                        "Static class attribute and conditional class attribute on the same element",
+                    )
+                    .primary_note(
+                        first_conditional_class.name()?.text_range(),
+                        "merge this into the static class attribute",
+                    );
+
+                // best effort fix for the simple case of a single static class and
+                // a twig if (without any else branches) containing only the class attribute
+                if static_classes.len() == 1 && conditional_classes.len() == 1 {
+                    if let Some(merged) = try_merge(&static_classes[0], &twig_if) {
+                        result = result
+                            .suggestion(
+                                static_classes[0].value()?.get_inner()?.syntax().text_range(),
+                                merged,
+                                "Merge the conditional class into this",
+                            )
+                            .suggestion(
+                                twig_if.syntax().text_range(),
+                                "",
+                                "Remove the conditional class attribute",
+                            );
+                    }
+                }
+
+                Some(result)
+            })
+            .collect::<Vec<_>>();
+
+        if results.is_empty() {
+            None
+        } else {
+            Some(results)
+        }
+    }
+}
+
+fn is_class_attribute(attribute: &HtmlAttribute) -> bool {
+    attribute
+        .name()
+        .is_some_and(|name| name.text().eq_ignore_ascii_case("class"))
+}
+
+/// Create the inner value of the merged class attribute (None if the twig if is too complex)
+fn try_merge(static_class: &HtmlAttribute, twig_if: &TwigIf) -> Option<String> {
+    if twig_if.syntax().children().any(|c| {
+        matches!(
+            c.kind(),
+            SyntaxKind::TWIG_ELSE_IF_BLOCK | SyntaxKind::TWIG_ELSE_BLOCK
+        )
+    }) {
+        return None;
+    }
+
+    let body: Body = support::child(twig_if.syntax())?;
+    let mut body_children = body.syntax().children();
+    let conditional_class = HtmlAttribute::cast(body_children.next()?)?;
+    if body_children.next().is_some() {
+        return None;
+    }
+
+    let condition: TwigExpression =
+        support::child(support::child::<TwigIfBlock>(twig_if.syntax())?.syntax())?;
+    let static_inner = static_class
+        .value()?
+        .get_inner()?
+        .syntax()
+        .text()
+        .to_string();
+    let conditional_inner = conditional_class
+        .value()?
+        .get_inner()?
+        .syntax()
+        .text()
+        .to_string();
+
+    Some(format!(
+        "{static_inner}{{% if {} %}} {}{{% endif %}}",
+        condition.syntax().text().to_string().trim(),
+        conditional_inner.trim()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "merge-conditional-class",
+            r#"<div class="a" {% if x %}class="b c"{% endif %}></div>"#,
+            expect![[r#"
+                warning[merge-conditional-class]: Static class attribute and conditional class attribute on the same element
+                  ┌─ ./debug-rule.html.twig:1:26
+                  │
+                1 │ <div class="a" {% if x %}class="b c"{% endif %}></div>
+                  │             - -----------^^^^^-----------------
+                  │             │ │          │
+                  │             │ │          merge this into the static class attribute
+                  │             │ Remove the conditional class attribute: 
+                  │             Merge the conditional class into this: a{% if x %} b c{% endif %}
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_without_fix_for_else_branch() {
+        test_rule(
+            "merge-conditional-class",
+            r#"<div class="a" {% if x %}class="b"{% else %}class="c"{% endif %}></div>"#,
+            expect![[r#"
+                warning[merge-conditional-class]: Static class attribute and conditional class attribute on the same element
+                  ┌─ ./debug-rule.html.twig:1:26
+                  │
+                1 │ <div class="a" {% if x %}class="b"{% else %}class="c"{% endif %}></div>
+                  │                          ^^^^^ merge this into the static class attribute
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_single_class() {
+        test_rule(
+            "merge-conditional-class",
+            r#"<div {% if x %}class="b"{% endif %}></div><div class="a" {% if x %}id="b"{% endif %}></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "merge-conditional-class",
+            r#"<div class="a" {% if x %}class="b c"{% endif %}></div>"#,
+            expect![[r#"<div class="a{% if x %} b c{% endif %}"></div>"#]],
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlEndingTag, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxToken, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleMisnestedTags;
+
+impl Rule for RuleMisnestedTags {
+    fn name(&self) -> &'static str {
+        "misnested-tags"
+    }
+
+    fn description(&self) -> &'static str {
+        "Html elements are closed in the order they were opened"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+
+        // the parser leaves the ending tag empty if an outer element was closed first
+        let ending_tag = tag.ending_tag()?;
+        if ending_tag.syntax().first_token().is_some() {
+            return None;
+        }
+
+        // the next token must be the ending tag of an element which contains this one
+        // (rowan token navigation stops at the empty ending tag node, so look it up by offset)
+        let root = tag.syntax().ancestors().last()?;
+        let next_token = root
+            .token_at_offset(tag.syntax().text_range().end())
+            .right_biased()?;
+        let next_token = next_non_trivia_token(next_token)?;
+        if next_token.kind() != T!["</"] {
+            return None;
+        }
+        let outer_ending_tag = next_token.parent().and_then(HtmlEndingTag::cast)?;
+        let outer_tag = outer_ending_tag.html_tag()?;
+        if !tag
+            .syntax()
+            .ancestors()
+            .any(|ancestor| &ancestor == outer_tag.syntax())
+        {
+            return None;
+        }
+        let outer_tag_name = outer_tag.name()?;
+
+        let outer_ending_range = TextRange::new(
+            next_token.text_range().start(),
+            outer_ending_tag.syntax().text_range().end(),
+        );
+
+        let result = self
+            .create_result(Severity::Error, "HTML elements closed out of order")
+            .primary_note(
+                tag_name.text_range(),
+                format!("<{}> is still open here", tag_name.text()),
+            )
+            .secondary_note(
+                outer_ending_range,
+                format!(
+                    "</{}> closes the outer element before </{}>",
+                    outer_tag_name.text(),
+                    tag_name.text()
+                ),
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// The token itself or the next token which is not trivia
+fn next_non_trivia_token(token: SyntaxToken) -> Option<SyntaxToken> {
+    let mut next = Some(token);
+    while let Some(t) = next {
+        if !t.kind().is_trivia() {
+            return Some(t);
+        }
+        next = t.next_token();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "misnested-tags",
+            "<div><b><i>text</b></i></div>",
+            expect![[r"
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:1:16
+                  │
+                1 │ <div><b><i>text</b></i></div>
+                  │                ^^ expected </i> ending tag but found </
+
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:1:20
+                  │
+                1 │ <div><b><i>text</b></i></div>
+                  │                    ^^ expected </div> ending tag but found </
+
+                error[misnested-tags]: HTML elements closed out of order
+                  ┌─ ./debug-rule.html.twig:1:10
+                  │
+                1 │ <div><b><i>text</b></i></div>
+                  │          ^     ---- </b> closes the outer element before </i>
+                  │          │      
+                  │          <i> is still open here
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_correct_nesting() {
+        test_rule(
+            "misnested-tags",
+            "<div><b><i>text</i></b><br></div>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_multiline() {
+        test_rule(
+            "misnested-tags",
+            "<div>\n    <span>\n        hello\n</div>\n</span>",
+            expect![[r"
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:4:1
+                  │
+                4 │ </div>
+                  │ ^^ expected </span> ending tag but found </
+
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:5:1
+                  │
+                5 │ </span>
+                  │ ^^ expected html, text or twig element but found </
+
+                error[misnested-tags]: HTML elements closed out of order
+                  ┌─ ./debug-rule.html.twig:2:6
+                  │
+                2 │     <span>
+                  │      ^^^^ <span> is still open here
+                3 │         hello
+                4 │ </div>
+                  │ ------ </div> closes the outer element before </span>
+
+            "]],
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{
+    SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, TextRange,
+};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleMultilineAttributeValueIndent;
+
+impl Rule for RuleMultilineAttributeValueIndent {
+    fn name(&self) -> &'static str {
+        "multiline-attribute-value-indent"
+    }
+
+    fn description(&self) -> &'static str {
+        "Continuation lines of multiline attribute values are aligned"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let name = attribute.name()?;
+        let value = attribute.value()?;
+        let value = value.syntax();
+        if !value.text().contains_char('\n')
+            || value.descendants().any(|n| n.kind() == SyntaxKind::ERROR)
+        {
+            return None;
+        }
+
+        // continuation lines are aligned with the attribute name and indented for nested twig syntax
+        let base = alignment_of(&name);
+        let format = &ctx.config().format;
+        let step = format
+            .indentation_mode
+            .corresponding_char()
+            .to_string()
+            .repeat(format.indentation_count as usize);
+
+        let mut results = vec![];
+        let line_breaks = value
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|t| t.kind() == T![lb]);
+        for line_break in line_breaks {
+            let Some(first) = line_break.next_token() else {
+                continue;
+            };
+            let (whitespace, content) = if first.kind() == T![ws] {
+                (Some(first.clone()), first.next_token())
+            } else {
+                (None, Some(first))
+            };
+            // empty lines and the end of the value don't need indentation
+            let Some(content) = content
+                .filter(|t| t.kind() != T![lb] && t.text_range().end() <= value.text_range().end())
+            else {
+                continue;
+            };
+
+            let depth = nesting_depth(&content, value, format.indent_children_of_blocks);
+            let expected = format!("{base}{}", step.repeat(depth));
+            let (range, actual) = match &whitespace {
+                Some(ws) => (ws.text_range(), ws.text()),
+                None => (TextRange::empty(content.text_range().start()), ""),
+            };
+            if actual == expected {
+                continue;
+            }
+
+            results.push(
+                self.create_result(Severity::Help, "Wrong indentation in attribute value")
+                    .primary_note(
+                        range,
+                        format!(
+                            "continuation lines of '{}' should be aligned with the attribute name{}",
+                            name.text(),
+                            if depth > 0 { " (plus nested twig indentation)" } else { "" }
+                        ),
+                    )
+                    .suggestion(range, expected, "Change indentation"),
+            );
+        }
+
+        Some(results)
+    }
+}
+
+/// Whitespace which aligns a line with the start of the token
+fn alignment_of(token: &SyntaxToken) -> String {
+    let mut line_prefix = String::new();
+    let mut previous = token.prev_token();
+    while let Some(t) = previous {
+        if t.kind() == T![lb] {
+            break;
+        }
+        line_prefix.insert_str(0, t.text());
+        previous = t.prev_token();
+    }
+
+    // keep the leading indentation (which may contain tabs) and align the rest with spaces
+    let indentation: String = line_prefix
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    let alignment = line_prefix.chars().count() - indentation.chars().count();
+
+    format!("{indentation}{}", " ".repeat(alignment))
+}
+
+/// Count the twig nodes inside the value which indent their children (same as the indentation rule)
+fn nesting_depth(token: &SyntaxToken, value: &SyntaxNode, indent_block_children: bool) -> usize {
+    token
+        .parent_ancestors()
+        .take_while(|n| n != value)
+        .filter(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::BODY
+                    | SyntaxKind::TWIG_ARGUMENTS
+                    | SyntaxKind::TWIG_LITERAL_ARRAY_INNER
+                    | SyntaxKind::TWIG_LITERAL_HASH_ITEMS
+            ) && (indent_block_children
+                || n.parent()
+                    .is_none_or(|p| p.kind() != SyntaxKind::TWIG_BLOCK))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "multiline-attribute-value-indent",
+            r#"<div>
+    <div class="card
+      {% if active %}
+   is-active
+            {% endif %}"></div>
+</div>"#,
+            expect![[r#"
+                help[multiline-attribute-value-indent]: Wrong indentation in attribute value
+                  ┌─ ./debug-rule.html.twig:3:1
+                  │
+                3 │       {% if active %}
+                  │ ^^^^^^
+                  │ │
+                  │ continuation lines of 'class' should be aligned with the attribute name
+                  │ Change indentation:          
+
+                help[multiline-attribute-value-indent]: Wrong indentation in attribute value
+                  ┌─ ./debug-rule.html.twig:4:1
+                  │
+                4 │    is-active
+                  │ ^^^
+                  │ │
+                  │ continuation lines of 'class' should be aligned with the attribute name (plus nested twig indentation)
+                  │ Change indentation:              
+
+                help[multiline-attribute-value-indent]: Wrong indentation in attribute value
+                  ┌─ ./debug-rule.html.twig:5:1
+                  │
+                5 │             {% endif %}"></div>
+                  │ ^^^^^^^^^^^^
+                  │ │
+                  │ continuation lines of 'class' should be aligned with the attribute name
+                  │ Change indentation:          
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "multiline-attribute-value-indent",
+            r#"<div>
+    <div id="card"
+         class="card
+         {% if active %}
+             is-active
+         {% endif %}"
+         style="color: red;
+         font-weight: bold"></div>
+</div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "multiline-attribute-value-indent",
+            r#"<div>
+    <div class="card
+      {% if active %}
+   is-active
+            {% endif %}"></div>
+</div>"#,
+            expect![[r#"
+                <div>
+                    <div class="card
+                         {% if active %}
+                             is-active
+                         {% endif %}"></div>
+                </div>"#]],
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoAccessKey;
+
+impl Rule for RuleNoAccessKey {
+    fn name(&self) -> &'static str {
+        "no-access-key"
+    }
+
+    fn description(&self) -> &'static str {
+        "The accesskey attribute is not used"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        if !attribute_name.text().eq_ignore_ascii_case("accesskey") {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Usage of accesskey attribute")
+            .primary_note(
+                attribute_name.text_range(),
+                "access keys often conflict with shortcuts of the browser or assistive technology",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-access-key",
+            r#"<button accesskey="s">Save</button>"#,
+            expect![[r#"
+                warning[no-access-key]: Usage of accesskey attribute
+                  ┌─ ./debug-rule.html.twig:1:9
+                  │
+                1 │ <button accesskey="s">Save</button>
+                  │         ^^^^^^^^^ access keys often conflict with shortcuts of the browser or assistive technology
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-access-key",
+            r#"<button class="accesskey" data-accesskey="s">Save</button>"#,
+            expect![""],
+        );
+    }
+}
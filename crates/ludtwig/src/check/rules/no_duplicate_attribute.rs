@@ -0,0 +1,88 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlStartingTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxToken};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoDuplicateAttribute;
+
+impl Rule for RuleNoDuplicateAttribute {
+    fn name(&self) -> &'static str {
+        "no-duplicate-attribute"
+    }
+
+    fn description(&self) -> &'static str {
+        "Html attributes are not defined multiple times on the same element"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let starting_tag = HtmlStartingTag::cast(node)?;
+
+        // only unconditional attributes (twig blocks like if / else may set the same attribute)
+        let mut seen: Vec<SyntaxToken> = vec![];
+        let mut results = vec![];
+        for name in starting_tag.attributes().filter_map(|a| a.name()) {
+            let first = seen
+                .iter()
+                .find(|other| other.text().eq_ignore_ascii_case(name.text()));
+            match first {
+                Some(first) => results.push(
+                    self.create_result(Severity::Error, "Duplicate html attribute")
+                        .primary_note(
+                            name.text_range(),
+                            format!("'{}' is already defined on this tag", name.text()),
+                        )
+                        .secondary_note(first.text_range(), "first defined here"),
+                ),
+                None => seen.push(name),
+            }
+        }
+
+        Some(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-duplicate-attribute",
+            r#"<div class="a" id="b" class="c" CLASS="d"></div>"#,
+            expect![[r#"
+                error[no-duplicate-attribute]: Duplicate html attribute
+                  ┌─ ./debug-rule.html.twig:1:23
+                  │
+                1 │ <div class="a" id="b" class="c" CLASS="d"></div>
+                  │      -----            ^^^^^ 'class' is already defined on this tag
+                  │      │                 
+                  │      first defined here
+
+                error[no-duplicate-attribute]: Duplicate html attribute
+                  ┌─ ./debug-rule.html.twig:1:33
+                  │
+                1 │ <div class="a" id="b" class="c" CLASS="d"></div>
+                  │      -----                      ^^^^^ 'CLASS' is already defined on this tag
+                  │      │                           
+                  │      first defined here
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-duplicate-attribute",
+            r#"<div class="a" data-class="b" {% if active %}id="c"{% else %}id="d"{% endif %}></div>"#,
+            expect![""],
+        );
+    }
+}
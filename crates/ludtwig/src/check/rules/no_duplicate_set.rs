@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, TwigAssignment, TwigLiteralName, TwigSet, TwigSetBlock,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxToken};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoDuplicateSet;
+
+impl Rule for RuleNoDuplicateSet {
+    fn name(&self) -> &'static str {
+        "no-duplicate-set"
+    }
+
+    fn description(&self) -> &'static str {
+        "Variables are not set twice in the same scope before their value is used"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if !matches!(node.kind(), SyntaxKind::BODY | SyntaxKind::ROOT) {
+            return None;
+        }
+
+        let ignored_variables = &ctx.config().rules.no_duplicate_set_ignored_variables;
+        let mut results = vec![];
+        // variables which were set in this scope, but not used since then
+        let mut unused_sets: HashMap<String, SyntaxToken> = HashMap::new();
+        for child in node.children() {
+            // the variables may or may not be set again inside of a conditional
+            if matches!(child.kind(), SyntaxKind::TWIG_IF | SyntaxKind::TWIG_FOR) {
+                unused_sets.clear();
+                continue;
+            }
+
+            let assigned_names = TwigSet::cast(child.clone())
+                .map(|twig_set| assigned_names(&twig_set))
+                .unwrap_or_default();
+
+            // a value like 'list|merge([item])' uses the previous value
+            for name in used_names(&child) {
+                if !assigned_names
+                    .iter()
+                    .any(|t| t.text_range() == name.text_range())
+                {
+                    unused_sets.remove(name.text());
+                }
+            }
+
+            for name in assigned_names {
+                if ignored_variables.iter().any(|v| v == name.text()) {
+                    continue;
+                }
+
+                if let Some(previous) = unused_sets.insert(name.text().to_owned(), name.clone()) {
+                    let result = self
+                        .create_result(Severity::Warning, "Variable is set twice in the same scope")
+                        .primary_note(
+                            name.text_range(),
+                            format!(
+                                "'{}' is set again before its previous value was used",
+                                name.text()
+                            ),
+                        )
+                        .secondary_note(previous.text_range(), "previously set here");
+                    results.push(result);
+                }
+            }
+        }
+
+        Some(results)
+    }
+}
+
+/// Name tokens on the left side of the set
+fn assigned_names(twig_set: &TwigSet) -> Vec<SyntaxToken> {
+    support::child::<TwigSetBlock>(twig_set.syntax())
+        .and_then(|set_block| support::child::<TwigAssignment>(set_block.syntax()))
+        .map(|assignment| {
+            support::children::<TwigLiteralName>(assignment.syntax())
+                .filter_map(|name| support::token(name.syntax(), T![word]))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// All name tokens inside of the node (which may be variable usages)
+fn used_names(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    node.descendants()
+        .filter_map(TwigLiteralName::cast)
+        .filter_map(|name| support::token(name.syntax(), T![word]))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-duplicate-set",
+            r"{% set title = 'Hello' %}
+<h1>Headline</h1>
+{% set title = 'World' %}
+{{ title }}",
+            expect![[r"
+                warning[no-duplicate-set]: Variable is set twice in the same scope
+                  ┌─ ./debug-rule.html.twig:3:8
+                  │
+                1 │ {% set title = 'Hello' %}
+                  │        ----- previously set here
+                2 │ <h1>Headline</h1>
+                3 │ {% set title = 'World' %}
+                  │        ^^^^^ 'title' is set again before its previous value was used
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-duplicate-set",
+            r"{% set title = 'Hello' %}
+<h1>{{ title }}</h1>
+{% set title = 'World' %}
+{% set classes = ['a'] %}
+{% set classes = classes|merge(['b']) %}
+{% set size = 'small' %}
+{% if large %}
+    {% set size = 'large' %}
+{% endif %}
+{% set size = size ~ '-text' %}
+{% block content %}
+    {% set title = 'Content' %}
+{% endblock %}
+{{ title }}{{ classes|join(' ') }}{{ size }}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_ignored_variables() {
+        test_rule_with_config(
+            "no-duplicate-set",
+            "{% set label = 'a' %}{% set label = 'b' %}{{ label }}",
+            |config| config.rules.no_duplicate_set_ignored_variables = vec!["label".to_owned()],
+            expect![""],
+        );
+    }
+}
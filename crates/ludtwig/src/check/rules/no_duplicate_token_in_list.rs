@@ -0,0 +1,166 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange, TextSize};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Attributes which hold a list of space separated tokens
+static TOKEN_LIST_ATTRIBUTES: &[&str] = &[
+    "class",
+    "rel",
+    "rev",
+    "headers",
+    "sandbox",
+    "part",
+    "blocking",
+    "itemprop",
+    "itemref",
+    "itemtype",
+    "aria-describedby",
+    "aria-labelledby",
+    "aria-controls",
+    "aria-owns",
+    "aria-flowto",
+];
+
+pub struct RuleNoDuplicateTokenInList;
+
+impl Rule for RuleNoDuplicateTokenInList {
+    fn name(&self) -> &'static str {
+        "no-duplicate-token-in-list"
+    }
+
+    fn description(&self) -> &'static str {
+        "Token list attributes (like class) don't contain the same token twice"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        if !TOKEN_LIST_ATTRIBUTES
+            .iter()
+            .any(|name| attribute_name.text().eq_ignore_ascii_case(name))
+        {
+            return None;
+        }
+
+        // dynamic values (containing twig syntax) are skipped
+        let inner = attribute.value()?.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None;
+        }
+        let value = inner.syntax().text().to_string();
+        let value_start = inner.syntax().text_range().start();
+
+        let mut results = vec![];
+        let mut seen: Vec<&str> = vec![];
+        let mut previous_end = 0;
+        for (start, token) in split_tokens(&value) {
+            let end = start + token.len();
+            if seen.contains(&token) {
+                let token_range = TextRange::new(
+                    value_start + TextSize::try_from(start).unwrap(),
+                    value_start + TextSize::try_from(end).unwrap(),
+                );
+                // remove the duplicate together with the whitespace in front of it
+                let removal_range = TextRange::new(
+                    value_start + TextSize::try_from(previous_end).unwrap(),
+                    token_range.end(),
+                );
+
+                results.push(
+                    self.create_result(Severity::Help, "Duplicate token in attribute value")
+                        .primary_note(
+                            token_range,
+                            format!(
+                                "'{token}' is already part of this {} list",
+                                attribute_name.text()
+                            ),
+                        )
+                        .suggestion(removal_range, "", "Remove the duplicate"),
+                );
+            } else {
+                seen.push(token);
+            }
+            previous_end = end;
+        }
+
+        Some(results)
+    }
+}
+
+/// Space separated tokens and their byte offsets in the value
+fn split_tokens(value: &str) -> impl Iterator<Item = (usize, &str)> {
+    value
+        .split(|c: char| c.is_ascii_whitespace())
+        .scan(0, |offset, token| {
+            let start = *offset;
+            *offset += token.len() + 1;
+            Some((start, token))
+        })
+        .filter(|(_, token)| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-duplicate-token-in-list",
+            r#"<a href="/" rel="noopener noopener">link</a>"#,
+            expect![[r#"
+                help[no-duplicate-token-in-list]: Duplicate token in attribute value
+                  ┌─ ./debug-rule.html.twig:1:27
+                  │
+                1 │ <a href="/" rel="noopener noopener">link</a>
+                  │                          -^^^^^^^^
+                  │                          ││
+                  │                          │'noopener' is already part of this rel list
+                  │                          Remove the duplicate: 
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-duplicate-token-in-list",
+            r#"<a href="/" class="btn btn-primary" rel="noopener noreferrer" data-list="a a">link</a>
+<div class="a {% if active %}a{% endif %}"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "no-duplicate-token-in-list",
+            r#"<a href="/" rel="noopener noopener">link</a>"#,
+            expect![[r#"<a href="/" rel="noopener">link</a>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_multiple_duplicates() {
+        test_rule_fix(
+            "no-duplicate-token-in-list",
+            r#"<div class="a  b a
+    c b"></div>"#,
+            expect![[r#"
+                <div class="a  b
+                    c"></div>"#]],
+        );
+    }
+}
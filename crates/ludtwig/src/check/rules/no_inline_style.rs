@@ -0,0 +1,89 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoInlineStyle;
+
+impl Rule for RuleNoInlineStyle {
+    fn name(&self) -> &'static str {
+        "no-inline-style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Style attributes are not used outside of the configured tags"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        if !attribute.name()?.text().eq_ignore_ascii_case("style") {
+            return None;
+        }
+
+        let tag_name = attribute
+            .syntax()
+            .ancestors()
+            .find_map(HtmlTag::cast)?
+            .name()?;
+        if ctx
+            .config()
+            .rules
+            .no_inline_style_allowed_tags
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(tag_name.text()))
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Inline style attribute")
+            .primary_note(
+                attribute.syntax().text_range_trimmed_trivia(),
+                "help: move these styles into a css class",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-inline-style",
+            r#"<div class="a" style="color: red">a</div>"#,
+            expect![[r#"
+                warning[no-inline-style]: Inline style attribute
+                  ┌─ ./debug-rule.html.twig:1:16
+                  │
+                1 │ <div class="a" style="color: red">a</div>
+                  │                ^^^^^^^^^^^^^^^^^^ help: move these styles into a css class
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-inline-style",
+            r#"<div class="style" data-style="a">a</div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_allowed_tags() {
+        test_rule_with_config(
+            "no-inline-style",
+            r#"<table style="width: 100%"><tr><td>a</td></tr></table>"#,
+            |config| config.rules.no_inline_style_allowed_tags = vec!["table".to_owned()],
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigLiteralName};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoInternalTwigVariables;
+
+impl Rule for RuleNoInternalTwigVariables {
+    fn name(&self) -> &'static str {
+        "no-internal-twig-variables"
+    }
+
+    fn description(&self) -> &'static str {
+        "Internal twig variables (like _context) are not used"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let literal_name = TwigLiteralName::cast(node)?;
+        let name = support::token(literal_name.syntax(), T![word])?;
+        if !ctx
+            .config()
+            .rules
+            .internal_twig_variables
+            .iter()
+            .any(|variable| variable == name.text())
+        {
+            return None;
+        }
+
+        // property access like `foo._self` doesn't reference the variable
+        let operand = literal_name.syntax().parent()?;
+        let is_property = operand.kind() == SyntaxKind::TWIG_OPERAND
+            && operand
+                .prev_sibling_or_token()
+                .is_some_and(|sibling| sibling.kind() == T!["."]);
+        // importing the macros of the same template is the intended usage of `_self`
+        let is_import = literal_name
+            .syntax()
+            .ancestors()
+            .any(|n| matches!(n.kind(), SyntaxKind::TWIG_IMPORT | SyntaxKind::TWIG_FROM));
+        if is_property || is_import {
+            return None;
+        }
+
+        let explanation = match name.text() {
+            "_self" => "it references the current template",
+            "_context" => "it exposes every variable of the current context",
+            "_charset" => "it holds the charset of the environment",
+            _ => "it is reserved by twig",
+        };
+        let result = self
+            .create_result(Severity::Warning, "Usage of internal twig variable")
+            .primary_note(
+                name.text_range(),
+                format!(
+                    "avoid '{}' because {explanation}, pass the needed values explicitly instead",
+                    name.text()
+                ),
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-internal-twig-variables",
+            "{{ _context.foo }}",
+            expect![[r"
+                warning[no-internal-twig-variables]: Usage of internal twig variable
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ _context.foo }}
+                  │    ^^^^^^^^ avoid '_context' because it exposes every variable of the current context, pass the needed values explicitly instead
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-internal-twig-variables",
+            "{% import _self as macros %}{% from _self import input %}{{ product._self }}{{ context.foo }}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_variables() {
+        test_rule_with_config(
+            "no-internal-twig-variables",
+            "{{ _charset }}{{ _internal }}",
+            |config| config.rules.internal_twig_variables = vec!["_internal".to_string()],
+            expect![[r"
+                warning[no-internal-twig-variables]: Usage of internal twig variable
+                  ┌─ ./debug-rule.html.twig:1:18
+                  │
+                1 │ {{ _charset }}{{ _internal }}
+                  │                  ^^^^^^^^^ avoid '_internal' because it is reserved by twig, pass the needed values explicitly instead
+
+            "]],
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNoNestedForm;
+
+impl Rule for RuleNoNestedForm {
+    fn name(&self) -> &'static str {
+        "no-nested-form"
+    }
+
+    fn description(&self) -> &'static str {
+        "Forms are not nested inside of other forms"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let form = HtmlTag::cast(node)?;
+        let form_name = form.name()?;
+        if !is_form(&form) {
+            return None;
+        }
+
+        let outer_form = form
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(HtmlTag::cast)
+            .find(is_form)?;
+
+        let mut result = self
+            .create_result(Severity::Error, "Nested form")
+            .primary_note(
+                form_name.text_range(),
+                "forms can't be nested inside of other forms",
+            );
+        if let Some(outer_name) = outer_form.name() {
+            result = result.secondary_note(outer_name.text_range(), "inside of this form");
+        }
+
+        Some(vec![result])
+    }
+}
+
+fn is_form(tag: &HtmlTag) -> bool {
+    tag.name()
+        .is_some_and(|name| name.text().eq_ignore_ascii_case("form"))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-nested-form",
+            r#"<form action="/search">
+    <div>
+        <form action="/newsletter"></form>
+    </div>
+</form>"#,
+            expect![[r#"
+                error[no-nested-form]: Nested form
+                  ┌─ ./debug-rule.html.twig:3:10
+                  │
+                1 │ <form action="/search">
+                  │  ---- inside of this form
+                2 │     <div>
+                3 │         <form action="/newsletter"></form>
+                  │          ^^^^ forms can't be nested inside of other forms
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "no-nested-form",
+            r#"<form action="/search"></form>
+<form action="/newsletter"></form>"#,
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxToken, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::line_indentation;
+
+pub struct RuleNoTrailingContentAfterClose;
+
+impl Rule for RuleNoTrailingContentAfterClose {
+    fn name(&self) -> &'static str {
+        "no-trailing-content-after-close"
+    }
+
+    fn description(&self) -> &'static str {
+        "The configured closing tags are not followed by content on the same line"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_token(&self, token: SyntaxToken, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if token.kind() != T!["%}"] || ctx.traversal_ctx().inside_trivia_sensitive_node {
+            return None;
+        }
+
+        // closing tags inside html tags (like attribute values) are inline by nature
+        let tag = token.parent()?;
+        if tag
+            .ancestors()
+            .any(|a| a.kind() == SyntaxKind::HTML_STARTING_TAG)
+        {
+            return None;
+        }
+
+        // find the keyword of this twig tag and check if it is a configured closer
+        let mut tag_tokens = tag
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|t| !t.kind().is_trivia());
+        let opening = tag_tokens.next()?;
+        let keyword = tag_tokens.next()?;
+        if opening.kind() != T!["{%"]
+            || !ctx
+                .config()
+                .rules
+                .no_trailing_content_after_close_tags
+                .iter()
+                .any(|closer| closer == keyword.text())
+        {
+            return None;
+        }
+
+        // only closers which start their own line are block-level constructs
+        let indentation = line_indentation(&opening)?;
+
+        // look for content behind the closer on the same line
+        let mut trailing = token.next_token();
+        while let Some(t) = trailing.as_ref().filter(|t| t.kind() == T![ws]) {
+            trailing = t.next_token();
+        }
+        let trailing = trailing.filter(|t| t.kind() != T![lb])?;
+
+        // the trailing content spans until the end of the line
+        let mut trailing_end = trailing.clone();
+        let mut next = trailing.next_token();
+        while let Some(t) = next.filter(|t| t.kind() != T![lb]) {
+            if t.kind() != T![ws] {
+                trailing_end = t.clone();
+            }
+            next = t.next_token();
+        }
+        let trailing_range = TextRange::new(
+            trailing.text_range().start(),
+            trailing_end.text_range().end(),
+        );
+
+        let between_range = TextRange::new(token.text_range().end(), trailing.text_range().start());
+        let line_break = ctx.config().format.line_ending.corresponding_string();
+        let result = self
+            .create_result(
+                Severity::Help,
+                format!("Content after {{% {} %}} on the same line", keyword.text()),
+            )
+            .primary_note(trailing_range, "move this content to the next line")
+            .suggestion(
+                between_range,
+                format!("{line_break}{indentation}"),
+                "Try this line break",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "no-trailing-content-after-close",
+            "{% if a %}\n    hello\n{% endif %} some text",
+            expect![[r"
+                help[no-trailing-content-after-close]: Content after {% endif %} on the same line
+                  ┌─ ./debug-rule.html.twig:3:13
+                  │
+                3 │ {% endif %} some text
+                  │            -^^^^^^^^^ move this content to the next line
+                  │            │ 
+                  │            Try this line break: 
+
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_inline() {
+        test_rule(
+            "no-trailing-content-after-close",
+            "<span>{% if a %}a{% endif %} b</span>\n<div class=\"{% if a %}a{% endif %} b\">\n{% if a %}\n    hello\n{% endif %}  \n</div>",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "no-trailing-content-after-close",
+            "<div>\n    {% if a %}\n        hello\n    {% endif %} <b>text</b>\n</div>",
+            expect![[r"
+                <div>
+                    {% if a %}
+                        hello
+                    {% endif %}
+                    <b>text</b>
+                </div>"]],
+        );
+    }
+}
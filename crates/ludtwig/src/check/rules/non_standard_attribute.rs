@@ -0,0 +1,421 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlStartingTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Standard html elements (all other tags like custom elements are not checked)
+static STANDARD_ELEMENTS: &[&str] = &[
+    "a",
+    "abbr",
+    "address",
+    "area",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "base",
+    "bdi",
+    "bdo",
+    "blockquote",
+    "body",
+    "br",
+    "button",
+    "canvas",
+    "caption",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "data",
+    "datalist",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "i",
+    "iframe",
+    "img",
+    "input",
+    "ins",
+    "kbd",
+    "label",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "map",
+    "mark",
+    "menu",
+    "meta",
+    "meter",
+    "nav",
+    "noscript",
+    "object",
+    "ol",
+    "optgroup",
+    "option",
+    "output",
+    "p",
+    "param",
+    "picture",
+    "pre",
+    "progress",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "script",
+    "search",
+    "section",
+    "select",
+    "slot",
+    "small",
+    "source",
+    "span",
+    "strong",
+    "style",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "title",
+    "tr",
+    "track",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+];
+
+/// Attributes of all standard html elements (including common legacy and microdata / `RDFa` ones)
+static STANDARD_ATTRIBUTES: &[&str] = &[
+    "abbr",
+    "about",
+    "accept",
+    "accesskey",
+    "action",
+    "align",
+    "allow",
+    "allowfullscreen",
+    "alt",
+    "archive",
+    "as",
+    "async",
+    "autocapitalize",
+    "autocomplete",
+    "autocorrect",
+    "autofocus",
+    "autoplay",
+    "axis",
+    "background",
+    "bgcolor",
+    "blocking",
+    "border",
+    "capture",
+    "cellpadding",
+    "cellspacing",
+    "char",
+    "charoff",
+    "charset",
+    "checked",
+    "cite",
+    "class",
+    "classid",
+    "clear",
+    "closedby",
+    "codebase",
+    "codetype",
+    "color",
+    "cols",
+    "colspan",
+    "command",
+    "commandfor",
+    "compact",
+    "content",
+    "contenteditable",
+    "controls",
+    "controlslist",
+    "coords",
+    "crossorigin",
+    "data",
+    "datatype",
+    "datetime",
+    "declare",
+    "decoding",
+    "default",
+    "defer",
+    "dir",
+    "dirname",
+    "disabled",
+    "disablepictureinpicture",
+    "disableremoteplayback",
+    "download",
+    "draggable",
+    "elementtiming",
+    "enctype",
+    "enterkeyhint",
+    "exportparts",
+    "face",
+    "fetchpriority",
+    "for",
+    "form",
+    "formaction",
+    "formenctype",
+    "formmethod",
+    "formnovalidate",
+    "formtarget",
+    "frame",
+    "frameborder",
+    "headers",
+    "height",
+    "hidden",
+    "high",
+    "href",
+    "hreflang",
+    "hspace",
+    "id",
+    "imagesizes",
+    "imagesrcset",
+    "inert",
+    "inlist",
+    "inputmode",
+    "integrity",
+    "is",
+    "ismap",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "kind",
+    "label",
+    "lang",
+    "language",
+    "list",
+    "loading",
+    "longdesc",
+    "loop",
+    "low",
+    "manifest",
+    "marginheight",
+    "marginwidth",
+    "max",
+    "maxlength",
+    "media",
+    "method",
+    "min",
+    "minlength",
+    "multiple",
+    "muted",
+    "name",
+    "nomodule",
+    "nonce",
+    "noshade",
+    "novalidate",
+    "nowrap",
+    "open",
+    "optimum",
+    "part",
+    "pattern",
+    "ping",
+    "placeholder",
+    "playsinline",
+    "popover",
+    "popovertarget",
+    "popovertargetaction",
+    "poster",
+    "prefix",
+    "preload",
+    "profile",
+    "property",
+    "readonly",
+    "referrerpolicy",
+    "rel",
+    "required",
+    "resource",
+    "rev",
+    "reversed",
+    "role",
+    "rows",
+    "rowspan",
+    "rules",
+    "sandbox",
+    "scheme",
+    "scope",
+    "scrolling",
+    "selected",
+    "shadowrootclonable",
+    "shadowrootdelegatesfocus",
+    "shadowrootmode",
+    "shape",
+    "size",
+    "sizes",
+    "slot",
+    "span",
+    "spellcheck",
+    "src",
+    "srcdoc",
+    "srclang",
+    "srcset",
+    "standby",
+    "start",
+    "step",
+    "style",
+    "summary",
+    "tabindex",
+    "target",
+    "title",
+    "translate",
+    "type",
+    "typeof",
+    "usemap",
+    "valign",
+    "value",
+    "valuetype",
+    "version",
+    "vocab",
+    "vspace",
+    "width",
+    "wrap",
+    "writingsuggestions",
+    "xmlns",
+];
+
+pub struct RuleNonStandardAttribute;
+
+impl Rule for RuleNonStandardAttribute {
+    fn name(&self) -> &'static str {
+        "non-standard-attribute"
+    }
+
+    fn description(&self) -> &'static str {
+        "Html elements only have attributes which are defined by the standard"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if ctx.traversal_ctx().inside_foreign_element {
+            return None; // svg and math have their own attributes
+        }
+
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        let tag_name = attribute
+            .syntax()
+            .ancestors()
+            .find_map(HtmlStartingTag::cast)?
+            .name()?;
+        if !STANDARD_ELEMENTS.contains(&tag_name.text().to_ascii_lowercase().as_str()) {
+            return None; // custom elements (with a hyphen) and unknown tags may have any attribute
+        }
+
+        // to stay conservative only plain names are checked, because names with hyphens,
+        // colons or other special characters are used by frameworks (like 'hx-get' or ':value')
+        let name = attribute_name.text().to_ascii_lowercase();
+        if !name.chars().all(|c| c.is_ascii_alphanumeric())
+            || !name.starts_with(|c: char| c.is_ascii_alphabetic())
+            || name.starts_with("on") // event handlers
+            || STANDARD_ATTRIBUTES.contains(&name.as_str())
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Non-standard html attribute")
+            .primary_note(
+                attribute_name.text_range(),
+                format!(
+                    "'{}' is not a standard attribute of <{}>",
+                    attribute_name.text(),
+                    tag_name.text()
+                ),
+            )
+            .suggestion(
+                attribute_name.text_range(),
+                format!("data-{name}"),
+                "Use a custom data attribute instead",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "non-standard-attribute",
+            r#"<div foo="x"></div>"#,
+            expect![[r#"
+                warning[non-standard-attribute]: Non-standard html attribute
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div foo="x"></div>
+                  │      ^^^
+                  │      │
+                  │      'foo' is not a standard attribute of <div>
+                  │      Use a custom data attribute instead: data-foo
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "non-standard-attribute",
+            r#"<div data-foo="x" aria-label="y" onclick="z()" class="a" hx-get="/b" :value="c" @click="d" itemprop="e"></div><my-element foo="x"></my-element><svg viewBox="0 0 1 1"><path d="M0 0"/></svg>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "non-standard-attribute",
+            r#"<div foo="x" myValue></div>"#,
+            expect![[r#"<div data-foo="x" data-myvalue></div>"#]],
+        );
+    }
+}
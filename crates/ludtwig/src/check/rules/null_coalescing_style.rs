@@ -0,0 +1,244 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigArguments, TwigLiteralName};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::config::NullCoalescingStyle;
+
+pub struct RuleNullCoalescingStyle;
+
+impl Rule for RuleNullCoalescingStyle {
+    fn name(&self) -> &'static str {
+        "null-coalescing-style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Null coalescing uses the configured style (operator or default filter)"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let style = &ctx.config().rules.null_coalescing_style;
+        let (range, suggested) = convert(&node, style)?;
+
+        // nested occurrences are converted in a later fix iteration (to prevent overlapping suggestions)
+        if node
+            .ancestors()
+            .skip(1)
+            .any(|a| convert(&a, style).is_some())
+        {
+            return None;
+        }
+
+        let result = match style {
+            NullCoalescingStyle::Operator => self
+                .create_result(
+                    Severity::Help,
+                    "Null coalescing with the default filter instead of the operator",
+                )
+                .primary_note(range, "use the ?? operator instead"),
+            NullCoalescingStyle::DefaultFilter => self
+                .create_result(
+                    Severity::Help,
+                    "Null coalescing with the operator instead of the default filter",
+                )
+                .primary_note(range, "use the default filter instead"),
+        };
+        let result = result.suggestion(
+            range,
+            suggested,
+            "Try this (note: the default filter also replaces empty values)",
+        );
+
+        Some(vec![result])
+    }
+}
+
+/// Range and replacement of the non preferred idiom or None if the node is not that idiom
+fn convert(node: &SyntaxNode, style: &NullCoalescingStyle) -> Option<(TextRange, String)> {
+    match style {
+        NullCoalescingStyle::Operator => convert_default_filter(node),
+        NullCoalescingStyle::DefaultFilter => convert_operator(node),
+    }
+}
+
+/// `x|default(y)` to `x ?? y`
+fn convert_default_filter(node: &SyntaxNode) -> Option<(TextRange, String)> {
+    if node.kind() != SyntaxKind::TWIG_FILTER {
+        return None;
+    }
+
+    let mut operands = node
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::TWIG_OPERAND);
+    let value = operands.next()?;
+    let filter = operands.next()?;
+    let filter_name: TwigLiteralName = support::child(&filter)?;
+    if support::token(filter_name.syntax(), T![word])?.text() != "default" {
+        return None;
+    }
+
+    // only default filters with exactly one argument can be converted
+    let arguments: TwigArguments = support::child(&filter)?;
+    let mut argument_iter = arguments.syntax().children();
+    let argument = argument_iter.next()?;
+    if argument_iter.next().is_some() {
+        return None;
+    }
+
+    let argument_text = wrap_if_operator_expression(&argument);
+    let mut suggested = format!("{} ?? {argument_text}", trimmed_text(&value));
+    if node.parent()?.kind() != SyntaxKind::TWIG_EXPRESSION {
+        // filters bind stronger than the operator (for example in filter chains)
+        suggested = format!("({suggested})");
+    }
+
+    Some((range_without_leading_trivia(node)?, suggested))
+}
+
+/// `x ?? y` to `x|default(y)`
+fn convert_operator(node: &SyntaxNode) -> Option<(TextRange, String)> {
+    if node.kind() != SyntaxKind::TWIG_BINARY_EXPRESSION {
+        return None;
+    }
+    support::token(node, T!["??"])?;
+
+    let mut expressions = node
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::TWIG_EXPRESSION);
+    let left = expressions.next()?;
+    let right = expressions.next()?;
+
+    let suggested = format!(
+        "{}|default({})",
+        wrap_if_operator_expression(&left),
+        trimmed_text(&right)
+    );
+
+    Some((range_without_leading_trivia(node)?, suggested))
+}
+
+fn trimmed_text(node: &SyntaxNode) -> String {
+    node.text().to_string().trim().to_string()
+}
+
+/// Wraps the expression in parentheses if it contains a top level operator
+fn wrap_if_operator_expression(expression: &SyntaxNode) -> String {
+    let is_operator_expression = expression.children().any(|c| {
+        matches!(
+            c.kind(),
+            SyntaxKind::TWIG_BINARY_EXPRESSION
+                | SyntaxKind::TWIG_UNARY_EXPRESSION
+                | SyntaxKind::TWIG_CONDITIONAL_EXPRESSION
+        )
+    });
+
+    if is_operator_expression {
+        format!("({})", trimmed_text(expression))
+    } else {
+        trimmed_text(expression)
+    }
+}
+
+fn range_without_leading_trivia(node: &SyntaxNode) -> Option<TextRange> {
+    let first_token = node
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .find(|t| !t.kind().is_trivia())?;
+
+    Some(TextRange::new(
+        first_token.text_range().start(),
+        node.text_range().end(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::NullCoalescingStyle;
+
+    #[test]
+    fn rule_reports_default_filter() {
+        test_rule(
+            "null-coalescing-style",
+            "{{ a.b|default('x') }}{{ a ?? 'y' }}{{ a|default }}",
+            expect![[r"
+                help[null-coalescing-style]: Null coalescing with the default filter instead of the operator
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ a.b|default('x') }}{{ a ?? 'y' }}{{ a|default }}
+                  │    ^^^^^^^^^^^^^^^^
+                  │    │
+                  │    use the ?? operator instead
+                  │    Try this (note: the default filter also replaces empty values): a.b ?? 'x'
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_default_filter() {
+        test_rule_fix(
+            "null-coalescing-style",
+            "{{ a.b|default('x') }}{{ a|default(b ~ c)|upper }}",
+            expect!["{{ a.b ?? 'x' }}{{ (a ?? (b ~ c))|upper }}"],
+        );
+    }
+
+    #[test]
+    fn rule_reports_operator_when_configured() {
+        test_rule_with_config(
+            "null-coalescing-style",
+            "{{ a.b ?? 'x' }}{{ a|default('y') }}{{ (a ~ b) ?? c ?? 'z' }}",
+            |config| config.rules.null_coalescing_style = NullCoalescingStyle::DefaultFilter,
+            expect![[r"
+                help[null-coalescing-style]: Null coalescing with the operator instead of the default filter
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ a.b ?? 'x' }}{{ a|default('y') }}{{ (a ~ b) ?? c ?? 'z' }}
+                  │    ^^^^^^^^^^
+                  │    │
+                  │    use the default filter instead
+                  │    Try this (note: the default filter also replaces empty values): a.b|default('x')
+
+                help[null-coalescing-style]: Null coalescing with the operator instead of the default filter
+                  ┌─ ./debug-rule.html.twig:1:40
+                  │
+                1 │ {{ a.b ?? 'x' }}{{ a|default('y') }}{{ (a ~ b) ?? c ?? 'z' }}
+                  │                                        ^^^^^^^^^^^^^^^^^^^
+                  │                                        │
+                  │                                        use the default filter instead
+                  │                                        Try this (note: the default filter also replaces empty values): (a ~ b)|default(c ?? 'z')
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_nested_default_filter_once() {
+        test_rule(
+            "null-coalescing-style",
+            "{{ a|default(b|default(c)) }}",
+            expect![[r"
+                help[null-coalescing-style]: Null coalescing with the default filter instead of the operator
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ {{ a|default(b|default(c)) }}
+                  │    ^^^^^^^^^^^^^^^^^^^^^^^
+                  │    │
+                  │    use the ?? operator instead
+                  │    Try this (note: the default filter also replaces empty values): a ?? b|default(c)
+
+            "]],
+        );
+    }
+}
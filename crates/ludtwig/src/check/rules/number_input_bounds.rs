@@ -0,0 +1,123 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleNumberInputBounds;
+
+impl Rule for RuleNumberInputBounds {
+    fn name(&self) -> &'static str {
+        "number-input-bounds"
+    }
+
+    fn description(&self) -> &'static str {
+        "Number inputs have the configured bounds attributes"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        if !tag_name.text().eq_ignore_ascii_case("input") {
+            return None;
+        }
+
+        let is_number_input = tag.attributes().any(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("type"))
+                && attribute
+                    .value()
+                    .and_then(|v| v.get_inner())
+                    .is_some_and(|inner| {
+                        inner
+                            .syntax()
+                            .text()
+                            .to_string()
+                            .trim()
+                            .eq_ignore_ascii_case("number")
+                    })
+        });
+        if !is_number_input {
+            return None;
+        }
+
+        let missing: Vec<&str> = ctx
+            .config()
+            .rules
+            .number_input_bounds_attributes
+            .iter()
+            .filter(|required| {
+                !tag.attributes().any(|attribute| {
+                    attribute
+                        .name()
+                        .is_some_and(|n| n.text().eq_ignore_ascii_case(required))
+                })
+            })
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Number input without bounds")
+            .primary_note(
+                tag_name.text_range(),
+                format!("add the missing attributes: {}", missing.join(", ")),
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "number-input-bounds",
+            r#"<input type="number" name="amount" min="0">"#,
+            expect![[r#"
+                warning[number-input-bounds]: Number input without bounds
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <input type="number" name="amount" min="0">
+                  │  ^^^^^ add the missing attributes: max
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_bounded_input() {
+        test_rule(
+            "number-input-bounds",
+            r#"<input type="number" min="0" max="10"><input type="text">"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_attributes() {
+        test_rule_with_config(
+            "number-input-bounds",
+            r#"<input type="number" min="0" max="10"><input type="number" min="0" max="10" step="2">"#,
+            |config| {
+                config.rules.number_input_bounds_attributes =
+                    vec!["min".to_string(), "max".to_string(), "step".to_string()];
+            },
+            expect![[r#"
+                warning[number-input-bounds]: Number input without bounds
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <input type="number" min="0" max="10"><input type="number" min="0" max="10" step="2">
+                  │  ^^^^^ add the missing attributes: step
+
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Elements with an implicit ARIA role that does not depend on other attributes or the context
+static IMPLICIT_ROLES: &[(&str, &str)] = &[
+    ("article", "article"),
+    ("aside", "complementary"),
+    ("button", "button"),
+    ("datalist", "listbox"),
+    ("details", "group"),
+    ("dialog", "dialog"),
+    ("fieldset", "group"),
+    ("figure", "figure"),
+    ("h1", "heading"),
+    ("h2", "heading"),
+    ("h3", "heading"),
+    ("h4", "heading"),
+    ("h5", "heading"),
+    ("h6", "heading"),
+    ("hr", "separator"),
+    ("li", "listitem"),
+    ("main", "main"),
+    ("menu", "list"),
+    ("meter", "meter"),
+    ("nav", "navigation"),
+    ("ol", "list"),
+    ("optgroup", "group"),
+    ("option", "option"),
+    ("output", "status"),
+    ("p", "paragraph"),
+    ("progress", "progressbar"),
+    ("search", "search"),
+    ("table", "table"),
+    ("tbody", "rowgroup"),
+    ("textarea", "textbox"),
+    ("tfoot", "rowgroup"),
+    ("thead", "rowgroup"),
+    ("tr", "row"),
+    ("ul", "list"),
+];
+
+pub struct RuleRedundantRole;
+
+impl Rule for RuleRedundantRole {
+    fn name(&self) -> &'static str {
+        "redundant-role"
+    }
+
+    fn description(&self) -> &'static str {
+        "Role attributes which only repeat the implicit role of the element"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        let (_, implicit_role) = IMPLICIT_ROLES
+            .iter()
+            .find(|(element, _)| tag_name.text().eq_ignore_ascii_case(element))?;
+
+        let results: Vec<CheckResult> = tag
+            .attributes()
+            .filter(|attribute| {
+                attribute
+                    .name()
+                    .is_some_and(|n| n.text().eq_ignore_ascii_case("role"))
+            })
+            .filter(|attribute| {
+                // dynamic values or role lists are not redundant
+                attribute
+                    .value()
+                    .and_then(|v| v.get_inner())
+                    .is_some_and(|inner| {
+                        inner.syntax().first_child().is_none()
+                            && inner
+                                .syntax()
+                                .text()
+                                .to_string()
+                                .trim()
+                                .eq_ignore_ascii_case(implicit_role)
+                    })
+            })
+            .filter_map(|attribute| {
+                let attribute_name = attribute.name()?;
+                let result = self
+                    .create_result(Severity::Help, "Redundant role attribute")
+                    .primary_note(
+                        attribute_name.text_range(),
+                        format!(
+                            "<{}> already has the implicit role '{implicit_role}'",
+                            tag_name.text()
+                        ),
+                    )
+                    .suggestion(attribute.syntax().text_range(), "", "Remove this attribute");
+                Some(result)
+            })
+            .collect();
+
+        Some(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "redundant-role",
+            r#"<nav class="main" role="navigation"></nav>"#,
+            expect![[r#"
+                help[redundant-role]: Redundant role attribute
+                  ┌─ ./debug-rule.html.twig:1:19
+                  │
+                1 │ <nav class="main" role="navigation"></nav>
+                  │                  -^^^^-------------
+                  │                  ││
+                  │                  │<nav> already has the implicit role 'navigation'
+                  │                  Remove this attribute: 
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "redundant-role",
+            r#"<div role="navigation"></div><ul role="tablist"></ul><a href="/" role="link"></a><nav role="{{ role }}"></nav>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "redundant-role",
+            r#"<nav class="main" role="navigation"></nav><div role="navigation"></div>"#,
+            expect![[r#"<nav class="main"></nav><div role="navigation"></div>"#]],
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleScriptLoading;
+
+impl Rule for RuleScriptLoading {
+    fn name(&self) -> &'static str {
+        "script-loading"
+    }
+
+    fn description(&self) -> &'static str {
+        "Scripts are loaded with defer or async to not block the rendering"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        let tag_name = tag.name()?;
+        if !tag_name.text().eq_ignore_ascii_case("script") {
+            return None;
+        }
+
+        let has_attribute = |name: &str| {
+            tag.attributes().any(|attribute| {
+                attribute
+                    .name()
+                    .is_some_and(|n| n.text().eq_ignore_ascii_case(name))
+            })
+        };
+        // inline scripts are exempt
+        if !has_attribute("src") || has_attribute("defer") || has_attribute("async") {
+            return None;
+        }
+
+        let is_module = tag.attributes().any(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("type"))
+                && attribute
+                    .value()
+                    .and_then(|v| v.get_inner())
+                    .is_some_and(|inner| inner.syntax().text() == "module")
+        });
+        if is_module {
+            return None;
+        }
+
+        let is_inside_head = tag.syntax().ancestors().skip(1).any(|ancestor| {
+            HtmlTag::cast(ancestor)
+                .and_then(|t| t.name())
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("head"))
+        });
+        if !is_inside_head && !ctx.config().rules.script_loading_check_body {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Render blocking script")
+            .primary_note(
+                tag_name.text_range(),
+                "add the defer or async attribute to load this script without blocking the rendering",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "script-loading",
+            r#"<head><script src="app.js"></script></head>"#,
+            expect![[r#"
+                warning[script-loading]: Render blocking script
+                  ┌─ ./debug-rule.html.twig:1:8
+                  │
+                1 │ <head><script src="app.js"></script></head>
+                  │        ^^^^^^ add the defer or async attribute to load this script without blocking the rendering
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_non_blocking_scripts() {
+        test_rule(
+            "script-loading",
+            r#"<head>
+    <script src="a.js" defer></script>
+    <script src="b.js" async></script>
+    <script src="c.js" type="module"></script>
+    <script>console.log('inline');</script>
+</head>
+<body><script src="d.js"></script></body>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_body_script_when_configured() {
+        test_rule_with_config(
+            "script-loading",
+            r#"<body><script src="d.js"></script></body>"#,
+            |config| config.rules.script_loading_check_body = true,
+            expect![[r#"
+                warning[script-loading]: Render blocking script
+                  ┌─ ./debug-rule.html.twig:1:8
+                  │
+                1 │ <body><script src="d.js"></script></body>
+                  │        ^^^^^^ add the defer or async attribute to load this script without blocking the rendering
+
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleSelectPlaceholderOption;
+
+impl Rule for RuleSelectPlaceholderOption {
+    fn name(&self) -> &'static str {
+        "select-placeholder-option"
+    }
+
+    fn description(&self) -> &'static str {
+        "Placeholder options of selects are disabled"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        if !tag.name()?.text().eq_ignore_ascii_case("select") {
+            return None;
+        }
+
+        // only the first option can act as a placeholder
+        let option = tag
+            .body()?
+            .syntax()
+            .children()
+            .find_map(HtmlTag::cast)
+            .filter(|t| {
+                t.name()
+                    .is_some_and(|n| n.text().eq_ignore_ascii_case("option"))
+            })?;
+        let option_name = option.name()?;
+
+        let has_empty_value = option.attributes().any(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("value"))
+                && attribute.value().is_some_and(|v| {
+                    v.get_inner()
+                        .is_none_or(|inner| inner.syntax().text().is_empty())
+                })
+        });
+        let is_disabled = option.attributes().any(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("disabled"))
+        });
+        if !has_empty_value || is_disabled {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Placeholder option without disabled")
+            .primary_note(
+                option_name.text_range(),
+                "add the disabled (and selected) attribute to avoid submitting the placeholder",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "select-placeholder-option",
+            r#"<select name="a"><option value="">Please choose</option><option value="1">One</option></select>"#,
+            expect![[r#"
+                warning[select-placeholder-option]: Placeholder option without disabled
+                  ┌─ ./debug-rule.html.twig:1:19
+                  │
+                1 │ <select name="a"><option value="">Please choose</option><option value="1">One</option></select>
+                  │                   ^^^^^^ add the disabled (and selected) attribute to avoid submitting the placeholder
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "select-placeholder-option",
+            r#"<select><option value="" disabled selected>Please choose</option></select><select><option value="1">One</option><option value="">None</option></select>"#,
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use ludtwig_parser::syntax::typed::{
+    support, AstNode, TwigAssignment, TwigEndSetBlock, TwigExpression, TwigLiteralName,
+    TwigLiteralString, TwigSet, TwigSetBlock,
+};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleSetBlockForLongValues;
+
+impl Rule for RuleSetBlockForLongValues {
+    fn name(&self) -> &'static str {
+        "set-block-for-long-values"
+    }
+
+    fn description(&self) -> &'static str {
+        "Long or multiline values are assigned with the set block syntax"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_set = TwigSet::cast(node)?;
+        // the block form is already used
+        if support::child::<TwigEndSetBlock>(twig_set.syntax()).is_some() {
+            return None;
+        }
+
+        let set_block = support::child::<TwigSetBlock>(twig_set.syntax())?;
+        let assignment = support::child::<TwigAssignment>(set_block.syntax())?;
+
+        // only single assignments of a plain string literal can be written in the block form
+        let mut names = support::children::<TwigLiteralName>(assignment.syntax());
+        let name = support::token(names.next()?.syntax(), T![word])?;
+        if names.next().is_some() {
+            return None;
+        }
+        let mut expressions = support::children::<TwigExpression>(assignment.syntax());
+        let expression = expressions.next()?;
+        if expressions.next().is_some() {
+            return None;
+        }
+        let string = expression
+            .syntax()
+            .children()
+            .find_map(TwigLiteralString::cast)?;
+        let inner = string.get_inner()?;
+        if inner.get_interpolations().next().is_some() {
+            return None;
+        }
+
+        let value = inner.syntax().text().to_string();
+        let max_length = ctx.config().rules.set_block_for_long_values_max_length;
+        let value_length = value.chars().count();
+        let message = if value.contains('\n') {
+            "this value spans multiple lines".to_owned()
+        } else if value_length > max_length {
+            format!("this value has {value_length} characters (more than {max_length})")
+        } else {
+            return None;
+        };
+
+        let mut result = self
+            .create_result(
+                Severity::Help,
+                "Long value assigned with the inline set syntax",
+            )
+            .primary_note(name.text_range(), message);
+
+        // escaped quotes would change their meaning in the block form and the block form
+        // captures its content as safe markup, where twig syntax is evaluated and html is not escaped
+        let changes_meaning = value.contains('\\')
+            || ["{{", "{%", "{#", "<", ">", "&"]
+                .iter()
+                .any(|pattern| value.contains(pattern));
+        if !changes_meaning {
+            let set_start = support::token(set_block.syntax(), T!["{%"])?;
+            let set_end = support::token(set_block.syntax(), T!["%}"])?;
+            // keep the outer whitespace control of the tag (like `{%-` and `-%}`),
+            // the delimiters around the value must not trim it
+            result = result.suggestion(
+                TextRange::new(
+                    set_start.text_range().start(),
+                    twig_set.syntax().text_range().end(),
+                ),
+                format!(
+                    "{} set {} %}}{value}{{% endset {}",
+                    set_start.text(),
+                    name.text(),
+                    set_end.text()
+                ),
+                "Try the block form instead",
+            );
+        }
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{
+        test_rule_does_not_fix_with_config, test_rule_fix_with_config, test_rule_with_config,
+    };
+
+    #[test]
+    fn rule_reports_long_value() {
+        test_rule_with_config(
+            "set-block-for-long-values",
+            "{% set title = 'Hello world' %}",
+            |config| config.rules.set_block_for_long_values_max_length = 10,
+            expect![[r"
+                help[set-block-for-long-values]: Long value assigned with the inline set syntax
+                  ┌─ ./debug-rule.html.twig:1:8
+                  │
+                1 │ {% set title = 'Hello world' %}
+                  │ -------^^^^^-------------------
+                  │ │      │
+                  │ │      this value has 11 characters (more than 10)
+                  │ Try the block form instead: {% set title %}Hello world{% endset %}
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_short_value() {
+        test_rule_with_config(
+            "set-block-for-long-values",
+            "{% set title = 'Hello world' %}",
+            |config| config.rules.set_block_for_long_values_max_length = 11,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_other_assignments() {
+        test_rule_with_config(
+            "set-block-for-long-values",
+            "{% set a, b = 'Hello world', 'Hello world' %}{% set c = other ~ 'Hello world' %}{% set d %}Hello world{% endset %}",
+            |config| config.rules.set_block_for_long_values_max_length = 5,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_multi_line_value() {
+        test_rule_fix_with_config(
+            "set-block-for-long-values",
+            "{% set text = 'Hello\nworld' %}",
+            |config| config.rules.set_block_for_long_values_max_length = 80,
+            expect!["{% set text %}Hello\nworld{% endset %}"],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix_with_config(
+            "set-block-for-long-values",
+            "<div>\n    {% set title = 'Hello world' %}\n</div>",
+            |config| config.rules.set_block_for_long_values_max_length = 10,
+            expect![[r"
+                <div>
+                    {% set title %}Hello world{% endset %}
+                </div>"]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_whitespace_control() {
+        test_rule_fix_with_config(
+            "set-block-for-long-values",
+            "<div>\n    {%- set title = 'Hello world' -%}\n</div>",
+            |config| config.rules.set_block_for_long_values_max_length = 10,
+            expect![[r"
+                <div>
+                    {%- set title %}Hello world{% endset -%}
+                </div>"]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_fix_twig_syntax_or_html() {
+        test_rule_does_not_fix_with_config(
+            "set-block-for-long-values",
+            "{% set a = 'Use {{ name }} and <b>bold</b> text' %}{% set b = 'Tom &amp; Jerry' %}",
+            |config| config.rules.set_block_for_long_values_max_length = 10,
+            expect!["{% set a = 'Use {{ name }} and <b>bold</b> text' %}{% set b = 'Tom &amp; Jerry' %}"],
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigExpression, TwigIf};
+use ludtwig_parser::syntax::untyped::{SyntaxElement, SyntaxKind, SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Maximum length of a branch output to still count as short
+const MAX_BRANCH_LENGTH: usize = 30;
+
+pub struct RuleSimplifyIfElse;
+
+impl Rule for RuleSimplifyIfElse {
+    fn name(&self) -> &'static str {
+        "simplify-if-else"
+    }
+
+    fn description(&self) -> &'static str {
+        "If else tags which only output short values can be a single expression"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_if = TwigIf::cast(node)?;
+
+        // only exactly one if and one else branch
+        let children: Vec<SyntaxNode> = twig_if.syntax().children().collect();
+        let kinds: Vec<SyntaxKind> = children.iter().map(SyntaxNode::kind).collect();
+        if kinds
+            != [
+                SyntaxKind::TWIG_IF_BLOCK,
+                SyntaxKind::BODY,
+                SyntaxKind::TWIG_ELSE_BLOCK,
+                SyntaxKind::BODY,
+                SyntaxKind::TWIG_ENDIF_BLOCK,
+            ]
+        {
+            return None;
+        }
+
+        // whitespace around the branch content is significant inside attribute values
+        let inside_string = twig_if.syntax().parent()?.kind() == SyntaxKind::HTML_STRING_INNER;
+        let condition: TwigExpression = support::child(&children[0])?;
+        let then_output = branch_output(&children[1], inside_string)?;
+        let else_output = branch_output(&children[3], inside_string)?;
+
+        let ternary = format!(
+            "{{{{ {} ? {then_output} : {else_output} }}}}",
+            wrap_if_conditional(condition.syntax())
+        );
+        let result = self
+            .create_result(Severity::Warning, "If else can be simplified")
+            .primary_note(
+                TextRange::new(
+                    support::token(&children[0], T!["{%"])?.text_range().start(),
+                    twig_if.syntax().text_range().end(),
+                ),
+                format!("this could be written as {ternary}"),
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// The branch as a twig expression if it only consists of a single short output
+fn branch_output(body: &SyntaxNode, inside_string: bool) -> Option<String> {
+    let mut elements = body
+        .children_with_tokens()
+        .filter(|e| !e.kind().is_trivia())
+        .peekable();
+
+    let output = match elements.peek()? {
+        SyntaxElement::Node(n) if n.kind() == SyntaxKind::TWIG_VAR => {
+            let expression: TwigExpression = support::child(n)?;
+            elements.next();
+            wrap_if_conditional(expression.syntax())
+        }
+        SyntaxElement::Node(n) if n.kind() == SyntaxKind::HTML_TEXT => {
+            let text = n.text().to_string();
+            elements.next();
+            string_literal(text.trim())?
+        }
+        _ if inside_string => {
+            // attribute values contain the plain tokens without a HTML_TEXT node
+            if body.children().next().is_some() {
+                return None;
+            }
+            let text = body.text().to_string();
+            if text.trim() != text {
+                return None;
+            }
+            elements.by_ref().for_each(drop);
+            string_literal(&text)?
+        }
+        _ => return None,
+    };
+
+    if elements.next().is_some() || output.len() > MAX_BRANCH_LENGTH {
+        return None;
+    }
+
+    Some(output)
+}
+
+fn string_literal(text: &str) -> Option<String> {
+    // markup, entities or twig syntax is no short output
+    if text.is_empty() || text.contains(['<', '>', '{', '}', '&', '\n']) {
+        return None;
+    }
+
+    Some(format!("'{}'", text.replace('\'', "\\'")))
+}
+
+fn wrap_if_conditional(expression: &SyntaxNode) -> String {
+    let text = expression.text().to_string();
+    let text = text.trim();
+    if expression
+        .first_child()
+        .is_some_and(|c| c.kind() == SyntaxKind::TWIG_CONDITIONAL_EXPRESSION)
+    {
+        format!("({text})")
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "simplify-if-else",
+            r#"<a class="{% if active %}active{% else %}inactive{% endif %}">{% if b %}{{ x.y }}{% else %}It's none{% endif %}</a>"#,
+            expect![[r#"
+                warning[simplify-if-else]: If else can be simplified
+                  ┌─ ./debug-rule.html.twig:1:11
+                  │
+                1 │ <a class="{% if active %}active{% else %}inactive{% endif %}">{% if b %}{{ x.y }}{% else %}It's none{% endif %}</a>
+                  │           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ this could be written as {{ active ? 'active' : 'inactive' }}
+
+                warning[simplify-if-else]: If else can be simplified
+                  ┌─ ./debug-rule.html.twig:1:63
+                  │
+                1 │ <a class="{% if active %}active{% else %}inactive{% endif %}">{% if b %}{{ x.y }}{% else %}It's none{% endif %}</a>
+                  │                                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ this could be written as {{ b ? x.y : 'It\'s none' }}
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_multiline() {
+        test_rule(
+            "simplify-if-else",
+            "<p>\n    {% if a %}\n        yes\n    {% else %}\n        no\n    {% endif %}\n</p>",
+            expect![[r"
+                warning[simplify-if-else]: If else can be simplified
+                  ┌─ ./debug-rule.html.twig:2:5
+                  │  
+                2 │ ╭     {% if a %}
+                3 │ │         yes
+                4 │ │     {% else %}
+                5 │ │         no
+                6 │ │     {% endif %}
+                  │ ╰───────────────^ this could be written as {{ a ? 'yes' : 'no' }}
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_complex() {
+        test_rule(
+            "simplify-if-else",
+            r#"{% if a %}<b>bold</b>{% else %}plain{% endif %}
+{% if a %}{{ a }}{% elseif b %}{{ b }}{% else %}c{% endif %}
+{% if a %}{{ a }} and {{ b }}{% else %}c{% endif %}
+{% if a %}this is a much longer text than a short output{% else %}c{% endif %}
+<a class="x{% if a %} active{% else %} inactive{% endif %}"></a>
+{% if a %}a{% endif %}"#,
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleTableCaption;
+
+impl Rule for RuleTableCaption {
+    fn name(&self) -> &'static str {
+        "table-caption"
+    }
+
+    fn description(&self) -> &'static str {
+        "Data tables have a caption as their first child"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let table = HtmlTag::cast(node)?;
+        let table_name = table.name()?;
+        if !table_name.text().eq_ignore_ascii_case("table") || is_layout_table(&table) {
+            return None;
+        }
+
+        // only data tables (with header cells) need a caption
+        let has_header_cells = table
+            .syntax()
+            .descendants()
+            .filter_map(HtmlTag::cast)
+            .any(|tag| has_name(&tag, "th") && closest_table(&tag).as_ref() == Some(&table));
+        if !has_header_cells {
+            return None;
+        }
+
+        // twig syntax in between is transparent
+        let children: Vec<HtmlTag> = table
+            .body()?
+            .syntax()
+            .descendants()
+            .filter_map(HtmlTag::cast)
+            .filter(|tag| {
+                tag.syntax()
+                    .ancestors()
+                    .skip(1)
+                    .find_map(HtmlTag::cast)
+                    .as_ref()
+                    == Some(&table)
+            })
+            .collect();
+
+        let result = match children.iter().position(|tag| has_name(tag, "caption")) {
+            Some(0) => return None,
+            Some(index) => self
+                .create_result(Severity::Warning, "Table caption is not the first child")
+                .primary_note(
+                    children[index].name()?.text_range(),
+                    "move the <caption> to the start of the table",
+                ),
+            None => self
+                .create_result(Severity::Warning, "Data table without a caption")
+                .primary_note(
+                    table_name.text_range(),
+                    "add a <caption> as the first child which describes this table",
+                ),
+        };
+
+        Some(vec![result])
+    }
+}
+
+fn has_name(tag: &HtmlTag, name: &str) -> bool {
+    tag.name()
+        .is_some_and(|n| n.text().eq_ignore_ascii_case(name))
+}
+
+fn closest_table(tag: &HtmlTag) -> Option<HtmlTag> {
+    tag.syntax()
+        .ancestors()
+        .skip(1)
+        .filter_map(HtmlTag::cast)
+        .find(|ancestor| has_name(ancestor, "table"))
+}
+
+/// Tables which are only used for the layout are exempt
+fn is_layout_table(table: &HtmlTag) -> bool {
+    table.attributes().any(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|n| n.text().eq_ignore_ascii_case("role"))
+            && attribute
+                .value()
+                .and_then(|v| v.get_inner())
+                .is_some_and(|inner| {
+                    let role = inner.syntax().text().to_string();
+                    matches!(role.trim(), "presentation" | "none")
+                })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "table-caption",
+            r"<table>
+    <tr><th>Name</th><th>Price</th></tr>
+    <tr><td>A</td><td>1</td></tr>
+</table>
+<table>
+    <thead><tr><th>Name</th></tr></thead>
+    <caption>Prices</caption>
+</table>",
+            expect![[r"
+                warning[table-caption]: Data table without a caption
+                  ┌─ ./debug-rule.html.twig:1:2
+                  │
+                1 │ <table>
+                  │  ^^^^^ add a <caption> as the first child which describes this table
+
+                warning[table-caption]: Table caption is not the first child
+                  ┌─ ./debug-rule.html.twig:7:6
+                  │
+                7 │     <caption>Prices</caption>
+                  │      ^^^^^^^ move the <caption> to the start of the table
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "table-caption",
+            r#"<table>
+    {% block table_caption %}<caption>Prices</caption>{% endblock %}
+    <tr><th>Name</th><th>Price</th></tr>
+</table>
+<table role="presentation">
+    <tr><th>Name</th></tr>
+</table>
+<table>
+    <tr><td>A</td><td><table><caption>Inner</caption><tr><th>B</th></tr></table></td></tr>
+</table>"#,
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleTableCellParent;
+
+impl Rule for RuleTableCellParent {
+    fn name(&self) -> &'static str {
+        "table-cell-parent"
+    }
+
+    fn description(&self) -> &'static str {
+        "Table cells are inside of a table row"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let cell = HtmlTag::cast(node)?;
+        let cell_name = cell.name()?;
+        if !["td", "th"]
+            .iter()
+            .any(|n| cell_name.text().eq_ignore_ascii_case(n))
+        {
+            return None;
+        }
+
+        // twig syntax in between is transparent and cells without any parent element
+        // are most likely inside of a partial template which gets included into a row
+        let parent = cell.syntax().ancestors().skip(1).find_map(HtmlTag::cast)?;
+        let parent_name = parent.name()?;
+        if parent_name.text().eq_ignore_ascii_case("tr") {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Table cell outside of a table row")
+            .primary_note(
+                cell_name.text_range(),
+                format!(
+                    "<{}> must be placed directly inside of a <tr>",
+                    cell_name.text()
+                ),
+            )
+            .secondary_note(
+                parent_name.text_range(),
+                format!("but its parent element is <{}>", parent_name.text()),
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "table-cell-parent",
+            r"<table>
+    <tbody>
+        <td>cell</td>
+    </tbody>
+</table>",
+            expect![[r"
+                warning[table-cell-parent]: Table cell outside of a table row
+                  ┌─ ./debug-rule.html.twig:3:10
+                  │
+                2 │     <tbody>
+                  │      ----- but its parent element is <tbody>
+                3 │         <td>cell</td>
+                  │          ^^ <td> must be placed directly inside of a <tr>
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "table-cell-parent",
+            r"<table>
+    <tr>
+        <th>heading</th>
+        {% block cells %}
+            {% for item in items %}
+                <td>{{ item }}</td>
+            {% endfor %}
+        {% endblock %}
+    </tr>
+</table>
+{% block partial_cells %}
+    <td>cell</td>
+{% endblock %}",
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::config::TrailingSlashPolicy;
+
+pub struct RuleTrailingSlashLinks;
+
+impl Rule for RuleTrailingSlashLinks {
+    fn name(&self) -> &'static str {
+        "trailing-slash-links"
+    }
+
+    fn description(&self) -> &'static str {
+        "Internal links use the configured trailing slash policy"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        if !tag.name()?.text().eq_ignore_ascii_case("a") {
+            return None;
+        }
+
+        let href = tag.attributes().find(|attribute| {
+            attribute
+                .name()
+                .is_some_and(|n| n.text().eq_ignore_ascii_case("href"))
+        })?;
+        let inner = href.value()?.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None; // dynamic href
+        }
+
+        // only internal paths without query, fragment or file extension
+        let path = inner.syntax().text().to_string();
+        let last_segment = path.trim_end_matches('/').rsplit('/').next()?;
+        if !path.starts_with('/')
+            || path.starts_with("//")
+            || path == "/"
+            || path.contains(['?', '#', ' '])
+            || last_segment.contains('.')
+        {
+            return None;
+        }
+
+        let policy = &ctx.config().rules.trailing_slash_links;
+        let expected = match policy {
+            TrailingSlashPolicy::Always if !path.ends_with('/') => format!("{path}/"),
+            TrailingSlashPolicy::Never if path.ends_with('/') => {
+                path.trim_end_matches('/').to_owned()
+            }
+            _ => return None,
+        };
+
+        let result = self
+            .create_result(Severity::Help, "Inconsistent trailing slash in link")
+            .primary_note(
+                inner.syntax().text_range(),
+                match policy {
+                    TrailingSlashPolicy::Always => "internal links should end with a slash",
+                    TrailingSlashPolicy::Never => "internal links should not end with a slash",
+                },
+            )
+            .suggestion(inner.syntax().text_range(), expected, "Try this instead");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::TrailingSlashPolicy;
+
+    #[test]
+    fn rule_reports_never() {
+        test_rule(
+            "trailing-slash-links",
+            r#"<a href="/account/orders/">Orders</a>"#,
+            expect![[r#"
+                help[trailing-slash-links]: Inconsistent trailing slash in link
+                  ┌─ ./debug-rule.html.twig:1:10
+                  │
+                1 │ <a href="/account/orders/">Orders</a>
+                  │          ^^^^^^^^^^^^^^^^
+                  │          │
+                  │          internal links should not end with a slash
+                  │          Try this instead: /account/orders
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_never() {
+        test_rule_fix(
+            "trailing-slash-links",
+            r#"<a href="/account/orders/">Orders</a><a href="/account">Account</a>"#,
+            expect![[r#"<a href="/account/orders">Orders</a><a href="/account">Account</a>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_always() {
+        test_rule_with_config(
+            "trailing-slash-links",
+            r#"<a href="/account/orders">Orders</a><a href="/account/">Account</a>"#,
+            |config| config.rules.trailing_slash_links = TrailingSlashPolicy::Always,
+            expect![[r#"
+                help[trailing-slash-links]: Inconsistent trailing slash in link
+                  ┌─ ./debug-rule.html.twig:1:10
+                  │
+                1 │ <a href="/account/orders">Orders</a><a href="/account/">Account</a>
+                  │          ^^^^^^^^^^^^^^^
+                  │          │
+                  │          internal links should end with a slash
+                  │          Try this instead: /account/orders/
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_always() {
+        test_rule_fix(
+            "trailing-slash-links",
+            r#"{# ludtwig-config trailing-slash-links=always #}<a href="/account/orders">Orders</a>"#,
+            expect![[
+                r#"{# ludtwig-config trailing-slash-links=always #}<a href="/account/orders/">Orders</a>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_other_hrefs() {
+        test_rule(
+            "trailing-slash-links",
+            r#"<a href="/">Home</a><a href="/search/?q=a">x</a><a href="/page/#top">x</a><a href="https://example.com/">x</a><a href="//cdn.example.com/">x</a><a href="{{ url }}/">x</a><a href="/files/doc.pdf">x</a><a href="mailto:a@b.c">x</a>"#,
+            expect![""],
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlTag};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxToken};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleTrailingWhitespace;
+
+impl Rule for RuleTrailingWhitespace {
+    fn name(&self) -> &'static str {
+        "trailing-whitespace"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lines don't end with whitespace"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_token(&self, token: SyntaxToken, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        if token.kind() != SyntaxKind::TK_WHITESPACE {
+            return None;
+        }
+
+        // whitespace must be followed by a line break or the end of the file
+        if token
+            .next_token()
+            .is_some_and(|next| next.kind() != SyntaxKind::TK_LINE_BREAK)
+        {
+            return None;
+        }
+
+        // whitespace only lines are reported by the 'whitespace-between-line-breaks' rule
+        if token.next_token().is_some()
+            && token
+                .prev_token()
+                .is_some_and(|prev| prev.kind() == SyntaxKind::TK_LINE_BREAK)
+        {
+            return None;
+        }
+
+        // whitespace inside of strings and preformatted content is part of the output
+        if token.parent_ancestors().any(|ancestor| {
+            ancestor.kind() == SyntaxKind::TWIG_LITERAL_STRING_INNER
+                || HtmlTag::cast(ancestor)
+                    .and_then(|tag| tag.name())
+                    .is_some_and(|name| matches!(name.text(), "pre" | "textarea"))
+        }) {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Help, "Trailing whitespace")
+            .primary_note(
+                token.text_range(),
+                "Unexpected whitespace at the end of the line",
+            )
+            .suggestion(token.text_range(), "", "Remove whitespace");
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "trailing-whitespace",
+            "<div>  \n    text\t\n</div> ",
+            expect![[r"
+                help[trailing-whitespace]: Trailing whitespace
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ <div>  
+                  │      ^^
+                  │      │
+                  │      Unexpected whitespace at the end of the line
+                  │      Remove whitespace: 
+
+                help[trailing-whitespace]: Trailing whitespace
+                  ┌─ ./debug-rule.html.twig:2:9
+                  │
+                2 │     text    
+                  │         ^^^^
+                  │         │   
+                  │         Unexpected whitespace at the end of the line
+                  │         Remove whitespace: 
+
+                help[trailing-whitespace]: Trailing whitespace
+                  ┌─ ./debug-rule.html.twig:3:7
+                  │
+                3 │ </div> 
+                  │       ^
+                  │       │
+                  │       Unexpected whitespace at the end of the line
+                  │       Remove whitespace: 
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "trailing-whitespace",
+            "<div>\n    \n    <pre>code  \n</pre>\n    <textarea>text \n</textarea>\n    {{ 'a  \nb' }}\n</div>\n",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "trailing-whitespace",
+            "<div>  \n    text\t \n</div>  ",
+            expect!["<div>\n    text\n</div>"],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_crlf() {
+        test_rule_fix(
+            "trailing-whitespace",
+            "<div> \r\n    text  \r\n</div>\r\n",
+            expect!["<div>\r\n    text\r\n</div>\r\n"],
+        );
+    }
+}
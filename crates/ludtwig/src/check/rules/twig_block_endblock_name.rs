@@ -0,0 +1,116 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigEndingBlock};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleTwigBlockEndblockName;
+
+impl Rule for RuleTwigBlockEndblockName {
+    fn name(&self) -> &'static str {
+        "twig-block-endblock-name"
+    }
+
+    fn description(&self) -> &'static str {
+        "Endblock tags repeat the name of their block"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let ending_block = TwigEndingBlock::cast(node)?;
+        let block_name = ending_block.twig_block()?.name()?;
+
+        let result = match ending_block.name() {
+            Some(name) if name.text() == block_name.text() => return None,
+            Some(name) => self
+                .create_result(Severity::Warning, "Endblock name does not match the block")
+                .primary_note(
+                    name.text_range(),
+                    format!("this block is named '{}'", block_name.text()),
+                )
+                .secondary_note(block_name.text_range(), "opened here")
+                .suggestion(
+                    name.text_range(),
+                    block_name.text(),
+                    "Use the name of the block",
+                ),
+            None => {
+                let endblock = support::token(ending_block.syntax(), T!["endblock"])?;
+                self.create_result(Severity::Help, "Missing block name behind endblock")
+                    .primary_note(
+                        endblock.text_range(),
+                        format!("add the block name '{}'", block_name.text()),
+                    )
+                    .suggestion(
+                        TextRange::empty(endblock.text_range().end()),
+                        format!(" {}", block_name.text()),
+                        "Add the name of the block",
+                    )
+            }
+        };
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "twig-block-endblock-name",
+            "{% block outer %}{% block inner %}{% endblock %}{% endblock other %}",
+            expect![[r"
+                error[SyntaxError]: The parser encountered a syntax error
+                  ┌─ ./debug-rule.html.twig:1:61
+                  │
+                1 │ {% block outer %}{% block inner %}{% endblock %}{% endblock other %}
+                  │                                                             ^^^^^ expected nothing or same twig block name as opening (outer) but found word
+
+                help[twig-block-endblock-name]: Missing block name behind endblock
+                  ┌─ ./debug-rule.html.twig:1:38
+                  │
+                1 │ {% block outer %}{% block inner %}{% endblock %}{% endblock other %}
+                  │                                      ^^^^^^^^- Add the name of the block:  inner
+                  │                                      │        
+                  │                                      add the block name 'inner'
+
+                warning[twig-block-endblock-name]: Endblock name does not match the block
+                  ┌─ ./debug-rule.html.twig:1:61
+                  │
+                1 │ {% block outer %}{% block inner %}{% endblock %}{% endblock other %}
+                  │          -----                                              ^^^^^
+                  │          │                                                  │
+                  │          │                                                  this block is named 'outer'
+                  │          │                                                  Use the name of the block: outer
+                  │          opened here
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "twig-block-endblock-name",
+            "{% block outer %}{% block inner %}{% endblock inner %}{% endblock outer %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "twig-block-endblock-name",
+            "{% block outer %}{% block inner %}{% endblock %}{% endblock other %}",
+            expect!["{% block outer %}{% block inner %}{% endblock inner %}{% endblock outer %}"],
+        );
+    }
+}
@@ -10,6 +10,18 @@ impl Rule for RuleTwigBlockLineBreaks {
         "twig-block-line-breaks"
     }
 
+    fn description(&self) -> &'static str {
+        "Twig blocks have line breaks around them"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     #[allow(clippy::too_many_lines)]
     fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         if ctx.traversal_ctx().inside_trivia_sensitive_node {
@@ -19,9 +31,11 @@ impl Rule for RuleTwigBlockLineBreaks {
         let block = TwigBlock::cast(node)?;
 
         // early return if parent is the root
-        if block.syntax().parent().map_or(true, |may_be_body| {
-            matches!(may_be_body.kind(), SyntaxKind::ROOT)
-        }) {
+        if block
+            .syntax()
+            .parent()
+            .is_none_or(|may_be_body| matches!(may_be_body.kind(), SyntaxKind::ROOT))
+        {
             return None;
         }
 
@@ -101,12 +115,12 @@ impl Rule for RuleTwigBlockLineBreaks {
                         .create_result(Severity::Help, "Wrong line break around block")
                         .primary_note(
                             token.text_range(),
-                            format!("Expected {} line breaks here", line_break_amount),
+                            format!("Expected {line_break_amount} line breaks here"),
                         )
                         .suggestion(
                             token.text_range(),
                             expected_str.clone(),
-                            format!("Change to {} line breaks", line_break_amount),
+                            format!("Change to {line_break_amount} line breaks"),
                         );
 
                     results.push(result);
@@ -119,12 +133,12 @@ impl Rule for RuleTwigBlockLineBreaks {
                     .create_result(Severity::Help, "Missing line break around block")
                     .primary_note(
                         range,
-                        format!("Expected {} line breaks before this", line_break_amount),
+                        format!("Expected {line_break_amount} line breaks before this"),
                     )
                     .suggestion(
                         range,
                         expected_str.clone(),
-                        format!("Add {} line breaks before this", line_break_amount),
+                        format!("Add {line_break_amount} line breaks before this"),
                     );
 
                 results.push(result);
@@ -165,7 +179,7 @@ mod tests {
         {% endblock %}
     </div>
 {% endblock %}",
-            expect![[r#"
+            expect![[r"
                 help[twig-block-line-breaks]: Wrong line break around block
                   ┌─ ./debug-rule.html.twig:5:23
                   │    
@@ -202,7 +216,7 @@ mod tests {
 
 
 
-            "#]],
+            "]],
         );
     }
 
@@ -253,7 +267,7 @@ mod tests {
     fn rule_does_not_report_trivia_sensitive() {
         test_rule(
             "twig-block-line-breaks",
-            r#"<pre>
+            r"<pre>
     {% block inner_a %}
         hello
     {% endblock %}
@@ -300,8 +314,8 @@ mod tests {
     {% block inner_b %}
         world
     {% endblock %}
-</div>"#,
-            expect![[r#"
+</div>",
+            expect![[r"
                 help[twig-block-line-breaks]: Wrong line break around block
                    ┌─ ./debug-rule.html.twig:44:19
                    │    
@@ -314,7 +328,7 @@ mod tests {
 
 
 
-            "#]],
+            "]],
         );
     }
 }
@@ -0,0 +1,180 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigBlock};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleTwigBlockNamePrefix;
+
+impl Rule for RuleTwigBlockNamePrefix {
+    fn name(&self) -> &'static str {
+        "twig-block-name-prefix"
+    }
+
+    fn description(&self) -> &'static str {
+        "Nested twig block names are prefixed with the name of their parent block"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let block = TwigBlock::cast(node)?;
+        let block_name = block.name()?;
+        let separator = &ctx.config().rules.twig_block_name_prefix_separator;
+
+        // top level blocks are exempt
+        let parent = parent_block(&block)?;
+        let parent_name = parent.name()?;
+
+        // the parent may be renamed by this rule as well, so the prefix is based on its corrected name
+        let prefix = format!("{}{separator}", expected_name(&parent, separator)?);
+        if block_name.text().starts_with(&prefix) {
+            return None;
+        }
+
+        let suggested_name = expected_name(&block, separator)?;
+        let mut result = self
+            .create_result(
+                Severity::Help,
+                "Block name is not prefixed with the parent block name",
+            )
+            .primary_note(
+                block_name.text_range(),
+                format!("help: prefix this block name with '{prefix}'"),
+            )
+            .secondary_note(parent_name.text_range(), "parent block")
+            .suggestion(
+                block_name.text_range(),
+                suggested_name.clone(),
+                "Try this name instead",
+            );
+
+        // keep the endblock name in sync
+        if let Some(ending_name) = block.ending_block().and_then(|e| e.name()) {
+            if ending_name.text() == block_name.text() {
+                result = result.suggestion(
+                    ending_name.text_range(),
+                    suggested_name,
+                    "Rename the endblock too",
+                );
+            }
+        }
+
+        Some(vec![result])
+    }
+}
+
+fn parent_block(block: &TwigBlock) -> Option<TwigBlock> {
+    block.syntax().ancestors().skip(1).find_map(TwigBlock::cast)
+}
+
+/// The name of the block after all of its parent blocks and itself are prefixed correctly
+fn expected_name(block: &TwigBlock, separator: &str) -> Option<String> {
+    let name = block.name()?;
+    let Some(parent) = parent_block(block) else {
+        return Some(name.text().to_owned());
+    };
+
+    let prefix = format!("{}{separator}", expected_name(&parent, separator)?);
+    if name.text().starts_with(&prefix) {
+        return Some(name.text().to_owned());
+    }
+
+    // replace the prefix of the uncorrected parent name instead of prefixing it twice
+    let old_prefix = format!("{}{separator}", parent.name()?.text());
+    let unprefixed = name.text().strip_prefix(&old_prefix).unwrap_or(name.text());
+    Some(format!("{prefix}{unprefixed}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "twig-block-name-prefix",
+            r"{% block page %}
+    <div>
+        {% block content %}{% endblock %}
+    </div>
+{% endblock %}",
+            expect![[r"
+                help[twig-block-name-prefix]: Block name is not prefixed with the parent block name
+                  ┌─ ./debug-rule.html.twig:3:18
+                  │
+                1 │ {% block page %}
+                  │          ---- parent block
+                2 │     <div>
+                3 │         {% block content %}{% endblock %}
+                  │                  ^^^^^^^
+                  │                  │
+                  │                  help: prefix this block name with 'page_'
+                  │                  Try this name instead: page_content
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "twig-block-name-prefix",
+            r"{% block page %}
+    {% block page_content %}
+        {% block page_content_inner %}{% endblock %}
+    {% endblock %}
+{% endblock %}
+{% block sidebar %}{% endblock %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_separator() {
+        test_rule_with_config(
+            "twig-block-name-prefix",
+            r"{% block page %}{% block page_content %}{% endblock %}{% block page-footer %}{% endblock %}{% endblock %}",
+            |config| {
+                config.rules.twig_block_name_prefix_separator = "-".to_owned();
+            },
+            expect![[r"
+                help[twig-block-name-prefix]: Block name is not prefixed with the parent block name
+                  ┌─ ./debug-rule.html.twig:1:26
+                  │
+                1 │ {% block page %}{% block page_content %}{% endblock %}{% block page-footer %}{% endblock %}{% endblock %}
+                  │          ----            ^^^^^^^^^^^^
+                  │          │               │
+                  │          │               help: prefix this block name with 'page-'
+                  │          │               Try this name instead: page-page_content
+                  │          parent block
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "twig-block-name-prefix",
+            r"{% block page %}
+    {% block content %}
+        {% block content_inner %}{% endblock content_inner %}
+    {% endblock content %}
+{% endblock %}",
+            expect![[r"
+                {% block page %}
+                    {% block page_content %}
+                        {% block page_content_inner %}{% endblock page_content_inner %}
+                    {% endblock page_content %}
+                {% endblock %}"]],
+        );
+    }
+}
@@ -10,6 +10,18 @@ impl Rule for RuleTwigBlockNameSnakeCase {
         "twig-block-name-snake-case"
     }
 
+    fn description(&self) -> &'static str {
+        "Twig block names are written in snake_case"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let block_name = TwigStartingBlock::cast(node)?.name()?;
         if !is_valid_ascii_alpha_snake_case(block_name.text()) {
@@ -167,7 +179,7 @@ mod tests {
         test_rule(
             "twig-block-name-snake-case",
             "{% block a-b %}hello{% endblock %}",
-            expect![[r#"
+            expect![[r"
                 help[twig-block-name-snake-case]: Block name is not written in snake_case
                   ┌─ ./debug-rule.html.twig:1:10
                   │
@@ -177,7 +189,7 @@ mod tests {
                   │          help: rename this block in snake_case
                   │          Try this name instead: a_b
 
-            "#]],
+            "]],
         );
     }
 
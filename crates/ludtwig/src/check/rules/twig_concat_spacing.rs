@@ -0,0 +1,151 @@
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxToken, TextRange};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::config::ConcatSpacing;
+
+pub struct RuleTwigConcatSpacing;
+
+impl Rule for RuleTwigConcatSpacing {
+    fn name(&self) -> &'static str {
+        "twig-concat-spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "The twig concatenation operator uses the configured spacing"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_token(&self, token: SyntaxToken, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        // only the concatenation operator (not a tilde inside of strings or html text)
+        if token.kind() != SyntaxKind::TK_TILDE
+            || token.parent()?.kind() != SyntaxKind::TWIG_BINARY_EXPRESSION
+        {
+            return None;
+        }
+
+        let expected = match ctx.config().rules.twig_concat_spacing {
+            ConcatSpacing::Spaced => " ",
+            ConcatSpacing::Compact => "",
+        };
+        let fixes: Vec<(TextRange, &str)> = [
+            (
+                token.prev_token(),
+                TextRange::empty(token.text_range().start()),
+            ),
+            (
+                token.next_token(),
+                TextRange::empty(token.text_range().end()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(neighbour, insert_range)| match neighbour {
+            // line breaks and indentation of multi line expressions are left alone
+            Some(t) if t.kind() == SyntaxKind::TK_LINE_BREAK || is_at_line_boundary(&t) => None,
+            Some(t) if t.kind() == SyntaxKind::TK_WHITESPACE => {
+                (t.text() != expected).then_some((t.text_range(), expected))
+            }
+            _ => (!expected.is_empty()).then_some((insert_range, expected)),
+        })
+        .collect();
+        if fixes.is_empty() {
+            return None;
+        }
+
+        let mut result = self
+            .create_result(Severity::Help, "Inconsistent spacing around concatenation")
+            .primary_note(
+                token.text_range(),
+                match ctx.config().rules.twig_concat_spacing {
+                    ConcatSpacing::Spaced => "help: use a single space on both sides of ~",
+                    ConcatSpacing::Compact => "help: remove the whitespace around ~",
+                },
+            );
+        for (range, replacement) in fixes {
+            result = result.suggestion(range, replacement, "Try this spacing instead");
+        }
+
+        Some(vec![result])
+    }
+}
+
+fn is_at_line_boundary(whitespace: &SyntaxToken) -> bool {
+    whitespace.kind() == SyntaxKind::TK_WHITESPACE
+        && [whitespace.prev_token(), whitespace.next_token()]
+            .iter()
+            .flatten()
+            .any(|t| t.kind() == SyntaxKind::TK_LINE_BREAK)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix, test_rule_with_config};
+    use crate::config::ConcatSpacing;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "twig-concat-spacing",
+            "{{ a~b }}",
+            expect![[r"
+            help[twig-concat-spacing]: Inconsistent spacing around concatenation
+              ┌─ ./debug-rule.html.twig:1:5
+              │
+            1 │ {{ a~b }}
+              │     ^- Try this spacing instead:  
+              │     │ 
+              │     help: use a single space on both sides of ~
+              │     Try this spacing instead:  
+
+        "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "twig-concat-spacing",
+            "{{ a ~ 'x~y' }}<p>a~b</p>{% set c = a\n    ~ b %}",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_compact() {
+        test_rule_with_config(
+            "twig-concat-spacing",
+            "{{ a ~ b~c }}",
+            |config| {
+                config.rules.twig_concat_spacing = ConcatSpacing::Compact;
+            },
+            expect![[r"
+                help[twig-concat-spacing]: Inconsistent spacing around concatenation
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ {{ a ~ b~c }}
+                  │     -^- Try this spacing instead: 
+                  │     ││ 
+                  │     │help: remove the whitespace around ~
+                  │     Try this spacing instead: 
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "twig-concat-spacing",
+            "{{ a~b }}{{ c  ~d~ 'e' }}",
+            expect!["{{ a ~ b }}{{ c ~ d ~ 'e' }}"],
+        );
+    }
+}
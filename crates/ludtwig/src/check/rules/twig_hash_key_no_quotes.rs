@@ -11,6 +11,18 @@ impl Rule for RuleTwigHashKeyNoQuotes {
         "twig-hash-key-no-quotes"
     }
 
+    fn description(&self) -> &'static str {
+        "Twig hash keys are not quoted unless necessary"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let hash_key = TwigLiteralHashKey::cast(node)?;
         let key_string_literal: TwigLiteralString = support::child(hash_key.syntax())?;
@@ -72,8 +84,8 @@ mod tests {
     fn rule_reports() {
         test_rule(
             "twig-hash-key-no-quotes",
-            r#"{% set v = { 'myKey': 42 } %}"#,
-            expect![[r#"
+            r"{% set v = { 'myKey': 42 } %}",
+            expect![[r"
                 help[twig-hash-key-no-quotes]: unnecessary quotation
                   ┌─ ./debug-rule.html.twig:1:14
                   │
@@ -84,7 +96,7 @@ mod tests {
                   │              help: remove quotation
                   │              remove this quote: 
 
-            "#]],
+            "]],
         );
     }
 
@@ -92,8 +104,8 @@ mod tests {
     fn rule_not_reports() {
         test_rule(
             "twig-hash-key-no-quotes",
-            r#"{% set v = { 'my-key': 42 } %}"#,
-            expect![[r#""#]],
+            r"{% set v = { 'my-key': 42 } %}",
+            expect![[r""]],
         );
     }
 
@@ -101,8 +113,8 @@ mod tests {
     fn rule_fixes() {
         test_rule_fix(
             "twig-hash-key-no-quotes",
-            r#"{% set v = { 'myKey': 42 } %}"#,
-            expect![r#"{% set v = { myKey: 42 } %}"#],
+            r"{% set v = { 'myKey': 42 } %}",
+            expect![r"{% set v = { myKey: 42 } %}"],
         );
     }
 }
@@ -11,6 +11,18 @@ impl Rule for RuleTwigLogicAnd {
         "twig-logic-and"
     }
 
+    fn description(&self) -> &'static str {
+        "The twig 'and' operator is used instead of '&&'"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
     fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let binary_expr_op = TwigBinaryExpression::cast(node)?.operator()?;
 
@@ -42,7 +54,7 @@ mod tests {
         test_rule(
             "twig-logic-and",
             "{% if a == 5 && b %}hello{% endif %}",
-            expect![[r#"
+            expect![[r"
                 error[twig-logic-and]: '&&' is not a valid twig operator
                   ┌─ ./debug-rule.html.twig:1:14
                   │
@@ -52,7 +64,7 @@ mod tests {
                   │              help: change this operator
                   │              Try this operator instead: and
 
-            "#]],
+            "]],
         );
     }
 
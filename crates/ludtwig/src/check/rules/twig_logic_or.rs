@@ -11,6 +11,18 @@ impl Rule for RuleTwigLogicOr {
         "twig-logic-or"
     }
 
+    fn description(&self) -> &'static str {
+        "The twig 'or' operator is used instead of '||'"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
     fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let binary = TwigBinaryExpression::cast(node)?;
         let binary_expr_op = binary.operator()?;
@@ -43,7 +55,7 @@ mod tests {
         test_rule(
             "twig-logic-or",
             "{% if a == 5 || b %}hello{% endif %}",
-            expect![[r#"
+            expect![[r"
                 error[twig-logic-or]: '||' is not a valid twig operator
                   ┌─ ./debug-rule.html.twig:1:14
                   │
@@ -53,7 +65,7 @@ mod tests {
                   │              help: change this operator
                   │              Try this operator instead: or
 
-            "#]],
+            "]],
         );
     }
 
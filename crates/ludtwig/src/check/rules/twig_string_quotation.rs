@@ -2,6 +2,7 @@ use ludtwig_parser::syntax::typed::{AstNode, TwigLiteralString};
 use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt, TextRange, TextSize};
 
 use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::attribute_expression_quote_style::attribute_value_of;
 use crate::config::Quotation;
 
 pub struct RuleTwigStringQuotation;
@@ -11,9 +12,26 @@ impl Rule for RuleTwigStringQuotation {
         "twig-string-quotation"
     }
 
+    fn description(&self) -> &'static str {
+        "Twig strings use the configured quotation"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         let twig_string = TwigLiteralString::cast(node)?;
 
+        // strings inside of attribute values are checked by the 'attribute-expression-quote-style' rule
+        if attribute_value_of(twig_string.syntax()).is_some() {
+            return None;
+        }
+
         // check for interpolated string where single quotes would be suggested
         if ctx.config().format.twig_quotation == Quotation::Single
             && twig_string
@@ -27,10 +45,10 @@ impl Rule for RuleTwigStringQuotation {
         let correct_quote = ctx.config().format.twig_quotation.corresponding_char();
         let opening_is_fine = twig_string
             .get_opening_quote()
-            .map_or(false, |t| t.text().starts_with(correct_quote));
+            .is_some_and(|t| t.text().starts_with(correct_quote));
         let closing_is_fine = twig_string
             .get_closing_quote()
-            .map_or(false, |t| t.text().starts_with(correct_quote));
+            .is_some_and(|t| t.text().starts_with(correct_quote));
 
         if !opening_is_fine || !closing_is_fine {
             // invalid quotation
@@ -59,9 +77,8 @@ pub fn make_changed_quotes_suggestion_if_possible(
     correct_quote: char,
     mut result: CheckResult,
 ) -> CheckResult {
-    let inner = match twig_string.get_inner() {
-        Some(inner) => inner,
-        None => return result,
+    let Some(inner) = twig_string.get_inner() else {
+        return result;
     };
 
     let inner_text = inner.syntax().text();
@@ -140,7 +157,16 @@ mod tests {
         test_rule(
             "twig-string-quotation",
             r#"{{ "/#{prefix}/phoneNumber" }}"#,
-            expect![r#""#],
+            expect![r""],
+        );
+    }
+
+    #[test]
+    fn rule_doesnt_report_strings_inside_attribute_values() {
+        test_rule(
+            "twig-string-quotation",
+            r#"<img src="{{ asset("x") }}">"#,
+            expect![r""],
         );
     }
 
@@ -149,7 +175,7 @@ mod tests {
         test_rule_fix(
             "twig-string-quotation",
             r#"{{ "double-quoted" }}"#,
-            expect![r#"{{ 'double-quoted' }}"#],
+            expect![r"{{ 'double-quoted' }}"],
         );
     }
 
@@ -9,6 +9,14 @@ impl Rule for RuleUnknownToken {
         "unknown-token"
     }
 
+    fn description(&self) -> &'static str {
+        "Tokens which the parser doesn't understand"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
     fn check_token(&self, token: SyntaxToken, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         if token.kind() != SyntaxKind::TK_UNKNOWN {
             return None;
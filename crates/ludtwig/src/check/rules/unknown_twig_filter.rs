@@ -0,0 +1,204 @@
+use ludtwig_parser::syntax::typed::{AstNode, TwigFilter};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::edit_distance;
+
+/// Filters of twig (core and extra), symfony and shopware
+static KNOWN_FILTERS: &[&str] = &[
+    // twig
+    "abs",
+    "batch",
+    "capitalize",
+    "column",
+    "convert_encoding",
+    "country_name",
+    "currency_name",
+    "currency_symbol",
+    "data_uri",
+    "date",
+    "date_modify",
+    "default",
+    "e",
+    "escape",
+    "filter",
+    "find",
+    "first",
+    "format",
+    "format_currency",
+    "format_date",
+    "format_datetime",
+    "format_number",
+    "format_time",
+    "html_to_markdown",
+    "inky_to_html",
+    "inline_css",
+    "invoke",
+    "join",
+    "json_encode",
+    "keys",
+    "language_name",
+    "last",
+    "length",
+    "locale_name",
+    "lower",
+    "map",
+    "markdown_to_html",
+    "merge",
+    "nl2br",
+    "number_format",
+    "plural",
+    "raw",
+    "reduce",
+    "replace",
+    "reverse",
+    "round",
+    "shuffle",
+    "singular",
+    "slice",
+    "slug",
+    "sort",
+    "spaceless",
+    "split",
+    "striptags",
+    "timezone_name",
+    "title",
+    "trim",
+    "u",
+    "upper",
+    "url_encode",
+    // symfony
+    "abbr_class",
+    "abbr_method",
+    "file_excerpt",
+    "file_link",
+    "file_relative",
+    "format_args",
+    "format_args_as_text",
+    "format_file",
+    "format_file_from_text",
+    "humanize",
+    "sanitize_html",
+    "serialize",
+    "trans",
+    "yaml_dump",
+    "yaml_encode",
+    // shopware
+    "boolval",
+    "currency",
+    "floatval",
+    "intval",
+    "json_decode",
+    "md5",
+    "replace_recursive",
+    "strval",
+    "sw_convert_unit",
+    "sw_encode_media_url",
+    "sw_encode_url",
+    "sw_sanitize",
+];
+
+/// Maximum edit distance for suggesting a known filter
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+pub struct RuleUnknownTwigFilter;
+
+impl Rule for RuleUnknownTwigFilter {
+    fn name(&self) -> &'static str {
+        "unknown-twig-filter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig filters are built in or on the configured allow list"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let filter = TwigFilter::cast(node)?;
+
+        // the filter name is the last operand (after the pipe)
+        let filter_name = filter
+            .syntax()
+            .children()
+            .filter(|c| c.kind() == SyntaxKind::TWIG_OPERAND)
+            .last()?
+            .first_child()
+            .filter(|c| c.kind() == SyntaxKind::TWIG_LITERAL_NAME)?
+            .first_token()?;
+
+        let allowed_filters = &ctx.config().rules.unknown_twig_filter_allowed;
+        let name = filter_name.text();
+        if KNOWN_FILTERS.contains(&name) || allowed_filters.iter().any(|allowed| allowed == name) {
+            return None;
+        }
+
+        // only mentioned in the note, because a custom filter can be close to a known one
+        let closest = KNOWN_FILTERS
+            .iter()
+            .copied()
+            .chain(allowed_filters.iter().map(String::as_str))
+            .map(|known| (known, edit_distance(name, known)))
+            .filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+            .min_by_key(|(_, distance)| *distance);
+        let did_you_mean = closest
+            .map(|(closest, _)| format!(" (did you mean '{closest}'?)"))
+            .unwrap_or_default();
+        let result = self
+            .create_result(Severity::Warning, "Unknown twig filter")
+            .primary_note(
+                filter_name.text_range(),
+                format!("'{name}' is not a known filter{did_you_mean}, custom filters can be added to unknown-twig-filter-allowed"),
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_does_not_fix, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "unknown-twig-filter",
+            "{{ a|defualt('x') }}{{ b|trim|my_custom_filter }}",
+            expect![[r"
+                warning[unknown-twig-filter]: Unknown twig filter
+                  ┌─ ./debug-rule.html.twig:1:6
+                  │
+                1 │ {{ a|defualt('x') }}{{ b|trim|my_custom_filter }}
+                  │      ^^^^^^^ 'defualt' is not a known filter (did you mean 'default'?), custom filters can be added to unknown-twig-filter-allowed
+
+                warning[unknown-twig-filter]: Unknown twig filter
+                  ┌─ ./debug-rule.html.twig:1:31
+                  │
+                1 │ {{ a|defualt('x') }}{{ b|trim|my_custom_filter }}
+                  │                               ^^^^^^^^^^^^^^^^ 'my_custom_filter' is not a known filter, custom filters can be added to unknown-twig-filter-allowed
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_allowed_filters() {
+        test_rule_with_config(
+            "unknown-twig-filter",
+            "{{ a|default('x')|trans|sw_sanitize }}{{ b|my_custom_filter(1, 2) }}",
+            |config| {
+                config.rules.unknown_twig_filter_allowed = vec!["my_custom_filter".to_owned()];
+            },
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_fix_custom_filters() {
+        test_rule_does_not_fix(
+            "unknown-twig-filter",
+            "{{ data|json }}{{ name|uc }}",
+            expect!["{{ data|json }}{{ name|uc }}"],
+        );
+    }
+}
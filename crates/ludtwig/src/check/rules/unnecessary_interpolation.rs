@@ -0,0 +1,131 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, TwigExpression, TwigLiteralString, TwigVar};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+use ludtwig_parser::T;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+pub struct RuleUnnecessaryInterpolation;
+
+impl Rule for RuleUnnecessaryInterpolation {
+    fn name(&self) -> &'static str {
+        "unnecessary-interpolation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig outputs which only consist of a constant string"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_var = TwigVar::cast(node)?;
+        let expression: TwigExpression = support::child(twig_var.syntax())?;
+
+        // plain text can't trim the surrounding whitespace like `{{-` or `-}}`
+        let delimiters = [
+            support::token(twig_var.syntax(), T!["{{"])?,
+            support::token(twig_var.syntax(), T!["}}"])?,
+        ];
+        if delimiters.iter().any(|token| token.text().len() > 2) {
+            return None;
+        }
+
+        // the string literal must be the whole expression (no filters, functions or operators)
+        let mut children = expression.syntax().children();
+        let literal = children.next().and_then(TwigLiteralString::cast)?;
+        if children.next().is_some() {
+            return None;
+        }
+
+        // interpolation, escape sequences or characters which would be escaped on output
+        // can't be written as plain text
+        let inner = literal.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None;
+        }
+        let text = inner.syntax().text().to_string();
+        if text.trim().is_empty() || text.contains(['\\', '<', '>', '&', '{', '}', '"', '\'', '\n'])
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(
+                Severity::Help,
+                "Unnecessary interpolation of a constant string",
+            )
+            .primary_note(
+                twig_var.syntax().text_range_trimmed_trivia(),
+                "this only outputs a constant string",
+            )
+            .suggestion(
+                twig_var.syntax().text_range_trimmed_trivia(),
+                text,
+                "Write the text directly",
+            );
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "unnecessary-interpolation",
+            r#"<p>{{ "hi" }}</p>"#,
+            expect![[r#"
+                help[unnecessary-interpolation]: Unnecessary interpolation of a constant string
+                  ┌─ ./debug-rule.html.twig:1:4
+                  │
+                1 │ <p>{{ "hi" }}</p>
+                  │    ^^^^^^^^^^
+                  │    │
+                  │    this only outputs a constant string
+                  │    Write the text directly: hi
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "unnecessary-interpolation",
+            r#"{{ "text"|trans }}{{ "a" ~ b }}{{ "foo #{bar}" }}{{ "<b>" }}{{ " " }}{{ hi }}{{- "trimmed" }}"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "unnecessary-interpolation",
+            r#"<p>{{ "hi" }}</p><a title="{{ 'some title' }}"></a>"#,
+            expect![[r#"<p>hi</p><a title="some title"></a>"#]],
+        );
+    }
+
+    #[test]
+    fn rule_fixes_multi_line() {
+        test_rule_fix(
+            "unnecessary-interpolation",
+            "<div>\n    {{ \"literal\" }}\n</div>",
+            expect![[r"
+                <div>
+                    literal
+                </div>"]],
+        );
+    }
+}
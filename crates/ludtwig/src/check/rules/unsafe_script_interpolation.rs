@@ -0,0 +1,149 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, HtmlTag, TwigExpression, TwigVar};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::output_filters;
+
+/// Script types whose content is executed as javascript or parsed as JSON
+static SCRIPT_TYPES: &[&str] = &[
+    "text/javascript",
+    "application/javascript",
+    "module",
+    "application/json",
+    "application/ld+json",
+    "importmap",
+];
+
+pub struct RuleUnsafeScriptInterpolation;
+
+impl Rule for RuleUnsafeScriptInterpolation {
+    fn name(&self) -> &'static str {
+        "unsafe-script-interpolation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig outputs inside of script or style elements without a fitting escaping strategy"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let twig_var = TwigVar::cast(node)?;
+
+        // only output inside of the body (not the attributes) of the closest element
+        let tag = twig_var.syntax().ancestors().find_map(HtmlTag::cast)?;
+        if tag
+            .starting_tag()?
+            .syntax()
+            .text_range()
+            .contains_range(twig_var.syntax().text_range())
+        {
+            return None;
+        }
+
+        let tag_name = tag.name()?.text().to_ascii_lowercase();
+        let (context, encodings) = match tag_name.as_str() {
+            "script" if is_script_executed(&tag) => ("javascript", "|json_encode or |e('js')"),
+            "style" => ("css", "|e('css')"),
+            _ => return None,
+        };
+
+        let expression: TwigExpression = support::child(twig_var.syntax())?;
+        if output_filters(expression.syntax())
+            .iter()
+            .any(|(name, strategy)| is_encoding_filter(&tag_name, name, strategy.as_deref()))
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Unsafe output inside of a script or style")
+            .primary_note(
+                twig_var.syntax().text_range_trimmed_trivia(),
+                format!("the html escaping of this output doesn't protect the {context} context against XSS"),
+            )
+            .secondary_note(
+                expression.syntax().text_range_trimmed_trivia(),
+                format!("help: encode the value with {encodings}"),
+            );
+
+        Some(vec![result])
+    }
+}
+
+fn is_script_executed(script: &HtmlTag) -> bool {
+    let script_type = script.attributes().find_map(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|n| n.text().eq_ignore_ascii_case("type"))
+            .then(|| attribute.value()?.get_inner())
+            .flatten()
+    });
+
+    match script_type {
+        None => true,
+        Some(inner) => {
+            let script_type = inner.syntax().text().to_string();
+            let script_type = script_type.trim();
+            script_type.is_empty()
+                || SCRIPT_TYPES
+                    .iter()
+                    .any(|t| script_type.eq_ignore_ascii_case(t))
+        }
+    }
+}
+
+fn is_encoding_filter(tag_name: &str, filter_name: &str, strategy: Option<&str>) -> bool {
+    let is_escape = matches!(filter_name, "e" | "escape");
+    match tag_name {
+        "script" => filter_name == "json_encode" || (is_escape && strategy == Some("js")),
+        _ => is_escape && strategy == Some("css"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "unsafe-script-interpolation",
+            r"<script>var x = {{ y }};</script>
+<style>.a { color: {{ color|raw }}; }</style>",
+            expect![[r"
+                warning[unsafe-script-interpolation]: Unsafe output inside of a script or style
+                  ┌─ ./debug-rule.html.twig:1:17
+                  │
+                1 │ <script>var x = {{ y }};</script>
+                  │                 ^^^^^^^
+                  │                 │ │
+                  │                 │ help: encode the value with |json_encode or |e('js')
+                  │                 the html escaping of this output doesn't protect the javascript context against XSS
+
+                warning[unsafe-script-interpolation]: Unsafe output inside of a script or style
+                  ┌─ ./debug-rule.html.twig:2:20
+                  │
+                2 │ <style>.a { color: {{ color|raw }}; }</style>
+                  │                    ^^^^^^^^^^^^^^^
+                  │                    │ │
+                  │                    │ help: encode the value with |e('css')
+                  │                    the html escaping of this output doesn't protect the css context against XSS
+
+            "]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "unsafe-script-interpolation",
+            r#"<script src="{{ asset('a.js') }}">var x = {{ y|json_encode|raw }};</script>
+<script>var x = '{{ y|e('js') }}';</script>
+<script type="text/x-template"><p>{{ y }}</p></script>
+<style>.a { color: {{ color|escape('css') }}; }</style>
+<div>{{ y }}</div>"#,
+            expect![""],
+        );
+    }
+}
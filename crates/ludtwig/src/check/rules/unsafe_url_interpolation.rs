@@ -0,0 +1,182 @@
+use ludtwig_parser::syntax::typed::{support, AstNode, HtmlAttribute, TwigExpression, TwigVar};
+use ludtwig_parser::syntax::untyped::{SyntaxKind, SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::output_filters;
+
+pub struct RuleUnsafeUrlInterpolation;
+
+impl Rule for RuleUnsafeUrlInterpolation {
+    fn name(&self) -> &'static str {
+        "unsafe-url-interpolation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twig outputs as whole url attribute values without a url safe filter"
+    }
+
+    fn check_node(&self, node: SyntaxNode, ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        let rules_config = &ctx.config().rules;
+        if !rules_config
+            .unsafe_url_attributes
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(attribute_name.text()))
+        {
+            return None;
+        }
+
+        // only values which consist of a single twig output
+        let inner = attribute.value()?.get_inner()?;
+        if inner
+            .syntax()
+            .children_with_tokens()
+            .filter(|element| !element.kind().is_trivia())
+            .count()
+            != 1
+        {
+            return None;
+        }
+        let twig_var = inner.syntax().children().find_map(TwigVar::cast)?;
+        let expression: TwigExpression = support::child(twig_var.syntax())?;
+
+        // literals and function calls (like path or asset) are not user controlled
+        if !matches!(
+            output_value(expression.syntax())?.kind(),
+            SyntaxKind::TWIG_LITERAL_NAME
+                | SyntaxKind::TWIG_ACCESSOR
+                | SyntaxKind::TWIG_INDEX_LOOKUP
+        ) {
+            return None;
+        }
+
+        let safe_filters = &rules_config.unsafe_url_safe_filters;
+        if output_filters(expression.syntax())
+            .iter()
+            .any(|(name, strategy)| {
+                safe_filters.iter().any(|safe| {
+                    safe == name
+                        || strategy
+                            .as_ref()
+                            .is_some_and(|strategy| *safe == format!("{name}('{strategy}')"))
+                })
+            })
+        {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Unsafe output inside of a url attribute")
+            .primary_note(
+                twig_var.syntax().text_range_trimmed_trivia(),
+                format!(
+                    "the html escaping of this output doesn't protect the '{}' url against injection (like 'javascript:' urls)",
+                    attribute_name.text()
+                ),
+            )
+            .secondary_note(
+                expression.syntax().text_range_trimmed_trivia(),
+                "help: only allow safe url schemes (like 'https:') or generate the url with a function like path() or url()",
+            );
+
+        Some(vec![result])
+    }
+}
+
+/// The innermost value of an output expression (without the applied filters)
+fn output_value(expression: &SyntaxNode) -> Option<SyntaxNode> {
+    let mut current = expression.first_child()?;
+    while current.kind() == SyntaxKind::TWIG_FILTER {
+        current = current
+            .children()
+            .find(|c| c.kind() == SyntaxKind::TWIG_OPERAND)?
+            .first_child()?;
+    }
+
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_with_config};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "unsafe-url-interpolation",
+            r#"<a href="{{ user_url }}">a</a>
+<img src="{{ user.avatar|raw }}">"#,
+            expect![[r#"
+                warning[unsafe-url-interpolation]: Unsafe output inside of a url attribute
+                  ┌─ ./debug-rule.html.twig:1:10
+                  │
+                1 │ <a href="{{ user_url }}">a</a>
+                  │          ^^^^^^^^^^^^^^
+                  │          │ │
+                  │          │ help: only allow safe url schemes (like 'https:') or generate the url with a function like path() or url()
+                  │          the html escaping of this output doesn't protect the 'href' url against injection (like 'javascript:' urls)
+
+                warning[unsafe-url-interpolation]: Unsafe output inside of a url attribute
+                  ┌─ ./debug-rule.html.twig:2:11
+                  │
+                2 │ <img src="{{ user.avatar|raw }}">
+                  │           ^^^^^^^^^^^^^^^^^^^^^
+                  │           │ │
+                  │           │ help: only allow safe url schemes (like 'https:') or generate the url with a function like path() or url()
+                  │           the html escaping of this output doesn't protect the 'src' url against injection (like 'javascript:' urls)
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "unsafe-url-interpolation",
+            r#"<a href="{{ user_url|e('url') }}">a</a>
+<a href="{{ user_url|url_encode }}">a</a>
+<a href="/search?q={{ query }}">a</a>
+<a href="{{ path('frontend.home.page') }}">a</a>
+<img src="{{ asset('logo.svg') }}">
+<form action="{{ 'https://example.com' }}"></form>
+<div title="{{ user_url }}"></div>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report_configured_filters() {
+        test_rule_with_config(
+            "unsafe-url-interpolation",
+            r#"<a href="{{ url|sanitize_url }}">a</a>"#,
+            |config| config.rules.unsafe_url_safe_filters = vec!["sanitize_url".to_owned()],
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_reports_configured_attributes() {
+        test_rule_with_config(
+            "unsafe-url-interpolation",
+            r#"<a href="{{ url }}">a</a><a data-href="{{ url|url_encode }}">a</a>"#,
+            |config| {
+                config.rules.unsafe_url_attributes = vec!["data-href".to_owned()];
+                config.rules.unsafe_url_safe_filters = vec!["sanitize_url".to_owned()];
+            },
+            expect![[r#"
+                warning[unsafe-url-interpolation]: Unsafe output inside of a url attribute
+                  ┌─ ./debug-rule.html.twig:1:40
+                  │
+                1 │ <a href="{{ url }}">a</a><a data-href="{{ url|url_encode }}">a</a>
+                  │                                        ^^^^^^^^^^^^^^^^^^^^
+                  │                                        │ │
+                  │                                        │ help: only allow safe url schemes (like 'https:') or generate the url with a function like path() or url()
+                  │                                        the html escaping of this output doesn't protect the 'data-href' url against injection (like 'javascript:' urls)
+
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextRange, TextSize};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::edit_distance;
+
+/// Non abstract roles of WAI-ARIA 1.2
+static ARIA_ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Maximum edit distance for suggesting a valid role
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+pub struct RuleValidRole;
+
+impl Rule for RuleValidRole {
+    fn name(&self) -> &'static str {
+        "valid-role"
+    }
+
+    fn description(&self) -> &'static str {
+        "Role attributes only contain valid ARIA roles"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        if !attribute.name()?.text().eq_ignore_ascii_case("role") {
+            return None;
+        }
+
+        // dynamic role values (containing twig syntax) are skipped
+        let inner = attribute.value()?.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None;
+        }
+
+        // the role attribute may contain a space separated list of fallback roles
+        let text = inner.syntax().text().to_string();
+        let inner_start = inner.syntax().text_range().start();
+        let mut results = vec![];
+        let mut offset = 0;
+        for role in text.split_ascii_whitespace() {
+            let position = offset + text[offset..].find(role)?;
+            offset = position + role.len();
+
+            let lowercase_role = role.to_ascii_lowercase();
+            if ARIA_ROLES.contains(&lowercase_role.as_str()) {
+                continue;
+            }
+
+            let range = TextRange::at(
+                inner_start + TextSize::try_from(position).ok()?,
+                TextSize::try_from(role.len()).ok()?,
+            );
+            let mut result = self
+                .create_result(Severity::Warning, "Invalid ARIA role")
+                .primary_note(range, format!("'{role}' is not a valid role"));
+            if let Some(closest) = closest_role(&lowercase_role) {
+                result = result.suggestion(range, closest, "Did you mean this role?");
+            }
+            results.push(result);
+        }
+
+        Some(results)
+    }
+}
+
+fn closest_role(role: &str) -> Option<&'static str> {
+    ARIA_ROLES
+        .iter()
+        .map(|valid| (valid, edit_distance(role, valid)))
+        .filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(valid, _)| *valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "valid-role",
+            r#"<div role="buton"></div>"#,
+            expect![[r#"
+                warning[valid-role]: Invalid ARIA role
+                  ┌─ ./debug-rule.html.twig:1:12
+                  │
+                1 │ <div role="buton"></div>
+                  │            ^^^^^
+                  │            │
+                  │            'buton' is not a valid role
+                  │            Did you mean this role?: button
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_reports_without_suggestion() {
+        test_rule(
+            "valid-role",
+            r#"<div role="switch something"></div>"#,
+            expect![[r#"
+                warning[valid-role]: Invalid ARIA role
+                  ┌─ ./debug-rule.html.twig:1:19
+                  │
+                1 │ <div role="switch something"></div>
+                  │                   ^^^^^^^^^ 'something' is not a valid role
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "valid-role",
+            r#"<nav role="navigation"></nav><div role="None"></div><div role="{{ role }}"></div><div role="tab{{ suffix }}"></div>"#,
+            expect![""],
+        );
+    }
+}
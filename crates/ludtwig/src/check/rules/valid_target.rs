@@ -0,0 +1,148 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute};
+use ludtwig_parser::syntax::untyped::{SyntaxNode, SyntaxNodeExt};
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+use crate::check::rules::edit_distance;
+
+/// Browsing context keywords (every other valid name must not start with an underscore)
+static TARGET_KEYWORDS: &[&str] = &["_self", "_blank", "_parent", "_top"];
+
+pub struct RuleValidTarget;
+
+impl Rule for RuleValidTarget {
+    fn name(&self) -> &'static str {
+        "valid-target"
+    }
+
+    fn description(&self) -> &'static str {
+        "Target attributes have a valid value"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let attribute = HtmlAttribute::cast(node)?;
+        let attribute_name = attribute.name()?;
+        if !attribute_name.text().eq_ignore_ascii_case("target") {
+            return None;
+        }
+
+        let inner = attribute.value().and_then(|v| v.get_inner());
+        // dynamic values (containing twig syntax) are skipped
+        if inner
+            .as_ref()
+            .is_some_and(|inner| inner.syntax().first_child().is_some())
+        {
+            return None;
+        }
+        let value = inner
+            .as_ref()
+            .map(|inner| inner.syntax().text().to_string())
+            .unwrap_or_default();
+        let trimmed_value = value.trim();
+
+        let result = match inner {
+            Some(inner) if !trimmed_value.is_empty() => {
+                if !trimmed_value.starts_with('_')
+                    || TARGET_KEYWORDS
+                        .iter()
+                        .any(|k| trimmed_value.eq_ignore_ascii_case(k))
+                {
+                    return None; // a keyword or custom browsing context (frame / window) name
+                }
+
+                let lowercase_value = trimmed_value.to_ascii_lowercase();
+                let nearest = TARGET_KEYWORDS
+                    .iter()
+                    .min_by_key(|k| edit_distance(&lowercase_value, k))?;
+                self.create_result(Severity::Warning, "Invalid target value")
+                    .primary_note(
+                        inner.syntax().text_range(),
+                        format!(
+                            "'{value}' is not a valid target, expected one of: {} or a frame name",
+                            TARGET_KEYWORDS.join(", ")
+                        ),
+                    )
+                    .suggestion(
+                        inner.syntax().text_range(),
+                        *nearest,
+                        "Did you mean this target?",
+                    )
+            }
+            _ => self
+                .create_result(Severity::Warning, "Missing target value")
+                .primary_note(
+                    attribute.syntax().text_range_trimmed_trivia(),
+                    format!(
+                        "target without a value opens the link in the same tab, use one of: {}",
+                        TARGET_KEYWORDS.join(", ")
+                    ),
+                ),
+        };
+
+        Some(vec![result])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::{test_rule, test_rule_fix};
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "valid-target",
+            r#"<a href="/a" target="_blnk">a</a>
+<a href="/b" target>b</a>
+<a href="/c" target="">c</a>"#,
+            expect![[r#"
+                warning[valid-target]: Invalid target value
+                  ┌─ ./debug-rule.html.twig:1:22
+                  │
+                1 │ <a href="/a" target="_blnk">a</a>
+                  │                      ^^^^^
+                  │                      │
+                  │                      '_blnk' is not a valid target, expected one of: _self, _blank, _parent, _top or a frame name
+                  │                      Did you mean this target?: _blank
+
+                warning[valid-target]: Missing target value
+                  ┌─ ./debug-rule.html.twig:2:14
+                  │
+                2 │ <a href="/b" target>b</a>
+                  │              ^^^^^^ target without a value opens the link in the same tab, use one of: _self, _blank, _parent, _top
+
+                warning[valid-target]: Missing target value
+                  ┌─ ./debug-rule.html.twig:3:14
+                  │
+                3 │ <a href="/c" target="">c</a>
+                  │              ^^^^^^^^^ target without a value opens the link in the same tab, use one of: _self, _blank, _parent, _top
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "valid-target",
+            r#"<a href="/a" target="_blank">a</a>
+<a href="/b" target="_TOP">b</a>
+<a href="/c" target="preview-frame">c</a>
+<form action="/d" target="{{ target }}"></form>"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn rule_fixes() {
+        test_rule_fix(
+            "valid-target",
+            r#"<a href="/a" target="_blnk">a</a><a href="/b" target="_parnet">b</a>"#,
+            expect![[r#"<a href="/a" target="_blank">a</a><a href="/b" target="_parent">b</a>"#]],
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use ludtwig_parser::syntax::typed::{AstNode, HtmlAttribute, HtmlTag};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+
+use crate::check::rule::{CheckResult, Rule, RuleExt, RuleRunContext, Severity};
+
+/// Users must be able to zoom to at least 200% (WCAG 1.4.4 Resize Text)
+const MIN_MAXIMUM_SCALE: f64 = 2.0;
+
+pub struct RuleViewportScalable;
+
+impl Rule for RuleViewportScalable {
+    fn name(&self) -> &'static str {
+        "viewport-scalable"
+    }
+
+    fn description(&self) -> &'static str {
+        "The viewport meta tag doesn't prevent zooming"
+    }
+
+    fn check_node(&self, node: SyntaxNode, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
+        let tag = HtmlTag::cast(node)?;
+        if !tag.name()?.text().eq_ignore_ascii_case("meta") {
+            return None;
+        }
+
+        let is_viewport = find_attribute(&tag, "name")
+            .and_then(|a| a.value())
+            .and_then(|v| v.get_inner())
+            .is_some_and(|inner| {
+                inner
+                    .syntax()
+                    .text()
+                    .to_string()
+                    .trim()
+                    .eq_ignore_ascii_case("viewport")
+            });
+        if !is_viewport {
+            return None;
+        }
+
+        // dynamic values (containing twig syntax) are skipped
+        let inner = find_attribute(&tag, "content")?.value()?.get_inner()?;
+        if inner.syntax().first_child().is_some() {
+            return None;
+        }
+        let content = inner.syntax().text().to_string();
+
+        let mut problems = vec![];
+        for (key, value) in content.split([',', ';']).filter_map(|p| p.split_once('=')) {
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim().to_ascii_lowercase();
+            match key.as_str() {
+                "user-scalable" if value == "no" || value == "0" => {
+                    problems.push(format!("'user-scalable={value}' disables zooming"));
+                }
+                "maximum-scale"
+                    if value
+                        .parse::<f64>()
+                        .is_ok_and(|scale| scale < MIN_MAXIMUM_SCALE) =>
+                {
+                    problems.push(format!(
+                        "'maximum-scale={value}' limits zooming to less than {MIN_MAXIMUM_SCALE}x"
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if problems.is_empty() {
+            return None;
+        }
+
+        let result = self
+            .create_result(Severity::Warning, "Viewport prevents zooming")
+            .primary_note(inner.syntax().text_range(), problems.join(" and "))
+            .secondary_note(
+                inner.syntax().text_range(),
+                "users with low vision rely on zooming, remove these restrictions",
+            );
+
+        Some(vec![result])
+    }
+}
+
+fn find_attribute(tag: &HtmlTag, name: &str) -> Option<HtmlAttribute> {
+    tag.attributes().find(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|n| n.text().eq_ignore_ascii_case(name))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::check::rules::test::test_rule;
+
+    #[test]
+    fn rule_reports() {
+        test_rule(
+            "viewport-scalable",
+            r#"<meta name="viewport" content="width=device-width, initial-scale=1, maximum-scale=1, user-scalable=no">"#,
+            expect![[r#"
+                warning[viewport-scalable]: Viewport prevents zooming
+                  ┌─ ./debug-rule.html.twig:1:32
+                  │
+                1 │ <meta name="viewport" content="width=device-width, initial-scale=1, maximum-scale=1, user-scalable=no">
+                  │                                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                  │                                │
+                  │                                'maximum-scale=1' limits zooming to less than 2x and 'user-scalable=no' disables zooming
+                  │                                users with low vision rely on zooming, remove these restrictions
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rule_does_not_report() {
+        test_rule(
+            "viewport-scalable",
+            r#"<meta name="viewport" content="width=device-width, initial-scale=1, maximum-scale=5">
+<meta name="description" content="maximum-scale=1">"#,
+            expect![""],
+        );
+    }
+}
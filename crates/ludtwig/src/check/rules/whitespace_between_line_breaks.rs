@@ -9,6 +9,18 @@ impl Rule for RuleWhitespaceBetweenLineBreaks {
         "whitespace-between-line-breaks"
     }
 
+    fn description(&self) -> &'static str {
+        "Empty lines don't contain whitespace"
+    }
+
+    fn supports_autofix(&self) -> bool {
+        true
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Help
+    }
+
     fn check_token(&self, token: SyntaxToken, _ctx: &RuleRunContext) -> Option<Vec<CheckResult>> {
         // rule only inspects line breaks
         if token.kind() != SyntaxKind::TK_LINE_BREAK {
@@ -51,7 +63,7 @@ mod tests {
 \t
             <hr/>
 {% endblock %}",
-            expect![[r#"
+            expect![[r"
                 help[whitespace-between-line-breaks]: Whitespace between line breaks
                   ┌─ ./debug-rule.html.twig:2:1
                   │
@@ -61,7 +73,7 @@ mod tests {
                   │ Unexpected whitespace on empty line
                   │ Remove whitespace: 
 
-            "#]],
+            "]],
         );
     }
 
@@ -73,11 +85,11 @@ mod tests {
 \t
             <hr/>
 {% endblock %}",
-            expect![[r#"
+            expect![[r"
                 {% block my_block %}
 
                             <hr/>
-                {% endblock %}"#]],
+                {% endblock %}"]],
         );
     }
 }
@@ -1,26 +1,51 @@
-use std::fmt::{Display, Formatter};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Write};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use figment::providers::{Env, Format as FigFormat, Toml};
+use figment::providers::{Env, Format as FigFormat, Serialized, Toml};
+use figment::value::Value;
 use figment::Figment;
-use serde::Deserialize;
+use ludtwig_parser::syntax::typed::{AstNode, LudtwigDirectiveConfig};
+use ludtwig_parser::syntax::untyped::SyntaxNode;
+use serde::{Deserialize, Serialize};
 
-use crate::Opts;
+use crate::check::rule::Rule;
+use crate::check::rules::RULE_DEFINITIONS;
+use crate::error::ConfigurationError;
+use crate::{Command, Opts};
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
     pub general: General,
+    pub severity: BTreeMap<String, RuleSeverity>,
     pub format: Format,
+    pub rules: Rules,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct General {
     pub active_rules: Vec<String>,
+    pub additional_void_elements: Vec<String>,
+    pub max_fix_iterations: usize,
+    pub ignore: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+/// Severity override for all reports of a rule
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum RuleSeverity {
+    Error,
+    Warning,
+    Help,
+    Info,
+    /// the rule doesn't run at all
+    Off,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct Format {
     pub line_ending: LineEnding,
@@ -32,7 +57,55 @@ pub struct Format {
     pub html_quotation: Quotation,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Rules {
+    pub img_dimensions_require_both: bool,
+    pub number_input_bounds_attributes: Vec<String>,
+    pub script_loading_check_body: bool,
+    pub null_coalescing_style: NullCoalescingStyle,
+    pub boolean_literal_casing: LiteralCasing,
+    pub block_name_manifest: Vec<String>,
+    pub block_name_manifest_only_overrides: bool,
+    pub block_name_denylist: Vec<String>,
+    pub no_trailing_content_after_close_tags: Vec<String>,
+    pub framework_directive_prefixes: Vec<String>,
+    pub framework_directive_patterns: Vec<String>,
+    pub empty_element_format: EmptyElementFormat,
+    pub unknown_twig_filter_allowed: Vec<String>,
+    pub closing_bracket_placement: ClosingBracketPlacement,
+    pub trailing_slash_links: TrailingSlashPolicy,
+    pub declare_extends_or_standalone_marker: String,
+    pub large_inline_svg_max_elements: usize,
+    pub large_inline_svg_max_lines: usize,
+    pub max_template_size_lines: usize,
+    pub max_template_size_nodes: usize,
+    pub internal_twig_variables: Vec<String>,
+    pub hex_color_casing: LiteralCasing,
+    pub loop_item_key_attributes: Vec<String>,
+    pub twig_concat_spacing: ConcatSpacing,
+    pub block_name_content_contracts: Vec<BlockContentContract>,
+    pub dangerous_twig_functions: Vec<String>,
+    pub dangerous_twig_dynamic_includes: bool,
+    pub data_boolean_attributes: Vec<String>,
+    pub data_boolean_format: DataBooleanFormat,
+    pub twig_block_name_prefix_separator: String,
+    pub set_block_for_long_values_max_length: usize,
+    pub unsafe_url_attributes: Vec<String>,
+    pub unsafe_url_safe_filters: Vec<String>,
+    pub include_with_key_order: Vec<String>,
+    pub div_soup_max_siblings: usize,
+    pub no_inline_style_allowed_tags: Vec<String>,
+    pub commented_out_code_min_elements: usize,
+    /// no limit if it is not set
+    pub max_block_nesting: Option<usize>,
+    pub autocomplete_password_values: Vec<String>,
+    pub autocomplete_card_field_names: Vec<String>,
+    pub no_duplicate_set_ignored_variables: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub enum IndentationMode {
     Space,
@@ -61,7 +134,7 @@ impl IndentationMode {
     }
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 pub enum LineEnding {
     #[serde(rename = "unix_LF")]
     UnixLF,
@@ -91,7 +164,7 @@ impl LineEnding {
     }
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 pub enum Quotation {
     #[serde(rename = "single")]
     Single,
@@ -121,10 +194,92 @@ impl Quotation {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum NullCoalescingStyle {
+    /// `x ?? y`
+    Operator,
+    /// `x|default(y)`
+    DefaultFilter,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum LiteralCasing {
+    Lowercase,
+    Uppercase,
+}
+
+impl LiteralCasing {
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            LiteralCasing::Lowercase => text.to_ascii_lowercase(),
+            LiteralCasing::Uppercase => text.to_ascii_uppercase(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmptyElementFormat {
+    /// `<div></div>`
+    SameLine,
+    /// `<div>` and `</div>` on separate lines
+    Expanded,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClosingBracketPlacement {
+    /// `>` directly behind the last attribute
+    SameLine,
+    /// `>` on its own line with the indentation of the tag
+    OwnLine,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum TrailingSlashPolicy {
+    /// `/account/`
+    Always,
+    /// `/account`
+    Never,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConcatSpacing {
+    /// `a ~ b`
+    Spaced,
+    /// `a~b`
+    Compact,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum DataBooleanFormat {
+    /// `data-open="true"` and `data-open="false"`
+    TrueFalse,
+    /// `data-open="1"` and `data-open="0"`
+    OneZero,
+    /// `data-open` (false can only be expressed by leaving out the attribute)
+    Presence,
+}
+
+/// Elements which must be inside of every block whose name matches the pattern
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct BlockContentContract {
+    /// regular expression for the block names
+    pub block_name_pattern: String,
+    pub required_elements: Vec<String>,
+}
+
 pub const DEFAULT_CONFIG_PATH: &str = "./ludtwig-config.toml";
 pub const DEFAULT_RAW_CONFIG: &str = include_str!("../ludtwig-config.toml");
 
 impl Config {
+    #[allow(clippy::result_large_err)]
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, figment::Error> {
         let config: Config = Figment::new()
             // first read the raw config from memory (for default values)
@@ -141,9 +296,125 @@ impl Config {
 
         Ok(config)
     }
+
+    /// Create a copy of this config with a single option overridden by a raw value.
+    /// The key is the kebab-case name of the option without its section (like `indentation-count`).
+    pub fn with_inline_option(&self, key: &str, value: &str) -> Result<Self, ConfigurationError> {
+        let figment = Figment::from(Serialized::defaults(self));
+
+        // only options inside these sections can be overridden inline
+        let Some(path) = ["format", "rules"]
+            .iter()
+            .map(|section| format!("{section}.{key}"))
+            .find(|path| figment.find_value(path).is_ok())
+        else {
+            return Err(ConfigurationError::UnknownInlineOption {
+                key: key.to_string(),
+            });
+        };
+
+        let parsed_value = Value::from_str(value).unwrap_or_else(|e| match e {});
+        figment.merge((path, parsed_value)).extract().map_err(|_| {
+            ConfigurationError::InvalidInlineOptionValue {
+                key: key.to_string(),
+                value: value.to_string(),
+            }
+        })
+    }
+}
+
+/// Get the config for a specific file based on the global config.
+/// Options of ludtwig-config directives on the top level of the file override the global ones.
+/// Invalid options are skipped here and reported by the `ludtwig-config-valid` rule.
+pub fn get_file_config(root: &SyntaxNode, config: &Config) -> Config {
+    let mut file_config = config.clone();
+
+    for option in root
+        .children()
+        .filter_map(LudtwigDirectiveConfig::cast)
+        .flat_map(|directive| directive.get_options())
+    {
+        let (Some(key), Some(value)) = (option.get_key(), option.get_value()) else {
+            continue;
+        };
+
+        if let Ok(new_config) = file_config.with_inline_option(key.text(), value.text()) {
+            file_config = new_config;
+        }
+    }
+
+    file_config
+}
+
+/// The default config where the active rules list contains every rule with its description.
+/// Rules which are not active by default are commented out.
+pub fn scaffold_config() -> String {
+    let default_config: Config = Figment::new()
+        .merge(Toml::string(DEFAULT_RAW_CONFIG))
+        .extract()
+        .expect("the default config should be valid");
+    let (active, inactive): (Vec<&&dyn Rule>, Vec<_>) = RULE_DEFINITIONS.iter().partition(|rule| {
+        default_config
+            .general
+            .active_rules
+            .iter()
+            .any(|name| name == rule.name())
+    });
+
+    let mut active_rules = String::from("active-rules = [\n");
+    for rule in active {
+        writeln!(
+            active_rules,
+            "    \"{}\", # {}",
+            rule.name(),
+            rule.description()
+        )
+        .unwrap();
+    }
+    active_rules
+        .push_str("    # the following rules are off by default (uncomment to activate them)\n");
+    for rule in inactive {
+        writeln!(
+            active_rules,
+            "    # \"{}\", # {}",
+            rule.name(),
+            rule.description()
+        )
+        .unwrap();
+    }
+    active_rules.push(']');
+
+    // replace the active rules list of the default config
+    let start = DEFAULT_RAW_CONFIG
+        .find("active-rules = [")
+        .expect("the default config should contain the active rules");
+    let end = start
+        + DEFAULT_RAW_CONFIG[start..]
+            .find("\n]")
+            .expect("the active rules should end with a line break and ']'")
+        + 2;
+    format!(
+        "{}{active_rules}{}",
+        &DEFAULT_RAW_CONFIG[..start],
+        &DEFAULT_RAW_CONFIG[end..]
+    )
 }
 
 pub fn handle_config_or_exit(opts: &Opts) -> Config {
+    if let Some(Command::Init { force }) = opts.command {
+        let config_path = Path::new(DEFAULT_CONFIG_PATH);
+        if config_path.exists() && !force {
+            println!("The configuration file already exists in the current directory. \
+            Use 'ludtwig init --force' to overwrite it or make a backup of your current config file (rename it).");
+            std::process::exit(1);
+        }
+
+        std::fs::write(config_path, scaffold_config()).expect("can't write config");
+        println!("Config with all rules was written to {DEFAULT_CONFIG_PATH}");
+
+        std::process::exit(0);
+    }
+
     let config_path = opts
         .config_path
         .clone()
@@ -169,8 +440,31 @@ pub fn handle_config_or_exit(opts: &Opts) -> Config {
         Ok(c) => c,
         Err(e) => {
             println!("Error reading configuration:");
-            println!("{}", e);
+            println!("{e}");
             std::process::exit(1);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaffold_config_round_trips() {
+        let scaffold = scaffold_config();
+        let config: Config = Figment::new()
+            .merge(Toml::string(&scaffold))
+            .extract()
+            .unwrap();
+        let default_config: Config = Figment::new()
+            .merge(Toml::string(DEFAULT_RAW_CONFIG))
+            .extract()
+            .unwrap();
+
+        assert_eq!(config, default_config);
+        for rule in RULE_DEFINITIONS {
+            assert!(scaffold.contains(&format!("\"{}\", # {}", rule.name(), rule.description())));
+        }
+    }
+}
@@ -12,7 +12,13 @@ pub enum FileProcessingError {
         path: PathBuf,
         io_error: std::io::Error,
     },
-    MaxApplyIteration,
+    StdinRead {
+        io_error: std::io::Error,
+    },
+    MaxApplyIteration {
+        path: PathBuf,
+        iterations: usize,
+    },
     OverlappingSuggestionInSingleRule {
         rule_name: String,
     },
@@ -27,11 +33,14 @@ impl Display for FileProcessingError {
             FileProcessingError::FileWrite { path, .. } => {
                 write!(f, "file {} can't be written", path.to_string_lossy())
             }
-            FileProcessingError::MaxApplyIteration => {
-                write!(f, "max suggestion apply iteration encountered. This may be caused by fighting rules (programmer error) or too many conflicting suggestions at once")
+            FileProcessingError::StdinRead { .. } => {
+                write!(f, "stdin can't be read")
+            }
+            FileProcessingError::MaxApplyIteration { path, iterations } => {
+                write!(f, "max suggestion apply iteration encountered in file {} after {iterations} iterations. This may be caused by fighting rules (programmer error) or too many conflicting suggestions at once (see 'max-fix-iterations' config)", path.to_string_lossy())
             }
             FileProcessingError::OverlappingSuggestionInSingleRule { rule_name } => {
-                write!(f, "Suggestion collision inside the same rule, check rule {} or write bug report - this is a programmer error", rule_name)
+                write!(f, "Suggestion collision inside the same rule, check rule {rule_name} or write bug report - this is a programmer error")
             }
         }
     }
@@ -41,8 +50,9 @@ impl Error for FileProcessingError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             FileProcessingError::FileRead { io_error, .. }
-            | FileProcessingError::FileWrite { io_error, .. } => Some(io_error),
-            FileProcessingError::MaxApplyIteration
+            | FileProcessingError::FileWrite { io_error, .. }
+            | FileProcessingError::StdinRead { io_error } => Some(io_error),
+            FileProcessingError::MaxApplyIteration { .. }
             | FileProcessingError::OverlappingSuggestionInSingleRule { .. } => None,
         }
     }
@@ -52,13 +62,21 @@ impl Error for FileProcessingError {
 #[derive(Debug)]
 pub enum ConfigurationError {
     RuleNotFound { name: String },
+    UnknownInlineOption { key: String },
+    InvalidInlineOptionValue { key: String, value: String },
 }
 
 impl Display for ConfigurationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ConfigurationError::RuleNotFound { name } => {
-                write!(f, "Can't find active rule {}", name)
+                write!(f, "Can't find active rule {name}")
+            }
+            ConfigurationError::UnknownInlineOption { key } => {
+                write!(f, "Unknown config option {key}")
+            }
+            ConfigurationError::InvalidInlineOptionValue { key, value } => {
+                write!(f, "Invalid value {value} for config option {key}")
             }
         }
     }
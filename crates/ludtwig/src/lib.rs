@@ -0,0 +1,396 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::module_name_repetitions)]
+
+use crate::check::rule::{Rule, Severity};
+use crate::check::rules::{get_config_active_rule_definitions, get_unknown_severity_rule_names};
+use crate::config::Config;
+use crate::output::{OutputFormat, ProcessingEvent};
+use clap::{Parser, Subcommand};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::types::TypesBuilder;
+use ignore::{WalkBuilder, WalkState};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench;
+mod check;
+mod config;
+mod error;
+mod output;
+mod process;
+mod watch;
+
+// uses author, version and description from Cargo.toml
+#[derive(Parser, Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+pub struct Opts {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Files or directories to scan
+    #[arg(
+        value_name = "FILE",
+        num_args = 1..,
+        required = true,
+        conflicts_with_all = ["create_config", "stdin", "list_rules"],
+        name = "files"
+    )]
+    files: Vec<PathBuf>,
+
+    /// Skip files and directories matching this glob pattern (relative to the current directory, like 'vendor' or 'vendor/**/*.twig'). Can be repeated.
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Apply all code suggestions automatically. This changes the original files!
+    #[arg(short = 'f', long)]
+    fix: bool,
+
+    /// Print a unified diff of the changes --fix would make to stdout without changing any files
+    #[arg(long, conflicts_with = "fix")]
+    fix_dry_run: bool,
+
+    /// Print out the parsed syntax tree for each file
+    #[arg(short = 'i', long)]
+    inspect: bool,
+
+    /// Exit with a non-zero code if help diagnostics remain (errors and warnings always fail the run)
+    #[arg(long)]
+    deny_help: bool,
+
+    /// Specify where the ludtwig configuration file is. Ludtwig looks in the current directory for a 'ludtwig-config.toml' by default.
+    #[arg(short = 'c', long)]
+    config_path: Option<PathBuf>,
+
+    /// Create the default configuration file in the config path. Defaults to the current directory.
+    #[arg(short = 'C', long, name = "create_config")]
+    create_config: bool,
+
+    /// Output format of the diagnostics.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+    format: OutputFormat,
+
+    /// Read the template from stdin instead of files. Together with --fix the fixed template is written to stdout.
+    #[arg(long, conflicts_with = "create_config")]
+    stdin: bool,
+
+    /// File path of the template read from stdin (used for reporting and the file specific configuration).
+    #[arg(long, value_name = "PATH", requires = "stdin")]
+    stdin_filepath: Option<PathBuf>,
+
+    /// Maximum number of threads used to scan and process files. Defaults to the number of CPU cores.
+    #[arg(long, value_name = "N")]
+    threads: Option<NonZeroUsize>,
+
+    /// Print all rules with their description, severity and autofix support (uses the config to show which are active).
+    #[arg(long, name = "list_rules")]
+    list_rules: bool,
+
+    /// Keep running after the first scan and check changed files again until the process is stopped.
+    #[arg(short = 'w', long, conflicts_with_all = ["stdin", "fix_dry_run"])]
+    watch: bool,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Write a 'ludtwig-config.toml' into the current directory, which lists every rule with its description.
+    Init {
+        /// Overwrite the configuration file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Context to pass to every processing thead (can be cloned)
+#[derive(Debug)]
+pub struct CliContext {
+    /// Channel sender for transmitting messages back to the CLI.
+    pub output_tx: Sender<ProcessingEvent>,
+    /// Shared Data
+    pub data: Arc<CliSharedData>,
+}
+
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CliSharedData {
+    /// Apply all code suggestions automatically. This changes the original files!
+    pub fix: bool,
+    /// Compute the fixes but only print them as a diff instead of changing the files
+    pub fix_dry_run: bool,
+    /// Print out the parsed syntax tree for each file
+    pub inspect: bool,
+    /// Remaining help diagnostics also result in a non-zero exit code
+    pub deny_help: bool,
+    /// Output format of the diagnostics
+    pub output_format: OutputFormat,
+    /// The template is read from stdin (and fixes are written to stdout)
+    pub stdin: bool,
+    /// The config values to use.
+    pub config: Config,
+    /// Config active rule definitions
+    pub rule_definitions: Vec<&'static dyn Rule>,
+}
+
+impl CliSharedData {
+    /// stdout is reserved for the machine-readable document, the fixed template from stdin
+    /// or the diff of a dry run
+    #[must_use]
+    pub fn is_stdout_reserved(&self) -> bool {
+        self.output_format.is_machine_readable() || (self.stdin && self.fix) || self.fix_dry_run
+    }
+}
+
+impl Clone for CliContext {
+    fn clone(&self) -> Self {
+        Self {
+            output_tx: self.output_tx.clone(),
+            data: Arc::clone(&self.data),
+        }
+    }
+}
+
+impl CliContext {
+    /// # Panics
+    /// If the output receiver was already dropped.
+    pub fn send_processing_output(&self, event: ProcessingEvent) {
+        self.output_tx
+            .send(event)
+            .expect("output should still receive ProcessingEvents");
+    }
+}
+
+/// Parse the CLI arguments, bootstrap the application and return the exit code.
+#[must_use]
+pub fn run() -> i32 {
+    let opts: Opts = Opts::parse();
+    let config = config::handle_config_or_exit(&opts);
+
+    app(opts, config)
+}
+
+/// The entry point of the async application.
+fn app(opts: Opts, config: Config) -> i32 {
+    if opts.list_rules {
+        return output::rule_list::print_rule_list(&config, opts.format);
+    }
+
+    if opts.stdin && opts.fix && opts.format.is_machine_readable() {
+        eprintln!("Error: --stdin with --fix writes the fixed template to stdout and can't be combined with a machine-readable --format");
+        return 1;
+    }
+
+    if opts.fix_dry_run && opts.format.is_machine_readable() {
+        eprintln!("Error: --fix-dry-run writes the diff to stdout and can't be combined with a machine-readable --format");
+        return 1;
+    }
+
+    if opts.watch && opts.format.is_machine_readable() {
+        eprintln!("Error: --watch checks files continuously and can't be combined with a machine-readable --format, which aggregates a single document");
+        return 1;
+    }
+
+    // files excluded by the command line or the config
+    let excludes = match build_exclude_set(opts.exclude.iter().chain(&config.general.ignore)) {
+        Ok(excludes) => excludes,
+        Err(e) => {
+            eprintln!("Error: invalid exclude glob pattern: {e}");
+            return 1;
+        }
+    };
+
+    // construct active rules
+    let active_rules = match get_config_active_rule_definitions(&config) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return 1;
+        }
+    };
+    for name in get_unknown_severity_rule_names(&config) {
+        eprintln!("Warning: unknown rule '{name}' in the [severity] config section is ignored");
+    }
+
+    let data = Arc::new(CliSharedData {
+        fix: opts.fix,
+        fix_dry_run: opts.fix_dry_run,
+        inspect: opts.inspect,
+        deny_help: opts.deny_help,
+        output_format: opts.format,
+        stdin: opts.stdin,
+        config,
+        rule_definitions: active_rules,
+    });
+
+    if !data.is_stdout_reserved() {
+        println!("Scanning files...");
+    }
+
+    let process_code = run_pass(&data, |cli_context| {
+        if opts.stdin {
+            let path = opts
+                .stdin_filepath
+                .unwrap_or_else(|| PathBuf::from("stdin.html.twig"));
+            if let Err(e) = process::process_stdin(path, cli_context.clone()) {
+                cli_context.send_processing_output(ProcessingEvent::Report(Severity::Error));
+                eprintln!("Error: {e}");
+            }
+        } else {
+            // work on each user specified file / directory path concurrently
+            // zero lets the walker and the thread pool choose the number of threads
+            let threads = opts.threads.map_or(0, NonZeroUsize::get);
+            handle_input_paths(&opts.files, threads, &excludes, cli_context);
+        }
+    });
+
+    if opts.watch {
+        return watch::watch_paths(&opts.files, &excludes, &data);
+    }
+
+    process_code
+}
+
+/// Run the processing of some files with its own output handler, which reports the results
+/// of exactly these files. Returns the exit code of the output handler.
+fn run_pass(data: &Arc<CliSharedData>, work: impl FnOnce(CliContext)) -> i32 {
+    // sender and receiver channels for the communication between tasks and the user.
+    let (tx, rx) = mpsc::channel();
+    let cli_context = CliContext {
+        output_tx: tx,
+        data: Arc::clone(data),
+    };
+
+    let output_data = Arc::clone(data);
+    let output_handler = thread::spawn(move || output::handle_processing_output(&rx, &output_data));
+
+    // this drops the last tx channel after processing
+    work(cli_context);
+
+    // the output_handler will finish execution if all the tx (sending channel) ends are closed.
+    output_handler
+        .join()
+        .expect("Error: can't join output_handler thread")
+}
+
+fn build_exclude_set<'a>(
+    patterns: impl Iterator<Item = &'a String>,
+) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    builder.build()
+}
+
+/// Exclude patterns are relative to the current directory
+fn is_excluded(excludes: &GlobSet, current_dir: Option<&Path>, path: &Path) -> bool {
+    let relative = current_dir
+        .and_then(|dir| path.strip_prefix(dir).ok())
+        .unwrap_or(path);
+    let relative = relative.strip_prefix(".").unwrap_or(relative);
+    excludes.is_match(relative)
+}
+
+/// Walker over all the template files in the user specified paths,
+/// which respects the `.gitignore` and `.ludtwig-ignore` files and doesn't enter excluded directories.
+/// Excluded files are still walked (to count them as skipped).
+fn template_walker(paths: &[PathBuf], excludes: &GlobSet) -> WalkBuilder {
+    let types = TypesBuilder::new()
+        .add_defaults()
+        .select("twig")
+        .select("html")
+        .build()
+        .unwrap();
+
+    let mut walker = WalkBuilder::new(&paths[0]);
+    for path in &paths[1..] {
+        walker.add(path);
+    }
+
+    let excludes = excludes.clone();
+    let current_dir = std::env::current_dir().ok();
+    walker
+        .add_custom_ignore_filename(".ludtwig-ignore")
+        .types(types)
+        .filter_entry(move |entry| {
+            entry.depth() == 0
+                || entry.file_type().is_none_or(|t| !t.is_dir())
+                || !is_excluded(&excludes, current_dir.as_deref(), entry.path())
+        });
+    walker
+}
+
+/// Process a directory path.
+fn handle_input_paths(
+    paths: &[PathBuf],
+    threads: usize,
+    excludes: &GlobSet,
+    cli_context: CliContext,
+) {
+    let walker = template_walker(paths, excludes)
+        .threads(threads)
+        .build_parallel();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Error: can't build the file processing thread pool");
+
+    let current_dir = std::env::current_dir().ok();
+    let current_dir = current_dir.as_deref();
+
+    // parallel directory traversal but move the work for each file to a different thread in the thread pool.
+    pool.scope(move |s| {
+        walker.run(|| {
+            let cli_context = cli_context.clone();
+
+            Box::new(move |entry| {
+                let entry = match entry {
+                    Ok(e) => e,
+                    Err(e) => {
+                        eprintln!("Error: walking over the file path: {e}");
+                        cli_context
+                            .send_processing_output(ProcessingEvent::Report(Severity::Error));
+                        return WalkState::Continue;
+                    }
+                };
+
+                // filter out directories
+                if entry.file_type().is_none_or(|t| t.is_dir()) {
+                    return WalkState::Continue;
+                }
+
+                if is_excluded(excludes, current_dir, entry.path()) {
+                    cli_context.send_processing_output(ProcessingEvent::FileSkipped);
+                    return WalkState::Continue;
+                }
+
+                let clone = cli_context.clone();
+                let tx_clone = cli_context.output_tx.clone();
+                s.spawn(
+                    move |_s1| match process::process_file(entry.path().into(), clone) {
+                        Ok(()) => {}
+                        Err(e) => {
+                            tx_clone
+                                .send(ProcessingEvent::Report(Severity::Error))
+                                .expect("output should still receive ProcessingEvents");
+                            eprintln!("Error: {e}");
+                        }
+                    },
+                );
+
+                WalkState::Continue
+            })
+        });
+    });
+}
@@ -1,25 +1,61 @@
+use clap::ValueEnum;
 use codespan_reporting::term::termcolor::{Buffer, BufferWriter, ColorChoice};
 use std::io;
 use std::io::Write;
 use std::sync::mpsc::Receiver;
 
 use crate::check::rule::Severity;
+use crate::output::json::JsonRecord;
+use crate::output::sarif::{SarifLog, SarifResult};
+use crate::CliSharedData;
+
+pub mod diff;
+pub mod json;
+pub mod rule_list;
+pub mod sarif;
+
+/// Format of the reported diagnostics
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable diagnostics on stderr
+    Human,
+    /// A single SARIF 2.1.0 document on stdout (for CI integration)
+    Sarif,
+    /// A single JSON array of diagnostic records on stdout (for editor tooling)
+    Json,
+}
+
+impl OutputFormat {
+    /// Machine-readable formats reserve stdout for their document
+    pub fn is_machine_readable(self) -> bool {
+        self != OutputFormat::Human
+    }
+}
 
 pub enum ProcessingEvent {
     FileProcessed,
+    /// The file was excluded by a glob pattern and not processed
+    FileSkipped,
     Report(Severity),
     OutputStderrMessage(Buffer),
+    OutputSarifResults(Vec<SarifResult>),
+    OutputJsonRecords(Vec<JsonRecord>),
+    OutputDiff(String),
 }
 
 /// This function receives all the [`CliOutputMessage`] instances from the receiver channel and
 /// prints information to the command line interface.
-pub fn handle_processing_output(rx: &Receiver<ProcessingEvent>) -> i32 {
+pub fn handle_processing_output(rx: &Receiver<ProcessingEvent>, data: &CliSharedData) -> i32 {
     let mut file_count = 0;
+    let mut skipped_count = 0;
     let mut error_count = 0;
     let mut warning_count = 0;
     let mut help_count = 0;
     let mut info_count = 0;
 
+    let mut sarif_results = vec![];
+    let mut json_records = vec![];
+
     let stderr_writer = BufferWriter::stderr(ColorChoice::Always);
 
     // receive all incoming messages until all sending ends are closed.
@@ -28,6 +64,9 @@ pub fn handle_processing_output(rx: &Receiver<ProcessingEvent>) -> i32 {
             ProcessingEvent::FileProcessed => {
                 file_count += 1;
             }
+            ProcessingEvent::FileSkipped => {
+                skipped_count += 1;
+            }
             ProcessingEvent::Report(severity) => match severity {
                 Severity::Error => {
                     error_count += 1;
@@ -45,14 +84,42 @@ pub fn handle_processing_output(rx: &Receiver<ProcessingEvent>) -> i32 {
             ProcessingEvent::OutputStderrMessage(buffer) => {
                 stderr_writer.print(&buffer).unwrap();
             }
+            ProcessingEvent::OutputSarifResults(mut results) => {
+                sarif_results.append(&mut results);
+            }
+            ProcessingEvent::OutputJsonRecords(mut records) => {
+                json_records.append(&mut records);
+            }
+            ProcessingEvent::OutputDiff(diff) => {
+                print!("{diff}");
+            }
         }
     }
 
     drop(stderr_writer); // finish writing to stderr
 
+    // all files are aggregated into a single document
+    let mut stdout = io::stdout().lock();
+    match data.output_format {
+        OutputFormat::Human => {}
+        OutputFormat::Sarif => {
+            let rule_names: Vec<&'static str> =
+                data.rule_definitions.iter().map(|r| r.name()).collect();
+            let log = SarifLog::new(&rule_names, sarif_results);
+            serde_json::to_writer_pretty(&mut stdout, &log).unwrap();
+            writeln!(stdout).unwrap();
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &json_records).unwrap();
+            writeln!(stdout).unwrap();
+        }
+    }
+    drop(stdout);
+
     let conclusion_msg = format!(
-        "\nFiles scanned: {}, Errors: {}, Warnings: {}, Helps: {}, Info: {}, Total: {}\n",
+        "\nFiles scanned: {}, Files skipped: {}, Errors: {}, Warnings: {}, Helps: {}, Info: {}, Total: {}\n",
         file_count,
+        skipped_count,
         error_count,
         warning_count,
         help_count,
@@ -60,11 +127,91 @@ pub fn handle_processing_output(rx: &Receiver<ProcessingEvent>) -> i32 {
         (error_count + warning_count + help_count + info_count)
     );
 
-    if file_count > 0 && (error_count > 0 || warning_count > 0 || help_count > 0) {
+    // remaining errors (including syntax errors) and warnings fail the run, help only with --deny-help
+    if file_count > 0
+        && (error_count > 0 || warning_count > 0 || (data.deny_help && help_count > 0))
+    {
         io::stderr().write_all(conclusion_msg.as_bytes()).unwrap();
-        1 // return exit code 1 if there were errors, warnings or help.
+        1
     } else {
-        print!("{}", conclusion_msg);
+        if data.is_stdout_reserved() {
+            eprint!("{conclusion_msg}");
+        } else {
+            print!("{conclusion_msg}");
+        }
         0
     }
 }
+
+/// One based line and column (in UTF-16 code units) of the byte offset in the source
+/// where '\r\n' counts as a single line break
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    let mut chars = source.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if index >= offset {
+            break;
+        }
+
+        match c {
+            '\r' if chars.peek().is_some_and(|(_, next)| *next == '\n') => {
+                // an offset between '\r' and '\n' still points at the line break
+                if index + 1 >= offset {
+                    break;
+                }
+                chars.next();
+                line += 1;
+                column = 1;
+            }
+            '\n' => {
+                line += 1;
+                column = 1;
+            }
+            c => column += c.len_utf16(),
+        }
+    }
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::line_column;
+
+    #[test]
+    fn line_column_first_line() {
+        assert_eq!(line_column("<div>\n</div>", 0), (1, 1));
+        assert_eq!(line_column("<div>\n</div>", 3), (1, 4));
+    }
+
+    #[test]
+    fn line_column_last_line_without_trailing_line_break() {
+        let source = "<div>\n    <span>\n</div>";
+        assert_eq!(line_column(source, 18), (3, 2));
+        assert_eq!(line_column(source, source.len()), (3, 7));
+        assert_eq!(line_column(source, source.len() + 10), (3, 7));
+    }
+
+    #[test]
+    fn line_column_line_break_boundary() {
+        // the line break itself belongs to the line it ends
+        assert_eq!(line_column("a\nb", 1), (1, 2));
+        assert_eq!(line_column("a\nb", 2), (2, 1));
+        assert_eq!(line_column("a\n", 2), (2, 1));
+    }
+
+    #[test]
+    fn line_column_crlf() {
+        let source = "a\r\nb\r\nc";
+        assert_eq!(line_column(source, 1), (1, 2));
+        assert_eq!(line_column(source, 2), (1, 2));
+        assert_eq!(line_column(source, 3), (2, 1));
+        assert_eq!(line_column(source, 6), (3, 1));
+    }
+
+    #[test]
+    fn line_column_utf16() {
+        assert_eq!(line_column("äö𝄞x", "äö𝄞".len()), (1, 5));
+    }
+}
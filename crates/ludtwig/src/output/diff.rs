@@ -0,0 +1,180 @@
+//! Unified diff of the changes made by fixing a file, which can be applied with `patch`.
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use dissimilar::Chunk;
+
+/// Unchanged lines shown around every change
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineChange {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Unified diff between the original and fixed source code of the file
+/// (empty if there are no changes).
+pub fn unified_diff(path: &Path, original: &str, fixed: &str) -> String {
+    let lines = diff_lines(original, fixed);
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, (change, _))| *change != LineChange::Equal)
+        .map(|(i, _)| i)
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    let path = path.to_string_lossy();
+    let mut diff = format!("--- {path}\n+++ {path}\n");
+
+    // group changes whose context overlaps into hunks
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for i in changed {
+        let start = i.saturating_sub(CONTEXT_LINES);
+        let end = (i + CONTEXT_LINES + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        let count_before = |change: LineChange| {
+            lines[..start]
+                .iter()
+                .filter(|(c, _)| *c == LineChange::Equal || *c == change)
+                .count()
+        };
+        let count_inside = |change: LineChange| {
+            lines[start..end]
+                .iter()
+                .filter(|(c, _)| *c == LineChange::Equal || *c == change)
+                .count()
+        };
+        let (original_count, fixed_count) = (
+            count_inside(LineChange::Delete),
+            count_inside(LineChange::Insert),
+        );
+        // an empty range starts at the line before it
+        let original_start = count_before(LineChange::Delete) + usize::from(original_count > 0);
+        let fixed_start = count_before(LineChange::Insert) + usize::from(fixed_count > 0);
+
+        let _ = writeln!(
+            diff,
+            "@@ -{original_start},{original_count} +{fixed_start},{fixed_count} @@"
+        );
+        for (change, line) in &lines[start..end] {
+            let marker = match change {
+                LineChange::Equal => ' ',
+                LineChange::Delete => '-',
+                LineChange::Insert => '+',
+            };
+            diff.push(marker);
+            diff.push_str(line);
+            if !line.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+
+    diff
+}
+
+/// Line based diff (each line includes its line break).
+/// Every distinct line is encoded as a single char to diff the lines like characters.
+fn diff_lines<'a>(original: &'a str, fixed: &'a str) -> Vec<(LineChange, &'a str)> {
+    let mut distinct_lines: Vec<&str> = vec![];
+    let mut line_indices: HashMap<&str, usize> = HashMap::new();
+    let mut encode = |text: &'a str| -> String {
+        text.split_inclusive('\n')
+            .map(|line| {
+                let index = *line_indices.entry(line).or_insert_with(|| {
+                    distinct_lines.push(line);
+                    distinct_lines.len() - 1
+                });
+                // start after the basic multilingual plane to only use valid chars
+                char::from_u32(0x10000 + u32::try_from(index).unwrap()).unwrap()
+            })
+            .collect()
+    };
+    let encoded_original = encode(original);
+    let encoded_fixed = encode(fixed);
+
+    dissimilar::diff(&encoded_original, &encoded_fixed)
+        .into_iter()
+        .flat_map(|chunk| {
+            let (change, encoded) = match chunk {
+                Chunk::Equal(e) => (LineChange::Equal, e),
+                Chunk::Delete(e) => (LineChange::Delete, e),
+                Chunk::Insert(e) => (LineChange::Insert, e),
+            };
+            encoded
+                .chars()
+                .map(|c| (change, distinct_lines[(c as usize) - 0x10000]))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use expect_test::expect;
+
+    use super::unified_diff;
+
+    #[test]
+    fn diff_contains_hunks_with_context() {
+        let original = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+        let fixed = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        expect![[r"
+            --- ./templates/a.html.twig
+            +++ ./templates/a.html.twig
+            @@ -1,5 +1,5 @@
+             a
+            -b
+            +B
+             c
+             d
+             e
+            @@ -10,4 +10,3 @@
+             j
+             k
+             l
+            -m
+        "]]
+        .assert_eq(&unified_diff(
+            Path::new("./templates/a.html.twig"),
+            original,
+            fixed,
+        ));
+    }
+
+    #[test]
+    fn diff_marks_missing_final_newline() {
+        expect![[r"
+            --- a.twig
+            +++ a.twig
+            @@ -1,1 +1,1 @@
+            -<div></div>
+            \ No newline at end of file
+            +<div></div>
+        "]]
+        .assert_eq(&unified_diff(
+            Path::new("a.twig"),
+            "<div></div>",
+            "<div></div>\n",
+        ));
+    }
+
+    #[test]
+    fn diff_is_empty_without_changes() {
+        assert_eq!(unified_diff(Path::new("a.twig"), "a\nb\n", "a\nb\n"), "");
+    }
+}
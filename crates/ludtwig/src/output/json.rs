@@ -0,0 +1,111 @@
+//! Conversion of check results into flat JSON records, which are easy to consume by editor tooling.
+use ludtwig_parser::syntax::untyped::TextRange;
+use serde::Serialize;
+
+use crate::check::rule::{CheckResult, CheckSuggestion, Severity};
+use crate::output::line_column;
+use crate::process::FileContext;
+
+#[derive(Debug, Serialize)]
+pub struct JsonRecord {
+    file: String,
+    /// Name of the rule or "syntax" for parser errors
+    rule: &'static str,
+    severity: &'static str,
+    message: String,
+    start: JsonPosition,
+    end: JsonPosition,
+    /// The first suggested fix of the result (if there is any)
+    replacement: Option<JsonReplacement>,
+}
+
+/// One based line and column (in UTF-16 code units) plus the byte offset in the file
+#[derive(Debug, Serialize)]
+struct JsonPosition {
+    line: usize,
+    column: usize,
+    offset: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonReplacement {
+    start: JsonPosition,
+    end: JsonPosition,
+    text: String,
+    message: String,
+}
+
+/// Convert the parser errors and rule check results of a single file into JSON records
+pub fn file_records(file_context: &FileContext, rule_results: &[CheckResult]) -> Vec<JsonRecord> {
+    let file = file_context.file_path.to_string_lossy().to_string();
+    let source = &file_context.source_code;
+
+    let parse_errors = file_context.parse_errors.iter().map(|error| JsonRecord {
+        file: file.clone(),
+        rule: "syntax",
+        severity: severity(&Severity::Error),
+        message: format!(
+            "The parser encountered a syntax error: {}",
+            error.expected_message()
+        ),
+        start: position(source, error.range.start().into()),
+        end: position(source, error.range.end().into()),
+        replacement: None,
+    });
+
+    let check_results = rule_results.iter().map(|result| {
+        // results without a primary note are reported at the start of the file
+        let range = result
+            .primary
+            .as_ref()
+            .map_or_else(TextRange::default, |primary| primary.syntax_range);
+
+        JsonRecord {
+            file: file.clone(),
+            rule: result.rule_name,
+            severity: severity(&result.severity),
+            message: match &result.primary {
+                Some(primary) if !primary.message.is_empty() => {
+                    format!("{}: {}", result.message, primary.message)
+                }
+                _ => result.message.clone(),
+            },
+            start: position(source, range.start().into()),
+            end: position(source, range.end().into()),
+            replacement: result
+                .suggestions
+                .first()
+                .map(|suggestion| replacement(source, suggestion)),
+        }
+    });
+
+    parse_errors.chain(check_results).collect()
+}
+
+fn replacement(source: &str, suggestion: &CheckSuggestion) -> JsonReplacement {
+    JsonReplacement {
+        start: position(source, suggestion.syntax_range.start().into()),
+        end: position(source, suggestion.syntax_range.end().into()),
+        text: suggestion.replace_with.clone(),
+        message: suggestion.message.clone(),
+    }
+}
+
+pub fn severity(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Help => "help",
+        Severity::Info => "info",
+    }
+}
+
+fn position(source: &str, offset: usize) -> JsonPosition {
+    let (line, column) = line_column(source, offset);
+
+    JsonPosition {
+        line,
+        column,
+        offset,
+    }
+}
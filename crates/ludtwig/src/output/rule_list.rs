@@ -0,0 +1,179 @@
+//! Overview of all the rules ludtwig knows about (printed by `--list-rules`).
+use std::io;
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::check::rules::RULE_DEFINITIONS;
+use crate::config::{Config, RuleSeverity};
+use crate::output::json::severity;
+use crate::output::OutputFormat;
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct RuleListEntry {
+    name: &'static str,
+    description: &'static str,
+    /// The rule is in the active rules of the config and not turned off
+    active: bool,
+    default_severity: &'static str,
+    /// Severity with the override of the config applied ("off" if the rule is turned off)
+    severity: &'static str,
+    autofix: bool,
+}
+
+/// All rule definitions sorted by name
+pub fn rule_list_entries(config: &Config) -> Vec<RuleListEntry> {
+    let mut entries: Vec<RuleListEntry> = RULE_DEFINITIONS
+        .iter()
+        .map(|rule| {
+            let default_severity = severity(&rule.default_severity());
+            let severity_override = config.severity.get(rule.name());
+            RuleListEntry {
+                name: rule.name(),
+                description: rule.description(),
+                active: config.general.active_rules.iter().any(|r| r == rule.name())
+                    && severity_override != Some(&RuleSeverity::Off),
+                default_severity,
+                severity: severity_override
+                    .copied()
+                    .map_or(default_severity, rule_severity),
+                autofix: rule.supports_autofix(),
+            }
+        })
+        .collect();
+    entries.sort_by_key(|entry| entry.name);
+    entries
+}
+
+/// Print the rule list to stdout and return the exit code
+pub fn print_rule_list(config: &Config, format: OutputFormat) -> i32 {
+    if format == OutputFormat::Sarif {
+        eprintln!("Error: --list-rules can only be combined with the human or json --format");
+        return 1;
+    }
+
+    let entries = rule_list_entries(config);
+    match write_rule_list(&entries, format, &mut io::stdout().lock()) {
+        // the reader stopped early (like `ludtwig --list-rules | head`)
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => 0,
+        Err(e) => {
+            eprintln!("Error: can't print the rule list: {e}");
+            1
+        }
+        Ok(()) => 0,
+    }
+}
+
+fn write_rule_list(
+    entries: &[RuleListEntry],
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        // sarif is rejected by print_rule_list
+        OutputFormat::Human | OutputFormat::Sarif => {
+            let name_width = entries.iter().map(|e| e.name.len()).max().unwrap_or(0);
+            writeln!(
+                out,
+                "{:name_width$}  Active  Severity  Autofix  Description",
+                "Rule"
+            )?;
+            for entry in entries {
+                writeln!(
+                    out,
+                    "{:name_width$}  {:6}  {:8}  {:7}  {}",
+                    entry.name,
+                    if entry.active { "yes" } else { "no" },
+                    entry.severity,
+                    if entry.autofix { "yes" } else { "no" },
+                    entry.description
+                )?;
+            }
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, entries)?;
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn rule_severity(severity: RuleSeverity) -> &'static str {
+    match severity {
+        RuleSeverity::Error => "error",
+        RuleSeverity::Warning => "warning",
+        RuleSeverity::Help => "help",
+        RuleSeverity::Info => "info",
+        RuleSeverity::Off => "off",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::DEFAULT_CONFIG_PATH;
+
+    use super::*;
+
+    #[test]
+    fn rule_list_applies_the_config() {
+        let mut config = Config::new(DEFAULT_CONFIG_PATH).unwrap();
+        config
+            .severity
+            .insert("final-newline".to_owned(), RuleSeverity::Off);
+        config
+            .severity
+            .insert("twig-logic-and".to_owned(), RuleSeverity::Warning);
+        let entries = rule_list_entries(&config);
+
+        assert_eq!(entries.len(), RULE_DEFINITIONS.len());
+        assert!(entries.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+        let entry = |name: &str| entries.iter().find(|e| e.name == name).unwrap();
+        assert_eq!(
+            entry("twig-logic-and"),
+            &RuleListEntry {
+                name: "twig-logic-and",
+                description: "The twig 'and' operator is used instead of '&&'",
+                active: true,
+                default_severity: "error",
+                severity: "warning",
+                autofix: true,
+            }
+        );
+        assert!(!entry("final-newline").active);
+        assert_eq!(entry("final-newline").severity, "off");
+        assert!(!entry("no-inline-style").active);
+    }
+
+    /// Accepts a single write before the reader is gone
+    struct ClosedAfterFirstWrite(bool);
+
+    impl Write for ClosedAfterFirstWrite {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.0 {
+                return Err(io::ErrorKind::BrokenPipe.into());
+            }
+            self.0 = true;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn rule_list_stops_on_broken_pipe() {
+        let config = Config::new(DEFAULT_CONFIG_PATH).unwrap();
+        let entries = rule_list_entries(&config);
+
+        let error = write_rule_list(
+            &entries,
+            OutputFormat::Human,
+            &mut ClosedAfterFirstWrite(false),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
@@ -0,0 +1,253 @@
+//! Conversion of check results into a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! document, which is understood by many CI systems (like GitHub code scanning).
+use std::path::Path;
+
+use ludtwig_parser::syntax::untyped::TextRange;
+use serde::Serialize;
+
+use crate::check::rule::{CheckResult, CheckSuggestion, Severity};
+use crate::output::line_column;
+use crate::process::FileContext;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+impl SarifLog {
+    /// A log with a single run over all processed files
+    pub fn new(rule_names: &[&'static str], results: Vec<SarifResult>) -> Self {
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: env!("CARGO_PKG_NAME"),
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules: rule_names
+                            .iter()
+                            .map(|name| SarifRule { id: name })
+                            .collect(),
+                    },
+                },
+                column_kind: "utf16CodeUnits",
+                results,
+            }],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRule {
+    id: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    rule_id: &'static str,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<SarifMessage>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifInsertedContent,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifInsertedContent {
+    text: String,
+}
+
+/// Convert the parser errors and rule check results of a single file into SARIF results
+pub fn file_results(file_context: &FileContext, rule_results: &[CheckResult]) -> Vec<SarifResult> {
+    let artifact = SarifArtifactLocation {
+        uri: file_uri(&file_context.file_path),
+    };
+    let source = &file_context.source_code;
+    let location = |range: TextRange, message: Option<String>| SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: artifact.clone(),
+            region: region(source, range),
+        },
+        message: message.map(|text| SarifMessage { text }),
+    };
+
+    let parse_errors = file_context.parse_errors.iter().map(|error| SarifResult {
+        rule_id: "SyntaxError",
+        level: level(&Severity::Error),
+        message: SarifMessage {
+            text: format!(
+                "The parser encountered a syntax error: {}",
+                error.expected_message()
+            ),
+        },
+        locations: vec![location(error.range, None)],
+        related_locations: vec![],
+        fixes: vec![],
+    });
+
+    let check_results = rule_results.iter().map(|result| SarifResult {
+        rule_id: result.rule_name,
+        level: level(&result.severity),
+        message: SarifMessage {
+            text: match &result.primary {
+                Some(primary) if !primary.message.is_empty() => {
+                    format!("{}: {}", result.message, primary.message)
+                }
+                _ => result.message.clone(),
+            },
+        },
+        locations: result
+            .primary
+            .iter()
+            .map(|primary| location(primary.syntax_range, None))
+            .collect(),
+        related_locations: result
+            .secondary
+            .iter()
+            .map(|secondary| location(secondary.syntax_range, Some(secondary.message.clone())))
+            .collect(),
+        fixes: result
+            .suggestions
+            .iter()
+            .map(|suggestion| fix(source, &artifact, suggestion))
+            .collect(),
+    });
+
+    parse_errors.chain(check_results).collect()
+}
+
+fn fix(source: &str, artifact: &SarifArtifactLocation, suggestion: &CheckSuggestion) -> SarifFix {
+    SarifFix {
+        description: SarifMessage {
+            text: suggestion.message.clone(),
+        },
+        artifact_changes: vec![SarifArtifactChange {
+            artifact_location: artifact.clone(),
+            replacements: vec![SarifReplacement {
+                deleted_region: region(source, suggestion.syntax_range),
+                inserted_content: SarifInsertedContent {
+                    text: suggestion.replace_with.clone(),
+                },
+            }],
+        }],
+    }
+}
+
+fn level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Help | Severity::Info => "note",
+    }
+}
+
+/// Relative file paths with forward slashes (as expected by most SARIF consumers)
+fn file_uri(path: &Path) -> String {
+    let uri = path.to_string_lossy().replace('\\', "/");
+    match uri.strip_prefix("./") {
+        Some(relative) => relative.to_owned(),
+        None => uri,
+    }
+}
+
+/// One based line and column (in UTF-16 code units) region of the text range
+fn region(source: &str, range: TextRange) -> SarifRegion {
+    let (start_line, start_column) = line_column(source, range.start().into());
+    let (end_line, end_column) = line_column(source, range.end().into());
+
+    SarifRegion {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    }
+}
@@ -1,18 +1,22 @@
 use std::collections::HashSet;
 use std::fs;
+use std::io;
+use std::io::Read;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use codespan_reporting::term::termcolor::{BufferWriter, ColorChoice};
 
-use ludtwig_parser::syntax::untyped::SyntaxNode;
-use ludtwig_parser::ParseError;
+use ludtwig_parser::syntax::untyped::{SyntaxNode, TextSize};
+use ludtwig_parser::{Parse, ParseError, ParseOptions};
 
-use crate::check::rule::{CheckResult, CheckSuggestion, Rule};
+use crate::check::rule::{CheckResult, CheckSuggestion, Rule, Severity};
 use crate::check::rules::get_file_active_rule_definitions;
 use crate::check::{get_rule_context_suggestions, produce_diagnostics, run_rules};
+use crate::config::get_file_config;
 use crate::error::FileProcessingError;
-use crate::output::ProcessingEvent;
-use crate::CliContext;
+use crate::output::{diff, json, sarif, OutputFormat, ProcessingEvent};
+use crate::{CliContext, Config};
 
 /// The context for a single file.
 #[derive(Debug)]
@@ -22,7 +26,7 @@ pub struct FileContext {
     /// The file path that is associated with this context
     pub file_path: PathBuf,
 
-    /// The parsed [SyntaxNode] AST for this file / context.
+    /// The parsed [`SyntaxNode`] AST for this file / context.
     pub tree_root: SyntaxNode,
 
     pub source_code: String,
@@ -32,6 +36,10 @@ pub struct FileContext {
     /// active rules for this specific file (may be less than global config definitions).
     /// these are defined after processing ludtwig-ignore-file directives
     pub file_rule_definitions: Vec<&'static dyn Rule>,
+
+    /// config for this specific file (may differ from the global config).
+    /// it is defined after processing ludtwig-config directives
+    pub file_config: Arc<Config>,
 }
 
 impl FileContext {
@@ -55,18 +63,37 @@ pub fn process_file(path: PathBuf, cli_context: CliContext) -> Result<(), FilePr
     run_analysis(path, file_content, cli_context)
 }
 
+/// Process a template read from stdin, the path is only used for reporting and the file config.
+pub fn process_stdin(path: PathBuf, cli_context: CliContext) -> Result<(), FileProcessingError> {
+    cli_context.send_processing_output(ProcessingEvent::FileProcessed);
+
+    let mut file_content = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut file_content) {
+        return Err(FileProcessingError::StdinRead { io_error: e });
+    }
+
+    run_analysis(path, file_content, cli_context)
+}
+
+#[allow(clippy::unnecessary_debug_formatting)]
 fn run_analysis(
     path: PathBuf,
     original_file_content: String,
     cli_context: CliContext,
 ) -> Result<(), FileProcessingError> {
-    let parse = ludtwig_parser::parse(&original_file_content);
+    let parse = parse_source(&original_file_content, &cli_context.data.config);
     let root = SyntaxNode::new_root(parse.green_node);
 
     let file_rule_definitions =
         get_file_active_rule_definitions(&root, &cli_context.data.rule_definitions);
-
-    let apply_suggestions = cli_context.data.fix;
+    let file_config = Arc::new(get_file_config(&root, &cli_context.data.config));
+
+    let apply_suggestions = cli_context.data.fix || cli_context.data.fix_dry_run;
+    // the original is only needed to show the changes of a dry run
+    let dry_run_original = cli_context
+        .data
+        .fix_dry_run
+        .then(|| original_file_content.clone());
     let file_context = FileContext {
         cli_context,
         file_path: path,
@@ -74,6 +101,7 @@ fn run_analysis(
         tree_root: root,
         parse_errors: parse.errors,
         file_rule_definitions,
+        file_config,
     };
 
     // run all the rules
@@ -82,24 +110,39 @@ fn run_analysis(
     // apply suggestions if needed
     let (file_context, rule_result_context) = if apply_suggestions {
         let (file_context, rule_result_context, dirty, iterations) =
-            match iteratively_apply_suggestions(file_context, rule_result_context) {
-                Ok(val) => val,
-                Err(e) => return Err(e),
-            };
-        if dirty {
+            iteratively_apply_suggestions(file_context, rule_result_context)?;
+        if let Some(original) = dry_run_original {
+            if dirty {
+                let diff = diff::unified_diff(
+                    &file_context.file_path,
+                    &original,
+                    &file_context.source_code,
+                );
+                file_context.send_processing_output(ProcessingEvent::OutputDiff(diff));
+            }
+        } else if file_context.cli_context.data.stdin {
+            // the (possibly unchanged) template is always written back for editor integrations
+            print!("{}", file_context.source_code);
+        } else if dirty {
             match fs::write(&file_context.file_path, &file_context.source_code) {
-                Ok(_) => {}
+                Ok(()) => {}
                 Err(e) => {
                     return Err(FileProcessingError::FileWrite {
                         path: file_context.file_path,
                         io_error: e,
                     })
                 }
-            };
-            println!(
+            }
+            let message = format!(
                 "fixed {:?} in {} iterations",
                 &file_context.file_path, iterations
             );
+            // stdout is reserved for the machine-readable document
+            if file_context.cli_context.data.is_stdout_reserved() {
+                eprintln!("{message}");
+            } else {
+                println!("{message}");
+            }
         }
 
         (file_context, rule_result_context)
@@ -108,47 +151,97 @@ fn run_analysis(
     };
 
     // send processing events for rule check results + parser errors and output them to the terminal
-    let writer = BufferWriter::stderr(ColorChoice::Always);
-    let mut buffer = writer.buffer();
-    produce_diagnostics(&file_context, rule_result_context, &mut buffer);
-    file_context.send_processing_output(ProcessingEvent::OutputStderrMessage(buffer));
+    match file_context.cli_context.data.output_format {
+        OutputFormat::Human => {
+            let writer = BufferWriter::stderr(ColorChoice::Always);
+            let mut buffer = writer.buffer();
+            produce_diagnostics(&file_context, rule_result_context, &mut buffer);
+            file_context.send_processing_output(ProcessingEvent::OutputStderrMessage(buffer));
+        }
+        OutputFormat::Sarif => {
+            let results = sarif::file_results(&file_context, &rule_result_context);
+            report_severities(&file_context, &rule_result_context);
+            file_context.send_processing_output(ProcessingEvent::OutputSarifResults(results));
+        }
+        OutputFormat::Json => {
+            let records = json::file_records(&file_context, &rule_result_context);
+            report_severities(&file_context, &rule_result_context);
+            file_context.send_processing_output(ProcessingEvent::OutputJsonRecords(records));
+        }
+    }
 
     Ok(())
 }
 
+/// Parse the source code with the parser options of the config
+pub(crate) fn parse_source(source_code: &str, config: &Config) -> Parse {
+    let options = ParseOptions {
+        additional_void_elements: config.general.additional_void_elements.clone(),
+    };
+    ludtwig_parser::parse_with_options(source_code, &options)
+}
+
+/// Notify the output about the severities of parser errors and rule check results
+fn report_severities(file_context: &FileContext, rule_results: &[CheckResult]) {
+    for _ in &file_context.parse_errors {
+        file_context.send_processing_output(ProcessingEvent::Report(Severity::Error));
+    }
+
+    for result in rule_results {
+        file_context.send_processing_output(ProcessingEvent::Report(result.severity.clone()));
+    }
+}
+
 pub fn iteratively_apply_suggestions(
     file_context: FileContext,
     check_results: Vec<CheckResult>,
 ) -> Result<(FileContext, Vec<CheckResult>, bool, usize), FileProcessingError> {
     let mut current_results = (file_context, check_results, false, 0);
 
-    // try at maximum 10 parsing iterations
-    for i in 0..10 {
-        if i >= 9 {
-            return Err(FileProcessingError::MaxApplyIteration);
-        }
-
+    let max_iterations = current_results.0.file_config.general.max_fix_iterations;
+    loop {
         let mut suggestions = get_rule_context_suggestions(&current_results.1);
+        // suggestions which don't change the source code would only cause another parse and rule run
+        let source_code = &current_results.0.source_code;
+        suggestions.retain(|(_, sug)| {
+            source_code
+                .get(usize::from(sug.syntax_range.start())..usize::from(sug.syntax_range.end()))
+                != Some(sug.replace_with.as_str())
+        });
         if suggestions.is_empty() {
             break;
         }
 
+        // apply suggestions at maximum `max_iterations` times
+        if current_results.3 >= max_iterations {
+            return Err(FileProcessingError::MaxApplyIteration {
+                path: current_results.0.file_path.clone(),
+                iterations: current_results.3,
+            });
+        }
+
         // sort by syntax range
-        suggestions
-            .sort_by(|(_, sug_a), (_, sug_b)| sug_a.syntax_range.ordering(sug_b.syntax_range));
+        suggestions.sort_by_key(|(_, sug)| (sug.syntax_range.start(), sug.syntax_range.end()));
 
-        // filter out overlapping suggestions
+        // filter out overlapping suggestions, adjacent ones (end == next start) are applied together
         let mut overlapping_rules = HashSet::new();
-        for ((rule_a, sug_a), (rule_b, sug_b)) in suggestions.iter().zip(suggestions.iter().skip(1))
-        {
-            if sug_a.syntax_range.ordering(sug_b.syntax_range).is_eq() {
-                if rule_a == rule_b {
-                    return Err(FileProcessingError::OverlappingSuggestionInSingleRule {
-                        rule_name: (*rule_a).to_string(),
-                    });
+        let mut furthest_end: Option<(&str, TextSize)> = None;
+        for (rule, sug) in &suggestions {
+            if let Some((furthest_rule, end)) = furthest_end {
+                if end > sug.syntax_range.start() {
+                    if furthest_rule == *rule {
+                        return Err(FileProcessingError::OverlappingSuggestionInSingleRule {
+                            rule_name: (*rule).to_string(),
+                        });
+                    }
+
+                    overlapping_rules.insert(*rule);
+                    continue;
                 }
+            }
 
-                overlapping_rules.insert(*rule_b);
+            if furthest_end.is_none_or(|(_, end)| sug.syntax_range.end() > end) {
+                furthest_end = Some((rule, sug.syntax_range.end()));
             }
         }
         let suggestions = suggestions
@@ -167,7 +260,7 @@ pub fn iteratively_apply_suggestions(
         let source_code = apply_suggestions_to_text(suggestions, current_results.0.source_code);
 
         // Parse the new source code again
-        let new_parse = ludtwig_parser::parse(&source_code);
+        let new_parse = parse_source(&source_code, &current_results.0.cli_context.data.config);
         let tree_root = SyntaxNode::new_root(new_parse.green_node);
 
         let file_context = FileContext {
@@ -0,0 +1,108 @@
+use std::collections::{BTreeSet, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use globset::GlobSet;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::check::rule::Severity;
+use crate::output::ProcessingEvent;
+use crate::{is_excluded, process, run_pass, template_walker, CliSharedData};
+
+/// Editors often write a file multiple times when saving, so wait until the events stop for this long
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+/// Watch the user specified paths and check every changed template again (until the process is stopped).
+/// Only returns if the file system can't be watched.
+pub fn watch_paths(paths: &[PathBuf], excludes: &GlobSet, data: &Arc<CliSharedData>) -> i32 {
+    let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("Error: can't watch the files: {e}");
+            return 1;
+        }
+    };
+    for path in paths {
+        if let Err(e) = watcher.watch(path, RecursiveMode::Recursive) {
+            eprintln!("Error: can't watch {}: {e}", path.to_string_lossy());
+            return 1;
+        }
+    }
+
+    let current_dir = std::env::current_dir().ok();
+    println!("\nWatching for changes...");
+
+    // wait for the first event of a change and collect all the following ones
+    while let Ok(event) = rx.recv() {
+        let mut changed_files = BTreeSet::new();
+        let mut next = Some(event);
+        while let Some(event) = next {
+            match event {
+                Ok(event) => changed_files.extend(changed_templates(event)),
+                Err(e) => eprintln!("Error: watching the files: {e}"),
+            }
+
+            next = match rx.recv_timeout(DEBOUNCE_DURATION) {
+                Ok(event) => Some(event),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => return 1,
+            };
+        }
+
+        // only the files which the initial pass would check (without ignored ones like 'node_modules')
+        let templates = walked_templates(paths, excludes);
+        changed_files.retain(|path| {
+            path.canonicalize()
+                .is_ok_and(|path| templates.contains(&path))
+                && !is_excluded(excludes, current_dir.as_deref(), path)
+        });
+        if changed_files.is_empty() {
+            continue;
+        }
+
+        // clear the terminal, so only the fresh results are visible
+        print!("\x1B[2J\x1B[1;1H");
+        let _ = std::io::stdout().flush();
+
+        run_pass(data, |cli_context| {
+            for path in changed_files {
+                if let Err(e) = process::process_file(path, cli_context.clone()) {
+                    cli_context.send_processing_output(ProcessingEvent::Report(Severity::Error));
+                    eprintln!("Error: {e}");
+                }
+            }
+        });
+        println!("\nWatching for changes...");
+    }
+
+    1
+}
+
+/// Paths of the created or modified twig files (like '.twig' or '.html.twig') of the event
+fn changed_templates(event: Event) -> impl Iterator<Item = PathBuf> {
+    let is_change = matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_));
+    event
+        .paths
+        .into_iter()
+        .filter(move |path| is_change && is_template(path))
+}
+
+fn is_template(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "twig")
+}
+
+/// Canonical paths of all the templates in the watched paths, which are not ignored
+/// by a `.gitignore` or `.ludtwig-ignore` file (the same ones the initial pass checks)
+fn walked_templates(paths: &[PathBuf], excludes: &GlobSet) -> HashSet<PathBuf> {
+    template_walker(paths, excludes)
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|entry| entry.path().canonicalize().ok())
+        .collect()
+}